@@ -1,4 +1,4 @@
-use human_regex::{escape_all, exactly, one_or_more, or, punctuation, whitespace, word_boundary};
+use human_regex::{one_of_words, one_or_more, punctuation, whitespace, WordOptions};
 use stop_words::{get, LANGUAGE};
 
 fn main() {
@@ -8,25 +8,30 @@ fn main() {
     // Print the contents
     println!("Original text:\n{}", document);
 
-    // Get the stopwords
-    let words = get(LANGUAGE::English);
+    // Get the stopwords, which come wrapped in quotes
+    let words: Vec<String> = get(LANGUAGE::English)
+        .iter()
+        .map(|word| word.trim_matches('"').to_string())
+        .collect();
 
     // Remove punctuation and lowercase the text to make parsing easier
     let lowercase_doc = document.to_ascii_lowercase();
     let regex_for_punctuation = one_or_more(punctuation());
     let text_without_punctuation = regex_for_punctuation
         .to_regex()
-        .replace_all(&*lowercase_doc, "");
+        .replace_all(&lowercase_doc, "");
 
-    // Make a regex to match stopwords with trailing spaces and punctuation
-    let regex_for_stop_words = word_boundary()
-        + exactly(1, or(&escape_all(&words)))
-        + word_boundary()
-        + one_or_more(whitespace());
+    // Match whole stop words, which compiles into an automaton rather than a regex of hundreds of alternatives
+    let options = WordOptions {
+        whole_word: true,
+        ..Default::default()
+    };
+    let stop_words = one_of_words(&words, &options).to_matcher();
 
-    // Remove stop words
-    let clean_text = regex_for_stop_words
+    // Remove stop words, then the extra whitespace they leave behind
+    let without_stop_words = stop_words.replace_all(&text_without_punctuation, "");
+    let clean_text = one_or_more(whitespace())
         .to_regex()
-        .replace_all(&*text_without_punctuation, "");
+        .replace_all(&without_stop_words, " ");
     println!("\nClean text:\n{}", clean_text);
 }
@@ -0,0 +1,14 @@
+use human_regex::{any, one_or_more, text};
+
+fn main() {
+    // Define a short string to match against, so that the traces stay readable
+    let matching_string = "<b>hi</b>";
+
+    // Watch the greedy repetition run to the end of the string and then backtrack
+    let greedy_regex_string = text("<") + one_or_more(any()) + text(">");
+    println!("Greedy:\n{}", greedy_regex_string.trace(matching_string));
+
+    // Watch the lazy repetition stop as soon as the rest of the pattern can match
+    let lazy_regex_string = text("<") + one_or_more(any()).lazy() + text(">");
+    println!("Lazy:\n{}", lazy_regex_string.trace(matching_string));
+}
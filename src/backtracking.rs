@@ -0,0 +1,666 @@
+//! Functions for finding patterns that make backtracking engines take exponential or polynomial time
+//!
+//! The regex crate runs in linear time, but engines that backtrack, like those of JavaScript, PCRE,
+//! Python or .NET, can take exponential or polynomial time when a pattern can match the same text in
+//! many ways, which lets a short input freeze a validator (known as ReDoS). Patterns exported with
+//! [to_dialect](HumanRegex::to_dialect) should be checked with [HumanRegex::backtracking_risks], which
+//! reports the sub-expression at fault, an input that triggers the slowdown and a safer rewrite when
+//! there is one.
+
+use super::humanregex::*;
+use super::lint::{finite_strings, subclass};
+use super::syntax::{self, FlagState};
+use regex_syntax::ast::{self, Ast};
+use regex_syntax::hir::{Class, Hir, HirKind};
+use std::ops::Range;
+
+/// The number of times the pump of an attack string is repeated when checking that the attack fails to match
+const CHECK_PUMPS: usize = 12;
+
+/// The characters tried at the end of an attack string, to make the match fail after all the backtracking
+const SUFFIXES: [&str; 8] = ["!", "\u{0}", "\n", " ", "a", "0", "_", "-"];
+
+/// The characters preferred when picking an example character from a class, in order
+const PREFERRED: &str = "a0 _-.A";
+
+/// How the time a backtracking engine takes grows with the length of an attack string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Complexity {
+    /// The time doubles with every repetition of the pump, so a few dozen characters are enough
+    Exponential,
+    /// The time grows with the length raised to this power
+    Polynomial(u32),
+}
+
+impl fmt::Display for Complexity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Complexity::Exponential => write!(f, "exponential"),
+            Complexity::Polynomial(2) => write!(f, "quadratic"),
+            Complexity::Polynomial(3) => write!(f, "cubic"),
+            Complexity::Polynomial(degree) => write!(f, "polynomial (degree {})", degree),
+        }
+    }
+}
+
+/// An input that makes a backtracking engine slow: the prefix, the pump repeated many times, then the suffix
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AttackString {
+    /// The text leading to the ambiguous sub-expression
+    pub prefix: String,
+    /// The text the sub-expression can match in more than one way
+    pub pump: String,
+    /// The text that makes the match fail, so that every way is tried
+    pub suffix: String,
+}
+
+impl AttackString {
+    /// Return the attack string with the pump repeated a number of times
+    /// ```
+    /// use human_regex::{digit, end, one_or_more};
+    /// let regex_string = one_or_more(one_or_more(digit())) + end();
+    /// let attack = &regex_string.backtracking_risks()[0].attack;
+    /// assert_eq!(attack.build(5), "00000!");
+    /// ```
+    pub fn build(&self, pumps: usize) -> String {
+        format!("{}{}{}", self.prefix, self.pump.repeat(pumps), self.suffix)
+    }
+}
+
+/// A sub-expression found by [HumanRegex::backtracking_risks]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BacktrackingRisk {
+    /// How the matching time grows with the length of the attack string
+    pub complexity: Complexity,
+    /// A description of the ambiguity and how to remove it
+    pub message: String,
+    /// The sub-expression at fault, as a byte range of the regex string
+    pub span: Range<usize>,
+    /// The sub-expression at fault
+    pub expression: String,
+    /// An input on which the pattern takes that long to fail
+    pub attack: AttackString,
+    /// A regex string matching the same as the sub-expression without the ambiguity, when one is known
+    pub rewrite: Option<String>,
+}
+
+impl fmt::Display for BacktrackingRisk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} backtracking: {} (at {}..{})",
+            self.complexity, self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl<T> HumanRegex<T> {
+    /// Look for sub-expressions that backtracking engines can take exponential or polynomial time on, in
+    /// order of their position in the regex string
+    ///
+    /// Three kinds of ambiguity are found: a repetition nested in another, like `(a+)+` or `(.*a)+`,
+    /// which is exponential, or polynomial with the bound as its degree when the outer repetition is
+    /// bounded, like `(.*a){12}`; branches of [or](crate::or) that match the same text under a
+    /// repetition, like `(\w|\d)+`, which is exponential too; and adjacent repetitions over overlapping
+    /// characters, like `\d+\d+`, which is polynomial. Each is only reported when an input that fails
+    /// to match can reach it, since a backtracking engine stops at the first way it finds.
+    /// ```
+    /// use human_regex::{beginning, digit, end, one_or_more, or, text, word, Complexity};
+    /// let regex_string = beginning() + one_or_more(or(&[word(), digit()])) + end();
+    /// let risks = regex_string.backtracking_risks();
+    /// assert_eq!(risks.len(), 1);
+    /// assert_eq!(risks[0].complexity, Complexity::Exponential);
+    /// assert_eq!(risks[0].expression, r"(?:(?:\w|\d))+");
+    /// assert_eq!(risks[0].attack.pump, "0");
+    /// assert_eq!(risks[0].rewrite.as_deref(), Some(r"(?:\w)+"));
+    ///
+    /// let quadratic = beginning() + one_or_more(digit()) + one_or_more(digit()) + end();
+    /// assert_eq!(quadratic.backtracking_risks()[0].complexity, Complexity::Polynomial(2));
+    /// assert!((beginning() + one_or_more(digit()) + text("x") + end()).backtracking_risks().is_empty());
+    /// ```
+    pub fn backtracking_risks(&self) -> Vec<BacktrackingRisk> {
+        let (Ok(ast), Ok(regex)) = (syntax::parse(&self.0), regex::Regex::new(&self.0)) else {
+            return Vec::new();
+        };
+        let mut analyzer = Analyzer {
+            pattern: &self.0,
+            regex,
+            risks: Vec::new(),
+        };
+        analyzer.visit(&ast, FlagState::default(), String::new());
+        analyzer
+            .risks
+            .sort_by_key(|risk| (risk.span.start, risk.span.end));
+        analyzer.risks
+    }
+}
+
+/// Walks the syntax tree of a regex string, collecting ambiguous sub-expressions
+struct Analyzer<'p> {
+    /// The regex string
+    pattern: &'p str,
+    /// The compiled regex string, used to check that attack strings fail to match
+    regex: regex::Regex,
+    /// The risks found so far
+    risks: Vec<BacktrackingRisk>,
+}
+
+impl Analyzer<'_> {
+    /// Check a node and everything inside of it, given an input that reaches the start of the node
+    fn visit(&mut self, node: &Ast, state: FlagState, prefix: String) {
+        match node {
+            Ast::Repetition(repetition) => {
+                self.nested(repetition, state, &prefix);
+                if unbounded(repetition) {
+                    self.overlapping_branches(repetition, state, &prefix);
+                }
+                self.visit(&repetition.ast, state, prefix);
+            }
+            Ast::Group(group) => {
+                let inner = match &group.kind {
+                    ast::GroupKind::NonCapturing(flags) => state.apply(flags),
+                    _ => state,
+                };
+                self.visit(&group.ast, inner, prefix);
+            }
+            Ast::Alternation(alternation) => {
+                for branch in &alternation.asts {
+                    self.visit(branch, state, prefix.clone());
+                }
+            }
+            Ast::Concat(concat) => {
+                let mut state = state;
+                let mut states = Vec::new();
+                for node in &concat.asts {
+                    states.push(state);
+                    if let Ast::Flags(flags) = node {
+                        state = state.apply(&flags.flags);
+                    }
+                }
+                self.adjacent(&concat.asts, &states, &prefix);
+                let mut prefix = prefix;
+                for (node, state) in concat.asts.iter().zip(states) {
+                    self.visit(node, state, prefix.clone());
+                    let hir = state.translate(syntax::source(self.pattern, node.span()));
+                    prefix.push_str(&hir.as_ref().and_then(example).unwrap_or_default());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Look for a repetition directly inside another, either with nothing around it that must match or
+    /// with a body that can match its own text twice over, like `(?:.*a)`
+    fn nested(&mut self, outer: &ast::Repetition, state: FlagState, prefix: &str) {
+        let complexity = match outer.op.kind {
+            _ if unbounded(outer) => Complexity::Exponential,
+            ast::RepetitionKind::Range(
+                ast::RepetitionRange::Exactly(maximum) | ast::RepetitionRange::Bounded(_, maximum),
+            ) if maximum >= 2 => Complexity::Polynomial(maximum),
+            _ => return,
+        };
+        let (body, state) = strip(&outer.ast, state);
+        let items: Vec<&Ast> = match body {
+            Ast::Concat(concat) => concat
+                .asts
+                .iter()
+                .filter(|node| !matches!(node, Ast::Flags(_) | Ast::Empty(_)))
+                .collect(),
+            node => vec![node],
+        };
+        let inner = items.iter().position(|node| match strip(node, state).0 {
+            Ast::Repetition(inner) => unbounded(inner),
+            _ => false,
+        });
+        let Some(inner) = inner else {
+            return;
+        };
+        let hirs: Option<Vec<Hir>> = items
+            .iter()
+            .map(|node| state.translate(syntax::source(self.pattern, node.span())))
+            .collect();
+        let Some(hirs) = hirs else {
+            return;
+        };
+        let rest_optional = hirs
+            .iter()
+            .enumerate()
+            .all(|(idx, hir)| idx == inner || hir.properties().minimum_len() == Some(0));
+        let Ast::Repetition(repeated) = strip(items[inner], state).0 else {
+            return;
+        };
+        let pump = match rest_optional {
+            true => state
+                .translate(syntax::source(self.pattern, repeated.ast.span()))
+                .and_then(|hir| pump(&hir)),
+            false => self.doubled(&outer.ast, state),
+        };
+        let Some(pump) = pump else {
+            return;
+        };
+        let source = syntax::source(self.pattern, &outer.span);
+        let inner_source = syntax::source(self.pattern, &repeated.span);
+        let optional = outer_minimum(outer) == 0;
+        let (rewrite, advice) = match items.len() {
+            _ if !rest_optional => (
+                None,
+                "keep the inner repetition from matching what follows it",
+            ),
+            _ if complexity != Complexity::Exponential => {
+                (None, "make what surrounds the inner repetition mandatory")
+            }
+            1 => {
+                let operand = syntax::source(self.pattern, repeated.ast.span());
+                let minimum = outer_minimum(outer) * outer_minimum(repeated);
+                (
+                    Some(format!("{}{}", operand, quantifier(minimum))),
+                    "use a single repetition instead",
+                )
+            }
+            2 => match separator(items[1 - inner], state) {
+                Some(separator) => {
+                    let separator = syntax::source(self.pattern, separator.span());
+                    // A separator after the inner repetition may also end the text, as it could before
+                    let loop_ = match inner {
+                        0 => format!(
+                            "{}(?:{}{})*{}",
+                            inner_source,
+                            separator,
+                            inner_source,
+                            syntax::source(self.pattern, items[1].span())
+                        ),
+                        _ => format!(
+                            "{}{}(?:{}{})*",
+                            syntax::source(self.pattern, items[0].span()),
+                            inner_source,
+                            separator,
+                            inner_source
+                        ),
+                    };
+                    let rewrite = match optional {
+                        true => format!("(?:{})?", loop_),
+                        false => loop_,
+                    };
+                    (
+                        Some(rewrite),
+                        "make the separator mandatory between repetitions instead",
+                    )
+                }
+                None => (None, "make what surrounds the inner repetition mandatory"),
+            },
+            _ => (None, "make what surrounds the inner repetition mandatory"),
+        };
+        let ways = match complexity {
+            Complexity::Exponential => "exponentially many ways",
+            Complexity::Polynomial(_) => "polynomially many ways",
+        };
+        let message = format!(
+            "`{}` repeats `{}`, so a run of {:?} can be split between the repetitions in {}; {}",
+            source, inner_source, pump, ways, advice
+        );
+        self.report(complexity, message, &outer.span, prefix, pump, rewrite);
+    }
+
+    /// Return a shortest text that the body of a repetition matches both once and twice in a row, in
+    /// a single pass, so that runs of it can be split between passes in many ways
+    fn doubled(&self, body: &Ast, state: FlagState) -> Option<String> {
+        let hir = state.translate(syntax::source(self.pattern, body.span()))?;
+        let once = pump(&hir)?;
+        let whole = regex::Regex::new(&format!("^(?:{})$", hir)).ok()?;
+        whole.is_match(&once.repeat(2)).then_some(once)
+    }
+
+    /// Look for a branch of an alternation under a repetition whose text the other branches can also
+    /// match, alone or one after another
+    fn overlapping_branches(
+        &mut self,
+        repetition: &ast::Repetition,
+        state: FlagState,
+        prefix: &str,
+    ) {
+        let (Ast::Alternation(alternation), state) = strip(&repetition.ast, state) else {
+            return;
+        };
+        let sources: Vec<&str> = alternation
+            .asts
+            .iter()
+            .map(|branch| syntax::source(self.pattern, branch.span()))
+            .collect();
+        let Some(hirs) = sources
+            .iter()
+            .map(|source| state.translate(source))
+            .collect::<Option<Vec<Hir>>>()
+        else {
+            return;
+        };
+        let whole = |hir: &Hir| regex::Regex::new(&format!("^(?:{})$", hir)).ok();
+        let matchers: Vec<Option<regex::Regex>> = hirs.iter().map(whole).collect();
+        // Later branches are checked first, since they are the ones that can usually be dropped
+        for branch in (0..hirs.len()).rev() {
+            let others: Vec<usize> = (0..hirs.len()).filter(|&idx| idx != branch).collect();
+            let runs = others
+                .iter()
+                .map(|&idx| hirs[idx].to_string())
+                .collect::<Vec<String>>()
+                .join("|");
+            let Ok(runs) = regex::Regex::new(&format!("^(?:{})+$", runs)) else {
+                continue;
+            };
+            let Some(shared) = candidates(&hirs, branch)
+                .into_iter()
+                .find(|string| !string.is_empty() && runs.is_match(string))
+            else {
+                continue;
+            };
+            let redundant = others
+                .iter()
+                .any(|&idx| subclass(&hirs[branch], Some(&hirs[idx])))
+                || finite_strings(&hirs[branch])
+                    .is_some_and(|strings| strings.iter().all(|string| runs.is_match(string)));
+            let alone = others.iter().copied().find(|&idx| {
+                matchers[idx]
+                    .as_ref()
+                    .is_some_and(|matcher| matcher.is_match(&shared))
+            });
+            let merged = match (alone.map(|idx| hirs[idx].kind()), hirs[branch].kind()) {
+                _ if redundant => Some(None),
+                (
+                    Some(HirKind::Class(Class::Unicode(other))),
+                    HirKind::Class(Class::Unicode(class)),
+                ) => {
+                    let mut union = other.clone();
+                    union.union(class);
+                    Some(Some(Hir::class(Class::Unicode(union)).to_string()))
+                }
+                _ => None,
+            };
+            let quantifier =
+                &self.pattern[repetition.ast.span().end.offset..repetition.span.end.offset];
+            let rewrite = merged.map(|merged| {
+                let branches: Vec<&str> = others
+                    .iter()
+                    .map(|&idx| match (&merged, Some(idx) == alone) {
+                        (Some(merged), true) => merged.as_str(),
+                        _ => sources[idx],
+                    })
+                    .collect();
+                format!("(?:{}){}", branches.join("|"), quantifier)
+            });
+            let overlap = match alone {
+                Some(idx) => format!(
+                    "the branches `{}` and `{}` both match {:?}",
+                    sources[idx], sources[branch], shared
+                ),
+                None => format!(
+                    "the branch `{}` matches {:?}, which the other branches also match one after \
+                     another",
+                    sources[branch], shared
+                ),
+            };
+            let message = format!(
+                "{}, so a run of it can be split between them in exponentially many ways; make the \
+                 branches match different text",
+                overlap
+            );
+            self.report(
+                Complexity::Exponential,
+                message,
+                &repetition.span,
+                prefix,
+                shared,
+                rewrite,
+            );
+            return;
+        }
+    }
+
+    /// Look for chains of repetitions in a sequence that can match the same characters, with only
+    /// optional nodes between them
+    fn adjacent(&mut self, nodes: &[Ast], states: &[FlagState], prefix: &str) {
+        let hirs: Vec<Option<Hir>> = nodes
+            .iter()
+            .zip(states)
+            .map(|(node, state)| state.translate(syntax::source(self.pattern, node.span())))
+            .collect();
+        let repeated = |idx: usize| -> Option<(&ast::Repetition, Hir)> {
+            match strip(&nodes[idx], states[idx]) {
+                (Ast::Repetition(repetition), state) if unbounded(repetition) => {
+                    let operand = syntax::source(self.pattern, repetition.ast.span());
+                    Some((repetition, state.translate(operand)?))
+                }
+                _ => None,
+            }
+        };
+        let mut prefix = prefix.to_string();
+        let mut start = 0;
+        while start < nodes.len() {
+            let Some((first, operand)) = repeated(start) else {
+                prefix.push_str(&hirs[start].as_ref().and_then(example).unwrap_or_default());
+                start += 1;
+                continue;
+            };
+            let Some(mut pump) = pump(&operand) else {
+                start += 1;
+                continue;
+            };
+            let runs = |operand: &Hir| regex::Regex::new(&format!("^(?:{})+$", operand)).ok();
+            let mut matchers = vec![runs(&operand)];
+            let mut chain = vec![(start, first, operand)];
+            let mut next = start + 1;
+            while next < nodes.len() {
+                match repeated(next) {
+                    Some((repetition, other)) => {
+                        matchers.push(runs(&other));
+                        let all_match = |pump: &str| {
+                            matchers
+                                .iter()
+                                .all(|matcher| matcher.as_ref().is_some_and(|m| m.is_match(pump)))
+                        };
+                        // The pump so far may not suit the next repetition while one of its own does
+                        let shared = [Some(pump.clone()), self::pump(&other)]
+                            .into_iter()
+                            .flatten()
+                            .find(|pump| all_match(pump));
+                        let Some(shared) = shared else {
+                            break;
+                        };
+                        pump = shared;
+                        chain.push((next, repetition, other));
+                    }
+                    None if hirs[next]
+                        .as_ref()
+                        .is_some_and(|hir| hir.properties().minimum_len() == Some(0)) => {}
+                    None => break,
+                }
+                next += 1;
+            }
+            let last = chain.last().map_or(start, |&(idx, _, _)| idx);
+            if chain.len() > 1 {
+                let span = ast::Span::new(nodes[start].span().start, nodes[last].span().end);
+                let sources: Vec<String> = chain
+                    .iter()
+                    .map(|(_, repetition, _)| {
+                        format!("`{}`", syntax::source(self.pattern, &repetition.span))
+                    })
+                    .collect();
+                let rewrite = match chain.as_slice() {
+                    [(a, first, first_operand), (b, second, second_operand)]
+                        if *b == a + 1 && first_operand == second_operand =>
+                    {
+                        let minimum = outer_minimum(first) + outer_minimum(second);
+                        Some(format!(
+                            "{}{}",
+                            syntax::source(self.pattern, first.ast.span()),
+                            quantifier(minimum)
+                        ))
+                    }
+                    _ => None,
+                };
+                let message = format!(
+                    "{} {} match a run of {:?}, so it can be split between them in many ways; \
+                     make them match different characters or merge them into one repetition",
+                    sources.join(" and "),
+                    if chain.len() == 2 { "both" } else { "all" },
+                    pump
+                );
+                self.report(
+                    Complexity::Polynomial(chain.len() as u32),
+                    message,
+                    &span,
+                    &prefix,
+                    pump,
+                    rewrite,
+                );
+            }
+            for hir in &hirs[start..=last] {
+                prefix.push_str(&hir.as_ref().and_then(example).unwrap_or_default());
+            }
+            start = last + 1;
+        }
+    }
+
+    /// Record a risk if some suffix makes an attack string fail to match, so that every way is tried
+    fn report(
+        &mut self,
+        complexity: Complexity,
+        message: String,
+        span: &ast::Span,
+        prefix: &str,
+        pump: String,
+        rewrite: Option<String>,
+    ) {
+        let suffix = SUFFIXES.iter().find(|suffix| {
+            let attack = format!("{}{}{}", prefix, pump.repeat(CHECK_PUMPS), suffix);
+            !self.regex.is_match(&attack)
+        });
+        let Some(suffix) = suffix else {
+            return;
+        };
+        self.risks.push(BacktrackingRisk {
+            complexity,
+            message,
+            span: span.start.offset..span.end.offset,
+            expression: syntax::source(self.pattern, span).to_string(),
+            attack: AttackString {
+                prefix: prefix.to_string(),
+                pump,
+                suffix: suffix.to_string(),
+            },
+            rewrite,
+        });
+    }
+}
+
+/// Return whether a repetition has no maximum
+fn unbounded(repetition: &ast::Repetition) -> bool {
+    matches!(
+        repetition.op.kind,
+        ast::RepetitionKind::ZeroOrMore
+            | ast::RepetitionKind::OneOrMore
+            | ast::RepetitionKind::Range(ast::RepetitionRange::AtLeast(_))
+    )
+}
+
+/// Return the minimum number of repetitions
+fn outer_minimum(repetition: &ast::Repetition) -> u32 {
+    match repetition.op.kind {
+        ast::RepetitionKind::ZeroOrOne | ast::RepetitionKind::ZeroOrMore => 0,
+        ast::RepetitionKind::OneOrMore => 1,
+        ast::RepetitionKind::Range(ast::RepetitionRange::Exactly(n))
+        | ast::RepetitionKind::Range(ast::RepetitionRange::AtLeast(n))
+        | ast::RepetitionKind::Range(ast::RepetitionRange::Bounded(n, _)) => n,
+    }
+}
+
+/// Return the quantifier repeating at least a number of times
+fn quantifier(minimum: u32) -> String {
+    match minimum {
+        0 => "*".to_string(),
+        1 => "+".to_string(),
+        minimum => format!("{{{},}}", minimum),
+    }
+}
+
+/// Return a node without the groups around it, which do not change what it matches, with the flags inside them
+fn strip(node: &Ast, state: FlagState) -> (&Ast, FlagState) {
+    match node {
+        Ast::Group(group) => match &group.kind {
+            ast::GroupKind::NonCapturing(flags) => strip(&group.ast, state.apply(flags)),
+            _ => strip(&group.ast, state),
+        },
+        node => (node, state),
+    }
+}
+
+/// Return the operand of an optional node, like `(?:\s)?`, to use as a mandatory separator
+fn separator(node: &Ast, state: FlagState) -> Option<&Ast> {
+    match strip(node, state).0 {
+        Ast::Repetition(repetition) if repetition.op.kind == ast::RepetitionKind::ZeroOrOne => {
+            Some(&repetition.ast)
+        }
+        _ => None,
+    }
+}
+
+/// Return a shortest string a node matches, if it matches any string of characters
+fn example(hir: &Hir) -> Option<String> {
+    match hir.kind() {
+        HirKind::Empty | HirKind::Look(_) => Some(String::new()),
+        HirKind::Literal(literal) => String::from_utf8(literal.0.to_vec()).ok(),
+        HirKind::Class(Class::Unicode(class)) => {
+            let ranges = class.ranges();
+            let preferred = PREFERRED.chars().find(|&chr| {
+                ranges
+                    .iter()
+                    .any(|range| range.start() <= chr && chr <= range.end())
+            });
+            preferred
+                .or_else(|| ranges.first().map(|range| range.start()))
+                .map(String::from)
+        }
+        HirKind::Class(Class::Bytes(_)) => None,
+        HirKind::Repetition(repetition) => {
+            Some(example(&repetition.sub)?.repeat(repetition.min as usize))
+        }
+        HirKind::Capture(capture) => example(&capture.sub),
+        HirKind::Concat(parts) => parts.iter().map(example).collect(),
+        HirKind::Alternation(branches) => branches.iter().find_map(example),
+    }
+}
+
+/// Return a short non-empty string a node matches, if it matches any
+fn pump(hir: &Hir) -> Option<String> {
+    let found = match hir.kind() {
+        HirKind::Repetition(repetition) if repetition.max != Some(0) => {
+            Some(pump(&repetition.sub)?.repeat(repetition.min.max(1) as usize))
+        }
+        HirKind::Capture(capture) => pump(&capture.sub),
+        HirKind::Concat(parts) => {
+            let shortest = example(hir)?;
+            match shortest.is_empty() {
+                false => Some(shortest),
+                true => parts.iter().find_map(pump),
+            }
+        }
+        HirKind::Alternation(branches) => branches.iter().find_map(pump),
+        _ => example(hir),
+    };
+    found.filter(|found| !found.is_empty())
+}
+
+/// Return the strings worth trying as the text a branch shares with the others
+fn candidates(hirs: &[Hir], branch: usize) -> Vec<String> {
+    let mut strings = finite_strings(&hirs[branch]).unwrap_or_default();
+    if let HirKind::Class(Class::Unicode(class)) = hirs[branch].kind() {
+        // A character the class has in common with another is the most telling example
+        for other in hirs {
+            if let HirKind::Class(Class::Unicode(other)) = other.kind() {
+                let mut both = class.clone();
+                both.intersect(other);
+                strings.extend(example(&Hir::class(Class::Unicode(both))));
+            }
+        }
+    }
+    strings.extend(pump(&hirs[branch]));
+    strings
+}
@@ -0,0 +1,388 @@
+//! Search files for lines matching a pattern written in the human_regex DSL
+//!
+//! ```text
+//! human-grep 'exactly 4 digit, "-", exactly 2 digit' notes.txt
+//! human-grep --library dates.hr --extract 'date' -r logs/
+//! ```
+
+use human_regex::{case_insensitive, highlight_ansi, parse_dsl, HumanRegex, PatternLibrary};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+/// The help text printed by `--help`
+const USAGE: &str = "\
+Search files for lines matching a pattern written in the human_regex DSL
+
+Usage: human-grep [OPTIONS] PATTERN [PATH...]
+
+Reads standard input when no path is given. Exits with 0 if a line matched,
+1 if none did and 2 on errors.
+
+Options:
+  -l, --library FILE   Load named patterns from FILE, usable by name in PATTERN
+  -A, --after N        Print N lines of context after each matching line
+  -B, --before N       Print N lines of context before each matching line
+  -C, --context N      Print N lines of context around each matching line
+  -c, --count          Print only the number of matching lines per file
+  -o, --only-matching  Print only the matching parts of lines
+  -x, --extract        Print the named capture groups as tab separated columns
+  -i, --ignore-case    Match without regard to case
+  -n, --line-number    Prefix lines with their line number
+  -r, --recursive      Search directories recursively
+      --no-ignore      Search files excluded by .gitignore and .ignore files
+      --hidden         Search hidden files and directories
+      --color          Highlight matches and capture groups
+      --show-regex     Print the regex the pattern compiles to and exit
+  -h, --help           Print this help
+";
+
+/// The command line options
+#[derive(Default)]
+struct Options {
+    /// The pattern, in the DSL
+    pattern: String,
+    /// The files and directories to search
+    paths: Vec<PathBuf>,
+    /// The library of named patterns
+    library: Option<PathBuf>,
+    /// The number of lines of context after a match
+    after: usize,
+    /// The number of lines of context before a match
+    before: usize,
+    /// Whether to print only counts
+    count: bool,
+    /// Whether to print only the matching parts of lines
+    only_matching: bool,
+    /// Whether to print named capture groups as columns
+    extract: bool,
+    /// Whether to ignore case
+    ignore_case: bool,
+    /// Whether to print line numbers
+    line_number: bool,
+    /// Whether to search directories
+    recursive: bool,
+    /// Whether to skip ignore files
+    no_ignore: bool,
+    /// Whether to search hidden files
+    hidden: bool,
+    /// Whether to color the output
+    color: bool,
+    /// Whether to print the regex instead of searching
+    show_regex: bool,
+}
+
+/// Run the search and exit with 0 if a line matched, 1 if none did and 2 on errors
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(message) => {
+            eprintln!("human-grep: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Search as the command line asks, returning whether any line matched
+fn run() -> Result<bool, String> {
+    let options = match parse_args(std::env::args().skip(1))? {
+        Some(options) => options,
+        None => {
+            print!("{}", USAGE);
+            return Ok(true);
+        }
+    };
+    let pattern = match &options.library {
+        Some(path) => {
+            let source = std::fs::read_to_string(path)
+                .map_err(|error| format!("{}: {}", path.display(), error))?;
+            PatternLibrary::parse(&source)
+                .map_err(|error| format!("{}: {}", path.display(), error))?
+                .parse_pattern(&options.pattern)
+        }
+        None => parse_dsl(&options.pattern),
+    }
+    .map_err(|error| format!("invalid pattern: {}", error))?;
+    let pattern = match options.ignore_case {
+        true => case_insensitive(pattern),
+        false => pattern,
+    };
+    if options.show_regex {
+        println!("{}", pattern);
+        return Ok(true);
+    }
+    let mut searcher = Searcher::new(&options, pattern);
+    if options.extract {
+        searcher.print_header();
+    }
+    if options.paths.is_empty() {
+        let mut input = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut input)
+            .map_err(|error| format!("standard input: {}", error))?;
+        searcher.search(None, &input);
+    } else {
+        for path in files(&options)? {
+            match std::fs::read(&path) {
+                Ok(input) => searcher.search(Some(&path), &input),
+                Err(error) => {
+                    eprintln!("human-grep: {}: {}", path.display(), error);
+                    searcher.failed = true;
+                }
+            }
+        }
+    }
+    if searcher.failed {
+        return Err("some files could not be read".to_string());
+    }
+    Ok(searcher.matched)
+}
+
+/// Parse the command line arguments, returning `None` when help is requested
+fn parse_args(args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    // Split context options with attached values, like `-C2`, into the option and its value
+    let mut args = args.flat_map(|arg| match arg.get(..2) {
+        Some("-A" | "-B" | "-C") if arg.len() > 2 => {
+            vec![arg[..2].to_string(), arg[2..].to_string()]
+        }
+        _ => vec![arg],
+    });
+    let mut options = Options::default();
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut number = |name: &str| -> Result<usize, String> {
+            let value = args
+                .next()
+                .ok_or_else(|| format!("{} needs a value", name))?;
+            value
+                .parse()
+                .map_err(|_| format!("{} needs a number, not `{}`", name, value))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-l" | "--library" => {
+                let path = args.next().ok_or("--library needs a file")?;
+                options.library = Some(PathBuf::from(path));
+            }
+            "-A" | "--after" => options.after = number(&arg)?,
+            "-B" | "--before" => options.before = number(&arg)?,
+            "-C" | "--context" => {
+                options.after = number(&arg)?;
+                options.before = options.after;
+            }
+            "-c" | "--count" => options.count = true,
+            "-o" | "--only-matching" => options.only_matching = true,
+            "-x" | "--extract" => options.extract = true,
+            "-i" | "--ignore-case" => options.ignore_case = true,
+            "-n" | "--line-number" => options.line_number = true,
+            "-r" | "--recursive" => options.recursive = true,
+            "--no-ignore" => options.no_ignore = true,
+            "--hidden" => options.hidden = true,
+            "--color" => options.color = true,
+            "--show-regex" => options.show_regex = true,
+            "--" => positional.extend(args.by_ref()),
+            flag if flag.starts_with('-') && flag != "-" => {
+                return Err(format!("unknown option `{}`, see --help", flag))
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let mut positional = positional.into_iter();
+    options.pattern = positional.next().ok_or("missing PATTERN, see --help")?;
+    options.paths = positional.map(PathBuf::from).collect();
+    Ok(Some(options))
+}
+
+/// List the files to search, walking directories when searching recursively
+fn files(options: &Options) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    for path in &options.paths {
+        if !path.is_dir() {
+            files.push(path.clone());
+        } else if !options.recursive {
+            return Err(format!(
+                "{}: is a directory, use --recursive",
+                path.display()
+            ));
+        } else {
+            let walker = ignore::WalkBuilder::new(path)
+                .standard_filters(!options.no_ignore)
+                .hidden(!options.hidden)
+                .sort_by_file_name(|a, b| a.cmp(b))
+                .build();
+            for entry in walker {
+                let entry = entry.map_err(|error| error.to_string())?;
+                if entry.file_type().is_some_and(|kind| kind.is_file()) {
+                    files.push(entry.into_path());
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Split input into lines without their line endings, replacing bytes that aren't valid UTF-8
+fn lines(input: &[u8]) -> Vec<String> {
+    let input = input.strip_suffix(b"\n").unwrap_or(input);
+    if input.is_empty() {
+        return Vec::new();
+    }
+    input
+        .split(|&byte| byte == b'\n')
+        .map(|line| {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            String::from_utf8_lossy(line).into_owned()
+        })
+        .collect()
+}
+
+/// The state of a search across files
+struct Searcher<'o> {
+    /// The command line options
+    options: &'o Options,
+    /// The compiled pattern
+    regex: regex::Regex,
+    /// Whether file names are printed before lines
+    with_file_name: bool,
+    /// Whether any line has matched
+    matched: bool,
+    /// Whether any file could not be read
+    failed: bool,
+    /// Whether anything has been printed, so context groups need a separator
+    printed: bool,
+    /// Where output goes
+    out: std::io::BufWriter<std::io::Stdout>,
+}
+
+impl<'o> Searcher<'o> {
+    /// Prepare a search
+    fn new(options: &'o Options, pattern: HumanRegex) -> Self {
+        Searcher {
+            options,
+            regex: pattern.to_regex(),
+            with_file_name: options.paths.len() > 1 || options.recursive,
+            matched: false,
+            failed: false,
+            printed: false,
+            out: std::io::BufWriter::new(std::io::stdout()),
+        }
+    }
+
+    /// Print the names of the columns printed by `--extract`
+    fn print_header(&mut self) {
+        let mut columns: Vec<&str> = Vec::new();
+        if self.with_file_name {
+            columns.push("file");
+        }
+        if self.options.line_number {
+            columns.push("line");
+        }
+        columns.extend(self.regex.capture_names().flatten());
+        let _ = writeln!(self.out, "{}", columns.join("\t"));
+    }
+
+    /// Search the contents of a file, or of standard input without a path
+    fn search(&mut self, path: Option<&Path>, input: &[u8]) {
+        let name = path.map(|path| path.display().to_string());
+        let lines: Vec<String> = lines(input);
+        let matching: Vec<bool> = lines.iter().map(|line| self.regex.is_match(line)).collect();
+        let count = matching.iter().filter(|&&matched| matched).count();
+        self.matched |= count > 0;
+        if self.options.count {
+            let _ = match &name {
+                Some(name) if self.with_file_name => writeln!(self.out, "{}:{}", name, count),
+                _ => writeln!(self.out, "{}", count),
+            };
+            return;
+        }
+        // Like grep, files containing NUL bytes are reported instead of printed
+        if input.contains(&0) {
+            if count > 0 {
+                let name = name.as_deref().unwrap_or("(standard input)");
+                let _ = writeln!(self.out, "Binary file {} matches", name);
+                self.printed = true;
+            }
+            return;
+        }
+        let context = !(self.options.only_matching || self.options.extract)
+            && self.options.before + self.options.after > 0;
+        let mut last_printed: Option<usize> = None;
+        for (idx, line) in lines.iter().enumerate() {
+            if !matching[idx] {
+                continue;
+            }
+            if context {
+                let first = idx.saturating_sub(self.options.before);
+                let first = last_printed.map_or(first, |last| first.max(last + 1));
+                if self.printed && last_printed.is_none_or(|last| first > last + 1) {
+                    let _ = writeln!(self.out, "--");
+                }
+                for (before, text) in lines.iter().enumerate().take(idx).skip(first) {
+                    self.print_line(&name, before, text, '-');
+                }
+            }
+            self.print_match(&name, idx, line);
+            last_printed = Some(idx);
+            if context {
+                for after in idx + 1..(idx + 1 + self.options.after).min(lines.len()) {
+                    if matching[after] {
+                        break;
+                    }
+                    self.print_line(&name, after, &lines[after], '-');
+                    last_printed = Some(after);
+                }
+            }
+        }
+    }
+
+    /// Print a matching line as the options ask
+    fn print_match(&mut self, name: &Option<String>, idx: usize, line: &str) {
+        if self.options.extract {
+            for captures in self.regex.captures_iter(line) {
+                let mut columns: Vec<String> = Vec::new();
+                if let Some(name) = name.as_ref().filter(|_| self.with_file_name) {
+                    columns.push(name.clone());
+                }
+                if self.options.line_number {
+                    columns.push((idx + 1).to_string());
+                }
+                for group in self.regex.capture_names().flatten() {
+                    let value = captures.name(group).map_or("", |found| found.as_str());
+                    columns.push(value.replace(['\t', '\n'], " "));
+                }
+                let _ = writeln!(self.out, "{}", columns.join("\t"));
+            }
+            self.printed = true;
+        } else if self.options.only_matching {
+            let found: Vec<String> = self
+                .regex
+                .find_iter(line)
+                .filter(|found| !found.is_empty())
+                .map(|found| found.as_str().to_string())
+                .collect();
+            for text in found {
+                self.print_line(name, idx, &text, ':');
+            }
+        } else {
+            self.print_line(name, idx, line, ':');
+        }
+    }
+
+    /// Print a line with its file name and line number when the options ask for them
+    fn print_line(&mut self, name: &Option<String>, idx: usize, line: &str, separator: char) {
+        let mut prefix = String::new();
+        if let Some(name) = name.as_ref().filter(|_| self.with_file_name) {
+            prefix.push_str(&format!("{}{}", name, separator));
+        }
+        if self.options.line_number {
+            prefix.push_str(&format!("{}{}", idx + 1, separator));
+        }
+        let text = match self.options.color && separator == ':' {
+            true => highlight_ansi(&self.regex, line),
+            false => line.to_string(),
+        };
+        let _ = writeln!(self.out, "{}{}", prefix, text);
+        self.printed = true;
+    }
+}
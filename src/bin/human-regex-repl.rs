@@ -0,0 +1,230 @@
+//! An interactive shell for building patterns and trying them on sample inputs
+//!
+//! Type a pattern as builder expressions, like `exactly(4, digit()) + text("-")`, or in the DSL, like
+//! `exactly 4 digit, "-"`, to see the regex it builds, an explanation of every part and whether the
+//! samples match. Commands start with `:`, see `:help`.
+
+use human_regex::{parse_builder_expression, parse_dsl, DslError, HumanRegex};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+
+/// The help text printed by `:help`
+const HELP: &str = "\
+Type a pattern to see its regex, an explanation and which samples match, either as
+builder expressions or in the DSL:
+
+  exactly(4, digit()) + text(\"-\") + named_capture(exactly(2, digit()), \"month\")
+  exactly 4 digit, \"-\", capture month: exactly 2 digit
+
+Commands:
+  :test TEXT     Add a sample and show whether the pattern matches it
+  :test          Show whether the pattern matches every sample
+  :samples       List the samples
+  :clear         Remove every sample
+  :save FILE     Save the samples to FILE, one per line
+  :load FILE     Add the samples in FILE, one per line
+  :dsl           Show the pattern in the DSL
+  :history       Show the lines entered in this session
+  :help          Show this help
+  :quit          Leave, as does Ctrl-D
+";
+
+/// The state of an interactive session
+struct Session {
+    /// The pattern being built
+    pattern: Option<HumanRegex>,
+    /// The sample inputs the pattern is tried on
+    samples: Vec<String>,
+    /// The lines entered in this session
+    history: Vec<String>,
+    /// Whether to highlight matches with ANSI escape sequences
+    color: bool,
+}
+
+/// Run the shell until the input ends or the user quits
+fn main() {
+    let mut editor = match DefaultEditor::new() {
+        Ok(editor) => editor,
+        Err(error) => {
+            eprintln!("human-regex-repl: {}", error);
+            std::process::exit(2);
+        }
+    };
+    let history = history_file();
+    if let Some(path) = &history {
+        let _ = editor.load_history(path);
+    }
+    let interactive = std::io::stdin().is_terminal();
+    if interactive {
+        println!(
+            "human_regex {}, type :help for help",
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+    let mut session = Session {
+        pattern: None,
+        samples: Vec::new(),
+        history: Vec::new(),
+        color: std::io::stdout().is_terminal(),
+    };
+    loop {
+        let line = match editor.readline(if interactive { "hr> " } else { "" }) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => {
+                eprintln!("human-regex-repl: {}", error);
+                break;
+            }
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line);
+        session.history.push(line.to_string());
+        match session.handle(line) {
+            Some(output) => print!("{}", output),
+            None => break,
+        }
+    }
+    if let Some(path) = &history {
+        let _ = editor.save_history(path);
+    }
+}
+
+/// Return the file keeping the history of lines across sessions
+fn history_file() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".human_regex_history"))
+}
+
+impl Session {
+    /// Handle a line of input, returning what to print, or `None` to quit
+    fn handle(&mut self, line: &str) -> Option<String> {
+        let (command, argument) = match line.strip_prefix(':') {
+            Some(command) => command.split_once(' ').unwrap_or((command, "")),
+            None => return Some(self.set_pattern(line)),
+        };
+        let argument = argument.trim();
+        let output = match (command, argument) {
+            ("quit" | "q" | "exit", _) => return None,
+            ("help" | "h", _) => HELP.to_string(),
+            ("test" | "t", "") => self.results(&self.samples),
+            ("test" | "t", sample) => {
+                self.samples.push(sample.to_string());
+                self.results(&[sample.to_string()])
+            }
+            ("samples", _) => self
+                .samples
+                .iter()
+                .enumerate()
+                .map(|(idx, sample)| format!("{:>3}  {}\n", idx + 1, sample))
+                .collect(),
+            ("clear", _) => {
+                self.samples.clear();
+                "removed every sample\n".to_string()
+            }
+            ("save", "") | ("load", "") => format!(":{} needs a file\n", command),
+            ("save", path) => {
+                let mut corpus = self.samples.join("\n");
+                corpus.push('\n');
+                match std::fs::write(path, corpus) {
+                    Ok(()) => format!("saved {} samples to {}\n", self.samples.len(), path),
+                    Err(error) => format!("could not save {}: {}\n", path, error),
+                }
+            }
+            ("load", path) => match std::fs::read_to_string(path) {
+                Ok(corpus) => {
+                    let loaded: Vec<String> = corpus
+                        .lines()
+                        .filter(|sample| !sample.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                    let mut output = format!("loaded {} samples from {}\n", loaded.len(), path);
+                    output.push_str(&self.results(&loaded));
+                    self.samples.extend(loaded);
+                    output
+                }
+                Err(error) => format!("could not load {}: {}\n", path, error),
+            },
+            ("dsl", _) => match &self.pattern {
+                Some(pattern) => format!("{}\n", pattern.to_dsl()),
+                None => "no pattern yet, type one first\n".to_string(),
+            },
+            ("history", _) => self
+                .history
+                .iter()
+                .enumerate()
+                .map(|(idx, line)| format!("{:>3}  {}\n", idx + 1, line))
+                .collect(),
+            _ => format!("unknown command :{}, type :help for help\n", command),
+        };
+        Some(output)
+    }
+
+    /// Parse a new pattern and describe it
+    fn set_pattern(&mut self, source: &str) -> String {
+        let pattern = match parse(source) {
+            Ok(pattern) => pattern,
+            Err(error) => {
+                let marker = " ".repeat(source[..error.offset.min(source.len())].chars().count());
+                return format!("  {}\n  {}^ {}\n", source, marker, error.message);
+            }
+        };
+        let mut output = format!("regex: {}\n", pattern);
+        for line in pattern.to_verbose_string().lines().skip(1) {
+            output.push_str(&format!("  {}\n", line));
+        }
+        self.pattern = Some(pattern);
+        output.push_str(&self.results(&self.samples));
+        output
+    }
+
+    /// Show whether the pattern matches each sample, with the matches highlighted and the captures listed
+    fn results(&self, samples: &[String]) -> String {
+        let Some(pattern) = &self.pattern else {
+            return "no pattern yet, type one first\n".to_string();
+        };
+        let regex = pattern.to_regex();
+        let mut output = String::new();
+        for sample in samples {
+            let Some(captures) = regex.captures(sample) else {
+                output.push_str(&format!("no match  {:?}\n", sample));
+                continue;
+            };
+            let shown = match self.color {
+                true => pattern.highlight_ansi(sample),
+                false => format!("{:?}", sample),
+            };
+            let mut groups = Vec::new();
+            for (idx, name) in regex.capture_names().enumerate() {
+                let label = match (idx, name) {
+                    (0, _) => "match".to_string(),
+                    (_, Some(name)) => name.to_string(),
+                    (idx, None) => idx.to_string(),
+                };
+                match captures.get(idx) {
+                    Some(found) => groups.push(format!("{} = {:?}", label, found.as_str())),
+                    None => groups.push(format!("{} = none", label)),
+                }
+            }
+            output.push_str(&format!("match     {}  {}\n", shown, groups.join(", ")));
+        }
+        output
+    }
+}
+
+/// Parse a pattern written as builder expressions or in the DSL, whichever it looks like
+fn parse(source: &str) -> Result<HumanRegex, DslError> {
+    let builder = parse_builder_expression(source);
+    if builder.is_ok() || source.contains("()") {
+        return builder;
+    }
+    parse_dsl(source).map_err(|error| match builder {
+        // The syntax that parsed further is most likely the one intended
+        Err(builder) if builder.offset > error.offset => builder,
+        _ => error,
+    })
+}
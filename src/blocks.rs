@@ -0,0 +1,1191 @@
+//! Functions for matching Unicode blocks
+//!
+//! The regex crate doesn't support the Block property, so these classes are built from the block ranges of
+//! the Unicode 16.0 character database. Surrogate blocks are left out, since no `char` can fall in them.
+
+use super::charset::CharSet;
+use super::humanregex::*;
+use std::ops::RangeInclusive;
+
+/// An enum covering all Unicode blocks
+///
+/// Used in the [unicode_block] function
+#[allow(missing_docs)] // variants are self documenting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnicodeBlock {
+    BasicLatin,
+    Latin1Supplement,
+    LatinExtendedA,
+    LatinExtendedB,
+    IpaExtensions,
+    SpacingModifierLetters,
+    CombiningDiacriticalMarks,
+    GreekAndCoptic,
+    Cyrillic,
+    CyrillicSupplement,
+    Armenian,
+    Hebrew,
+    Arabic,
+    Syriac,
+    ArabicSupplement,
+    Thaana,
+    NKo,
+    Samaritan,
+    Mandaic,
+    SyriacSupplement,
+    ArabicExtendedB,
+    ArabicExtendedA,
+    Devanagari,
+    Bengali,
+    Gurmukhi,
+    Gujarati,
+    Oriya,
+    Tamil,
+    Telugu,
+    Kannada,
+    Malayalam,
+    Sinhala,
+    Thai,
+    Lao,
+    Tibetan,
+    Myanmar,
+    Georgian,
+    HangulJamo,
+    Ethiopic,
+    EthiopicSupplement,
+    Cherokee,
+    UnifiedCanadianAboriginalSyllabics,
+    Ogham,
+    Runic,
+    Tagalog,
+    Hanunoo,
+    Buhid,
+    Tagbanwa,
+    Khmer,
+    Mongolian,
+    UnifiedCanadianAboriginalSyllabicsExtended,
+    Limbu,
+    TaiLe,
+    NewTaiLue,
+    KhmerSymbols,
+    Buginese,
+    TaiTham,
+    CombiningDiacriticalMarksExtended,
+    Balinese,
+    Sundanese,
+    Batak,
+    Lepcha,
+    OlChiki,
+    CyrillicExtendedC,
+    GeorgianExtended,
+    SundaneseSupplement,
+    VedicExtensions,
+    PhoneticExtensions,
+    PhoneticExtensionsSupplement,
+    CombiningDiacriticalMarksSupplement,
+    LatinExtendedAdditional,
+    GreekExtended,
+    GeneralPunctuation,
+    SuperscriptsAndSubscripts,
+    CurrencySymbols,
+    CombiningDiacriticalMarksForSymbols,
+    LetterlikeSymbols,
+    NumberForms,
+    Arrows,
+    MathematicalOperators,
+    MiscellaneousTechnical,
+    ControlPictures,
+    OpticalCharacterRecognition,
+    EnclosedAlphanumerics,
+    BoxDrawing,
+    BlockElements,
+    GeometricShapes,
+    MiscellaneousSymbols,
+    Dingbats,
+    MiscellaneousMathematicalSymbolsA,
+    SupplementalArrowsA,
+    BraillePatterns,
+    SupplementalArrowsB,
+    MiscellaneousMathematicalSymbolsB,
+    SupplementalMathematicalOperators,
+    MiscellaneousSymbolsAndArrows,
+    Glagolitic,
+    LatinExtendedC,
+    Coptic,
+    GeorgianSupplement,
+    Tifinagh,
+    EthiopicExtended,
+    CyrillicExtendedA,
+    SupplementalPunctuation,
+    CjkRadicalsSupplement,
+    KangxiRadicals,
+    IdeographicDescriptionCharacters,
+    CjkSymbolsAndPunctuation,
+    Hiragana,
+    Katakana,
+    Bopomofo,
+    HangulCompatibilityJamo,
+    Kanbun,
+    BopomofoExtended,
+    CjkStrokes,
+    KatakanaPhoneticExtensions,
+    EnclosedCjkLettersAndMonths,
+    CjkCompatibility,
+    CjkUnifiedIdeographsExtensionA,
+    YijingHexagramSymbols,
+    CjkUnifiedIdeographs,
+    YiSyllables,
+    YiRadicals,
+    Lisu,
+    Vai,
+    CyrillicExtendedB,
+    Bamum,
+    ModifierToneLetters,
+    LatinExtendedD,
+    SylotiNagri,
+    CommonIndicNumberForms,
+    PhagsPa,
+    Saurashtra,
+    DevanagariExtended,
+    KayahLi,
+    Rejang,
+    HangulJamoExtendedA,
+    Javanese,
+    MyanmarExtendedB,
+    Cham,
+    MyanmarExtendedA,
+    TaiViet,
+    MeeteiMayekExtensions,
+    EthiopicExtendedA,
+    LatinExtendedE,
+    CherokeeSupplement,
+    MeeteiMayek,
+    HangulSyllables,
+    HangulJamoExtendedB,
+    PrivateUseArea,
+    CjkCompatibilityIdeographs,
+    AlphabeticPresentationForms,
+    ArabicPresentationFormsA,
+    VariationSelectors,
+    VerticalForms,
+    CombiningHalfMarks,
+    CjkCompatibilityForms,
+    SmallFormVariants,
+    ArabicPresentationFormsB,
+    HalfwidthAndFullwidthForms,
+    Specials,
+    LinearBSyllabary,
+    LinearBIdeograms,
+    AegeanNumbers,
+    AncientGreekNumbers,
+    AncientSymbols,
+    PhaistosDisc,
+    Lycian,
+    Carian,
+    CopticEpactNumbers,
+    OldItalic,
+    Gothic,
+    OldPermic,
+    Ugaritic,
+    OldPersian,
+    Deseret,
+    Shavian,
+    Osmanya,
+    Osage,
+    Elbasan,
+    CaucasianAlbanian,
+    Vithkuqi,
+    Todhri,
+    LinearA,
+    LatinExtendedF,
+    CypriotSyllabary,
+    ImperialAramaic,
+    Palmyrene,
+    Nabataean,
+    Hatran,
+    Phoenician,
+    Lydian,
+    MeroiticHieroglyphs,
+    MeroiticCursive,
+    Kharoshthi,
+    OldSouthArabian,
+    OldNorthArabian,
+    Manichaean,
+    Avestan,
+    InscriptionalParthian,
+    InscriptionalPahlavi,
+    PsalterPahlavi,
+    OldTurkic,
+    OldHungarian,
+    HanifiRohingya,
+    Garay,
+    RumiNumeralSymbols,
+    Yezidi,
+    ArabicExtendedC,
+    OldSogdian,
+    Sogdian,
+    OldUyghur,
+    Chorasmian,
+    Elymaic,
+    Brahmi,
+    Kaithi,
+    SoraSompeng,
+    Chakma,
+    Mahajani,
+    Sharada,
+    SinhalaArchaicNumbers,
+    Khojki,
+    Multani,
+    Khudawadi,
+    Grantha,
+    TuluTigalari,
+    Newa,
+    Tirhuta,
+    Siddham,
+    Modi,
+    MongolianSupplement,
+    Takri,
+    MyanmarExtendedC,
+    Ahom,
+    Dogra,
+    WarangCiti,
+    DivesAkuru,
+    Nandinagari,
+    ZanabazarSquare,
+    Soyombo,
+    UnifiedCanadianAboriginalSyllabicsExtendedA,
+    PauCinHau,
+    DevanagariExtendedA,
+    Sunuwar,
+    Bhaiksuki,
+    Marchen,
+    MasaramGondi,
+    GunjalaGondi,
+    Makasar,
+    Kawi,
+    LisuSupplement,
+    TamilSupplement,
+    Cuneiform,
+    CuneiformNumbersAndPunctuation,
+    EarlyDynasticCuneiform,
+    CyproMinoan,
+    EgyptianHieroglyphs,
+    EgyptianHieroglyphFormatControls,
+    EgyptianHieroglyphsExtendedA,
+    AnatolianHieroglyphs,
+    GurungKhema,
+    BamumSupplement,
+    Mro,
+    Tangsa,
+    BassaVah,
+    PahawhHmong,
+    KiratRai,
+    Medefaidrin,
+    Miao,
+    IdeographicSymbolsAndPunctuation,
+    Tangut,
+    TangutComponents,
+    KhitanSmallScript,
+    TangutSupplement,
+    KanaExtendedB,
+    KanaSupplement,
+    KanaExtendedA,
+    SmallKanaExtension,
+    Nushu,
+    Duployan,
+    ShorthandFormatControls,
+    SymbolsForLegacyComputingSupplement,
+    ZnamennyMusicalNotation,
+    ByzantineMusicalSymbols,
+    MusicalSymbols,
+    AncientGreekMusicalNotation,
+    KaktovikNumerals,
+    MayanNumerals,
+    TaiXuanJingSymbols,
+    CountingRodNumerals,
+    MathematicalAlphanumericSymbols,
+    SuttonSignWriting,
+    LatinExtendedG,
+    GlagoliticSupplement,
+    CyrillicExtendedD,
+    NyiakengPuachueHmong,
+    Toto,
+    Wancho,
+    NagMundari,
+    OlOnal,
+    EthiopicExtendedB,
+    MendeKikakui,
+    Adlam,
+    IndicSiyaqNumbers,
+    OttomanSiyaqNumbers,
+    ArabicMathematicalAlphabeticSymbols,
+    MahjongTiles,
+    DominoTiles,
+    PlayingCards,
+    EnclosedAlphanumericSupplement,
+    EnclosedIdeographicSupplement,
+    MiscellaneousSymbolsAndPictographs,
+    Emoticons,
+    OrnamentalDingbats,
+    TransportAndMapSymbols,
+    AlchemicalSymbols,
+    GeometricShapesExtended,
+    SupplementalArrowsC,
+    SupplementalSymbolsAndPictographs,
+    ChessSymbols,
+    SymbolsAndPictographsExtendedA,
+    SymbolsForLegacyComputing,
+    CjkUnifiedIdeographsExtensionB,
+    CjkUnifiedIdeographsExtensionC,
+    CjkUnifiedIdeographsExtensionD,
+    CjkUnifiedIdeographsExtensionE,
+    CjkUnifiedIdeographsExtensionF,
+    CjkUnifiedIdeographsExtensionI,
+    CjkCompatibilityIdeographsSupplement,
+    CjkUnifiedIdeographsExtensionG,
+    CjkUnifiedIdeographsExtensionH,
+    Tags,
+    VariationSelectorsSupplement,
+    SupplementaryPrivateUseAreaA,
+    SupplementaryPrivateUseAreaB,
+}
+
+impl UnicodeBlock {
+    /// Every variant, in order of declaration, which is also the order of the blocks
+    pub const ALL: [UnicodeBlock; 335] = [
+        UnicodeBlock::BasicLatin,
+        UnicodeBlock::Latin1Supplement,
+        UnicodeBlock::LatinExtendedA,
+        UnicodeBlock::LatinExtendedB,
+        UnicodeBlock::IpaExtensions,
+        UnicodeBlock::SpacingModifierLetters,
+        UnicodeBlock::CombiningDiacriticalMarks,
+        UnicodeBlock::GreekAndCoptic,
+        UnicodeBlock::Cyrillic,
+        UnicodeBlock::CyrillicSupplement,
+        UnicodeBlock::Armenian,
+        UnicodeBlock::Hebrew,
+        UnicodeBlock::Arabic,
+        UnicodeBlock::Syriac,
+        UnicodeBlock::ArabicSupplement,
+        UnicodeBlock::Thaana,
+        UnicodeBlock::NKo,
+        UnicodeBlock::Samaritan,
+        UnicodeBlock::Mandaic,
+        UnicodeBlock::SyriacSupplement,
+        UnicodeBlock::ArabicExtendedB,
+        UnicodeBlock::ArabicExtendedA,
+        UnicodeBlock::Devanagari,
+        UnicodeBlock::Bengali,
+        UnicodeBlock::Gurmukhi,
+        UnicodeBlock::Gujarati,
+        UnicodeBlock::Oriya,
+        UnicodeBlock::Tamil,
+        UnicodeBlock::Telugu,
+        UnicodeBlock::Kannada,
+        UnicodeBlock::Malayalam,
+        UnicodeBlock::Sinhala,
+        UnicodeBlock::Thai,
+        UnicodeBlock::Lao,
+        UnicodeBlock::Tibetan,
+        UnicodeBlock::Myanmar,
+        UnicodeBlock::Georgian,
+        UnicodeBlock::HangulJamo,
+        UnicodeBlock::Ethiopic,
+        UnicodeBlock::EthiopicSupplement,
+        UnicodeBlock::Cherokee,
+        UnicodeBlock::UnifiedCanadianAboriginalSyllabics,
+        UnicodeBlock::Ogham,
+        UnicodeBlock::Runic,
+        UnicodeBlock::Tagalog,
+        UnicodeBlock::Hanunoo,
+        UnicodeBlock::Buhid,
+        UnicodeBlock::Tagbanwa,
+        UnicodeBlock::Khmer,
+        UnicodeBlock::Mongolian,
+        UnicodeBlock::UnifiedCanadianAboriginalSyllabicsExtended,
+        UnicodeBlock::Limbu,
+        UnicodeBlock::TaiLe,
+        UnicodeBlock::NewTaiLue,
+        UnicodeBlock::KhmerSymbols,
+        UnicodeBlock::Buginese,
+        UnicodeBlock::TaiTham,
+        UnicodeBlock::CombiningDiacriticalMarksExtended,
+        UnicodeBlock::Balinese,
+        UnicodeBlock::Sundanese,
+        UnicodeBlock::Batak,
+        UnicodeBlock::Lepcha,
+        UnicodeBlock::OlChiki,
+        UnicodeBlock::CyrillicExtendedC,
+        UnicodeBlock::GeorgianExtended,
+        UnicodeBlock::SundaneseSupplement,
+        UnicodeBlock::VedicExtensions,
+        UnicodeBlock::PhoneticExtensions,
+        UnicodeBlock::PhoneticExtensionsSupplement,
+        UnicodeBlock::CombiningDiacriticalMarksSupplement,
+        UnicodeBlock::LatinExtendedAdditional,
+        UnicodeBlock::GreekExtended,
+        UnicodeBlock::GeneralPunctuation,
+        UnicodeBlock::SuperscriptsAndSubscripts,
+        UnicodeBlock::CurrencySymbols,
+        UnicodeBlock::CombiningDiacriticalMarksForSymbols,
+        UnicodeBlock::LetterlikeSymbols,
+        UnicodeBlock::NumberForms,
+        UnicodeBlock::Arrows,
+        UnicodeBlock::MathematicalOperators,
+        UnicodeBlock::MiscellaneousTechnical,
+        UnicodeBlock::ControlPictures,
+        UnicodeBlock::OpticalCharacterRecognition,
+        UnicodeBlock::EnclosedAlphanumerics,
+        UnicodeBlock::BoxDrawing,
+        UnicodeBlock::BlockElements,
+        UnicodeBlock::GeometricShapes,
+        UnicodeBlock::MiscellaneousSymbols,
+        UnicodeBlock::Dingbats,
+        UnicodeBlock::MiscellaneousMathematicalSymbolsA,
+        UnicodeBlock::SupplementalArrowsA,
+        UnicodeBlock::BraillePatterns,
+        UnicodeBlock::SupplementalArrowsB,
+        UnicodeBlock::MiscellaneousMathematicalSymbolsB,
+        UnicodeBlock::SupplementalMathematicalOperators,
+        UnicodeBlock::MiscellaneousSymbolsAndArrows,
+        UnicodeBlock::Glagolitic,
+        UnicodeBlock::LatinExtendedC,
+        UnicodeBlock::Coptic,
+        UnicodeBlock::GeorgianSupplement,
+        UnicodeBlock::Tifinagh,
+        UnicodeBlock::EthiopicExtended,
+        UnicodeBlock::CyrillicExtendedA,
+        UnicodeBlock::SupplementalPunctuation,
+        UnicodeBlock::CjkRadicalsSupplement,
+        UnicodeBlock::KangxiRadicals,
+        UnicodeBlock::IdeographicDescriptionCharacters,
+        UnicodeBlock::CjkSymbolsAndPunctuation,
+        UnicodeBlock::Hiragana,
+        UnicodeBlock::Katakana,
+        UnicodeBlock::Bopomofo,
+        UnicodeBlock::HangulCompatibilityJamo,
+        UnicodeBlock::Kanbun,
+        UnicodeBlock::BopomofoExtended,
+        UnicodeBlock::CjkStrokes,
+        UnicodeBlock::KatakanaPhoneticExtensions,
+        UnicodeBlock::EnclosedCjkLettersAndMonths,
+        UnicodeBlock::CjkCompatibility,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionA,
+        UnicodeBlock::YijingHexagramSymbols,
+        UnicodeBlock::CjkUnifiedIdeographs,
+        UnicodeBlock::YiSyllables,
+        UnicodeBlock::YiRadicals,
+        UnicodeBlock::Lisu,
+        UnicodeBlock::Vai,
+        UnicodeBlock::CyrillicExtendedB,
+        UnicodeBlock::Bamum,
+        UnicodeBlock::ModifierToneLetters,
+        UnicodeBlock::LatinExtendedD,
+        UnicodeBlock::SylotiNagri,
+        UnicodeBlock::CommonIndicNumberForms,
+        UnicodeBlock::PhagsPa,
+        UnicodeBlock::Saurashtra,
+        UnicodeBlock::DevanagariExtended,
+        UnicodeBlock::KayahLi,
+        UnicodeBlock::Rejang,
+        UnicodeBlock::HangulJamoExtendedA,
+        UnicodeBlock::Javanese,
+        UnicodeBlock::MyanmarExtendedB,
+        UnicodeBlock::Cham,
+        UnicodeBlock::MyanmarExtendedA,
+        UnicodeBlock::TaiViet,
+        UnicodeBlock::MeeteiMayekExtensions,
+        UnicodeBlock::EthiopicExtendedA,
+        UnicodeBlock::LatinExtendedE,
+        UnicodeBlock::CherokeeSupplement,
+        UnicodeBlock::MeeteiMayek,
+        UnicodeBlock::HangulSyllables,
+        UnicodeBlock::HangulJamoExtendedB,
+        UnicodeBlock::PrivateUseArea,
+        UnicodeBlock::CjkCompatibilityIdeographs,
+        UnicodeBlock::AlphabeticPresentationForms,
+        UnicodeBlock::ArabicPresentationFormsA,
+        UnicodeBlock::VariationSelectors,
+        UnicodeBlock::VerticalForms,
+        UnicodeBlock::CombiningHalfMarks,
+        UnicodeBlock::CjkCompatibilityForms,
+        UnicodeBlock::SmallFormVariants,
+        UnicodeBlock::ArabicPresentationFormsB,
+        UnicodeBlock::HalfwidthAndFullwidthForms,
+        UnicodeBlock::Specials,
+        UnicodeBlock::LinearBSyllabary,
+        UnicodeBlock::LinearBIdeograms,
+        UnicodeBlock::AegeanNumbers,
+        UnicodeBlock::AncientGreekNumbers,
+        UnicodeBlock::AncientSymbols,
+        UnicodeBlock::PhaistosDisc,
+        UnicodeBlock::Lycian,
+        UnicodeBlock::Carian,
+        UnicodeBlock::CopticEpactNumbers,
+        UnicodeBlock::OldItalic,
+        UnicodeBlock::Gothic,
+        UnicodeBlock::OldPermic,
+        UnicodeBlock::Ugaritic,
+        UnicodeBlock::OldPersian,
+        UnicodeBlock::Deseret,
+        UnicodeBlock::Shavian,
+        UnicodeBlock::Osmanya,
+        UnicodeBlock::Osage,
+        UnicodeBlock::Elbasan,
+        UnicodeBlock::CaucasianAlbanian,
+        UnicodeBlock::Vithkuqi,
+        UnicodeBlock::Todhri,
+        UnicodeBlock::LinearA,
+        UnicodeBlock::LatinExtendedF,
+        UnicodeBlock::CypriotSyllabary,
+        UnicodeBlock::ImperialAramaic,
+        UnicodeBlock::Palmyrene,
+        UnicodeBlock::Nabataean,
+        UnicodeBlock::Hatran,
+        UnicodeBlock::Phoenician,
+        UnicodeBlock::Lydian,
+        UnicodeBlock::MeroiticHieroglyphs,
+        UnicodeBlock::MeroiticCursive,
+        UnicodeBlock::Kharoshthi,
+        UnicodeBlock::OldSouthArabian,
+        UnicodeBlock::OldNorthArabian,
+        UnicodeBlock::Manichaean,
+        UnicodeBlock::Avestan,
+        UnicodeBlock::InscriptionalParthian,
+        UnicodeBlock::InscriptionalPahlavi,
+        UnicodeBlock::PsalterPahlavi,
+        UnicodeBlock::OldTurkic,
+        UnicodeBlock::OldHungarian,
+        UnicodeBlock::HanifiRohingya,
+        UnicodeBlock::Garay,
+        UnicodeBlock::RumiNumeralSymbols,
+        UnicodeBlock::Yezidi,
+        UnicodeBlock::ArabicExtendedC,
+        UnicodeBlock::OldSogdian,
+        UnicodeBlock::Sogdian,
+        UnicodeBlock::OldUyghur,
+        UnicodeBlock::Chorasmian,
+        UnicodeBlock::Elymaic,
+        UnicodeBlock::Brahmi,
+        UnicodeBlock::Kaithi,
+        UnicodeBlock::SoraSompeng,
+        UnicodeBlock::Chakma,
+        UnicodeBlock::Mahajani,
+        UnicodeBlock::Sharada,
+        UnicodeBlock::SinhalaArchaicNumbers,
+        UnicodeBlock::Khojki,
+        UnicodeBlock::Multani,
+        UnicodeBlock::Khudawadi,
+        UnicodeBlock::Grantha,
+        UnicodeBlock::TuluTigalari,
+        UnicodeBlock::Newa,
+        UnicodeBlock::Tirhuta,
+        UnicodeBlock::Siddham,
+        UnicodeBlock::Modi,
+        UnicodeBlock::MongolianSupplement,
+        UnicodeBlock::Takri,
+        UnicodeBlock::MyanmarExtendedC,
+        UnicodeBlock::Ahom,
+        UnicodeBlock::Dogra,
+        UnicodeBlock::WarangCiti,
+        UnicodeBlock::DivesAkuru,
+        UnicodeBlock::Nandinagari,
+        UnicodeBlock::ZanabazarSquare,
+        UnicodeBlock::Soyombo,
+        UnicodeBlock::UnifiedCanadianAboriginalSyllabicsExtendedA,
+        UnicodeBlock::PauCinHau,
+        UnicodeBlock::DevanagariExtendedA,
+        UnicodeBlock::Sunuwar,
+        UnicodeBlock::Bhaiksuki,
+        UnicodeBlock::Marchen,
+        UnicodeBlock::MasaramGondi,
+        UnicodeBlock::GunjalaGondi,
+        UnicodeBlock::Makasar,
+        UnicodeBlock::Kawi,
+        UnicodeBlock::LisuSupplement,
+        UnicodeBlock::TamilSupplement,
+        UnicodeBlock::Cuneiform,
+        UnicodeBlock::CuneiformNumbersAndPunctuation,
+        UnicodeBlock::EarlyDynasticCuneiform,
+        UnicodeBlock::CyproMinoan,
+        UnicodeBlock::EgyptianHieroglyphs,
+        UnicodeBlock::EgyptianHieroglyphFormatControls,
+        UnicodeBlock::EgyptianHieroglyphsExtendedA,
+        UnicodeBlock::AnatolianHieroglyphs,
+        UnicodeBlock::GurungKhema,
+        UnicodeBlock::BamumSupplement,
+        UnicodeBlock::Mro,
+        UnicodeBlock::Tangsa,
+        UnicodeBlock::BassaVah,
+        UnicodeBlock::PahawhHmong,
+        UnicodeBlock::KiratRai,
+        UnicodeBlock::Medefaidrin,
+        UnicodeBlock::Miao,
+        UnicodeBlock::IdeographicSymbolsAndPunctuation,
+        UnicodeBlock::Tangut,
+        UnicodeBlock::TangutComponents,
+        UnicodeBlock::KhitanSmallScript,
+        UnicodeBlock::TangutSupplement,
+        UnicodeBlock::KanaExtendedB,
+        UnicodeBlock::KanaSupplement,
+        UnicodeBlock::KanaExtendedA,
+        UnicodeBlock::SmallKanaExtension,
+        UnicodeBlock::Nushu,
+        UnicodeBlock::Duployan,
+        UnicodeBlock::ShorthandFormatControls,
+        UnicodeBlock::SymbolsForLegacyComputingSupplement,
+        UnicodeBlock::ZnamennyMusicalNotation,
+        UnicodeBlock::ByzantineMusicalSymbols,
+        UnicodeBlock::MusicalSymbols,
+        UnicodeBlock::AncientGreekMusicalNotation,
+        UnicodeBlock::KaktovikNumerals,
+        UnicodeBlock::MayanNumerals,
+        UnicodeBlock::TaiXuanJingSymbols,
+        UnicodeBlock::CountingRodNumerals,
+        UnicodeBlock::MathematicalAlphanumericSymbols,
+        UnicodeBlock::SuttonSignWriting,
+        UnicodeBlock::LatinExtendedG,
+        UnicodeBlock::GlagoliticSupplement,
+        UnicodeBlock::CyrillicExtendedD,
+        UnicodeBlock::NyiakengPuachueHmong,
+        UnicodeBlock::Toto,
+        UnicodeBlock::Wancho,
+        UnicodeBlock::NagMundari,
+        UnicodeBlock::OlOnal,
+        UnicodeBlock::EthiopicExtendedB,
+        UnicodeBlock::MendeKikakui,
+        UnicodeBlock::Adlam,
+        UnicodeBlock::IndicSiyaqNumbers,
+        UnicodeBlock::OttomanSiyaqNumbers,
+        UnicodeBlock::ArabicMathematicalAlphabeticSymbols,
+        UnicodeBlock::MahjongTiles,
+        UnicodeBlock::DominoTiles,
+        UnicodeBlock::PlayingCards,
+        UnicodeBlock::EnclosedAlphanumericSupplement,
+        UnicodeBlock::EnclosedIdeographicSupplement,
+        UnicodeBlock::MiscellaneousSymbolsAndPictographs,
+        UnicodeBlock::Emoticons,
+        UnicodeBlock::OrnamentalDingbats,
+        UnicodeBlock::TransportAndMapSymbols,
+        UnicodeBlock::AlchemicalSymbols,
+        UnicodeBlock::GeometricShapesExtended,
+        UnicodeBlock::SupplementalArrowsC,
+        UnicodeBlock::SupplementalSymbolsAndPictographs,
+        UnicodeBlock::ChessSymbols,
+        UnicodeBlock::SymbolsAndPictographsExtendedA,
+        UnicodeBlock::SymbolsForLegacyComputing,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionB,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionC,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionD,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionE,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionF,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionI,
+        UnicodeBlock::CjkCompatibilityIdeographsSupplement,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionG,
+        UnicodeBlock::CjkUnifiedIdeographsExtensionH,
+        UnicodeBlock::Tags,
+        UnicodeBlock::VariationSelectorsSupplement,
+        UnicodeBlock::SupplementaryPrivateUseAreaA,
+        UnicodeBlock::SupplementaryPrivateUseAreaB,
+    ];
+
+    /// The name of the block in the Unicode character database
+    /// ```
+    /// use human_regex::UnicodeBlock;
+    /// assert_eq!(UnicodeBlock::Latin1Supplement.name(), "Latin-1 Supplement");
+    /// ```
+    pub fn name(self) -> &'static str {
+        BLOCKS[self as usize].0
+    }
+
+    /// The characters in the block
+    /// ```
+    /// use human_regex::UnicodeBlock;
+    /// assert_eq!(UnicodeBlock::BasicLatin.range(), '\0'..='\u{7F}');
+    /// ```
+    pub fn range(self) -> RangeInclusive<char> {
+        let (_, start, end) = BLOCKS[self as usize];
+        start..=end
+    }
+}
+
+/// The name, first and last character of every block, in the order of [UnicodeBlock]
+const BLOCKS: [(&str, char, char); 335] = [
+    ("Basic Latin", '\u{0}', '\u{7F}'),
+    ("Latin-1 Supplement", '\u{80}', '\u{FF}'),
+    ("Latin Extended-A", '\u{100}', '\u{17F}'),
+    ("Latin Extended-B", '\u{180}', '\u{24F}'),
+    ("IPA Extensions", '\u{250}', '\u{2AF}'),
+    ("Spacing Modifier Letters", '\u{2B0}', '\u{2FF}'),
+    ("Combining Diacritical Marks", '\u{300}', '\u{36F}'),
+    ("Greek and Coptic", '\u{370}', '\u{3FF}'),
+    ("Cyrillic", '\u{400}', '\u{4FF}'),
+    ("Cyrillic Supplement", '\u{500}', '\u{52F}'),
+    ("Armenian", '\u{530}', '\u{58F}'),
+    ("Hebrew", '\u{590}', '\u{5FF}'),
+    ("Arabic", '\u{600}', '\u{6FF}'),
+    ("Syriac", '\u{700}', '\u{74F}'),
+    ("Arabic Supplement", '\u{750}', '\u{77F}'),
+    ("Thaana", '\u{780}', '\u{7BF}'),
+    ("NKo", '\u{7C0}', '\u{7FF}'),
+    ("Samaritan", '\u{800}', '\u{83F}'),
+    ("Mandaic", '\u{840}', '\u{85F}'),
+    ("Syriac Supplement", '\u{860}', '\u{86F}'),
+    ("Arabic Extended-B", '\u{870}', '\u{89F}'),
+    ("Arabic Extended-A", '\u{8A0}', '\u{8FF}'),
+    ("Devanagari", '\u{900}', '\u{97F}'),
+    ("Bengali", '\u{980}', '\u{9FF}'),
+    ("Gurmukhi", '\u{A00}', '\u{A7F}'),
+    ("Gujarati", '\u{A80}', '\u{AFF}'),
+    ("Oriya", '\u{B00}', '\u{B7F}'),
+    ("Tamil", '\u{B80}', '\u{BFF}'),
+    ("Telugu", '\u{C00}', '\u{C7F}'),
+    ("Kannada", '\u{C80}', '\u{CFF}'),
+    ("Malayalam", '\u{D00}', '\u{D7F}'),
+    ("Sinhala", '\u{D80}', '\u{DFF}'),
+    ("Thai", '\u{E00}', '\u{E7F}'),
+    ("Lao", '\u{E80}', '\u{EFF}'),
+    ("Tibetan", '\u{F00}', '\u{FFF}'),
+    ("Myanmar", '\u{1000}', '\u{109F}'),
+    ("Georgian", '\u{10A0}', '\u{10FF}'),
+    ("Hangul Jamo", '\u{1100}', '\u{11FF}'),
+    ("Ethiopic", '\u{1200}', '\u{137F}'),
+    ("Ethiopic Supplement", '\u{1380}', '\u{139F}'),
+    ("Cherokee", '\u{13A0}', '\u{13FF}'),
+    (
+        "Unified Canadian Aboriginal Syllabics",
+        '\u{1400}',
+        '\u{167F}',
+    ),
+    ("Ogham", '\u{1680}', '\u{169F}'),
+    ("Runic", '\u{16A0}', '\u{16FF}'),
+    ("Tagalog", '\u{1700}', '\u{171F}'),
+    ("Hanunoo", '\u{1720}', '\u{173F}'),
+    ("Buhid", '\u{1740}', '\u{175F}'),
+    ("Tagbanwa", '\u{1760}', '\u{177F}'),
+    ("Khmer", '\u{1780}', '\u{17FF}'),
+    ("Mongolian", '\u{1800}', '\u{18AF}'),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended",
+        '\u{18B0}',
+        '\u{18FF}',
+    ),
+    ("Limbu", '\u{1900}', '\u{194F}'),
+    ("Tai Le", '\u{1950}', '\u{197F}'),
+    ("New Tai Lue", '\u{1980}', '\u{19DF}'),
+    ("Khmer Symbols", '\u{19E0}', '\u{19FF}'),
+    ("Buginese", '\u{1A00}', '\u{1A1F}'),
+    ("Tai Tham", '\u{1A20}', '\u{1AAF}'),
+    (
+        "Combining Diacritical Marks Extended",
+        '\u{1AB0}',
+        '\u{1AFF}',
+    ),
+    ("Balinese", '\u{1B00}', '\u{1B7F}'),
+    ("Sundanese", '\u{1B80}', '\u{1BBF}'),
+    ("Batak", '\u{1BC0}', '\u{1BFF}'),
+    ("Lepcha", '\u{1C00}', '\u{1C4F}'),
+    ("Ol Chiki", '\u{1C50}', '\u{1C7F}'),
+    ("Cyrillic Extended-C", '\u{1C80}', '\u{1C8F}'),
+    ("Georgian Extended", '\u{1C90}', '\u{1CBF}'),
+    ("Sundanese Supplement", '\u{1CC0}', '\u{1CCF}'),
+    ("Vedic Extensions", '\u{1CD0}', '\u{1CFF}'),
+    ("Phonetic Extensions", '\u{1D00}', '\u{1D7F}'),
+    ("Phonetic Extensions Supplement", '\u{1D80}', '\u{1DBF}'),
+    (
+        "Combining Diacritical Marks Supplement",
+        '\u{1DC0}',
+        '\u{1DFF}',
+    ),
+    ("Latin Extended Additional", '\u{1E00}', '\u{1EFF}'),
+    ("Greek Extended", '\u{1F00}', '\u{1FFF}'),
+    ("General Punctuation", '\u{2000}', '\u{206F}'),
+    ("Superscripts and Subscripts", '\u{2070}', '\u{209F}'),
+    ("Currency Symbols", '\u{20A0}', '\u{20CF}'),
+    (
+        "Combining Diacritical Marks for Symbols",
+        '\u{20D0}',
+        '\u{20FF}',
+    ),
+    ("Letterlike Symbols", '\u{2100}', '\u{214F}'),
+    ("Number Forms", '\u{2150}', '\u{218F}'),
+    ("Arrows", '\u{2190}', '\u{21FF}'),
+    ("Mathematical Operators", '\u{2200}', '\u{22FF}'),
+    ("Miscellaneous Technical", '\u{2300}', '\u{23FF}'),
+    ("Control Pictures", '\u{2400}', '\u{243F}'),
+    ("Optical Character Recognition", '\u{2440}', '\u{245F}'),
+    ("Enclosed Alphanumerics", '\u{2460}', '\u{24FF}'),
+    ("Box Drawing", '\u{2500}', '\u{257F}'),
+    ("Block Elements", '\u{2580}', '\u{259F}'),
+    ("Geometric Shapes", '\u{25A0}', '\u{25FF}'),
+    ("Miscellaneous Symbols", '\u{2600}', '\u{26FF}'),
+    ("Dingbats", '\u{2700}', '\u{27BF}'),
+    (
+        "Miscellaneous Mathematical Symbols-A",
+        '\u{27C0}',
+        '\u{27EF}',
+    ),
+    ("Supplemental Arrows-A", '\u{27F0}', '\u{27FF}'),
+    ("Braille Patterns", '\u{2800}', '\u{28FF}'),
+    ("Supplemental Arrows-B", '\u{2900}', '\u{297F}'),
+    (
+        "Miscellaneous Mathematical Symbols-B",
+        '\u{2980}',
+        '\u{29FF}',
+    ),
+    (
+        "Supplemental Mathematical Operators",
+        '\u{2A00}',
+        '\u{2AFF}',
+    ),
+    ("Miscellaneous Symbols and Arrows", '\u{2B00}', '\u{2BFF}'),
+    ("Glagolitic", '\u{2C00}', '\u{2C5F}'),
+    ("Latin Extended-C", '\u{2C60}', '\u{2C7F}'),
+    ("Coptic", '\u{2C80}', '\u{2CFF}'),
+    ("Georgian Supplement", '\u{2D00}', '\u{2D2F}'),
+    ("Tifinagh", '\u{2D30}', '\u{2D7F}'),
+    ("Ethiopic Extended", '\u{2D80}', '\u{2DDF}'),
+    ("Cyrillic Extended-A", '\u{2DE0}', '\u{2DFF}'),
+    ("Supplemental Punctuation", '\u{2E00}', '\u{2E7F}'),
+    ("CJK Radicals Supplement", '\u{2E80}', '\u{2EFF}'),
+    ("Kangxi Radicals", '\u{2F00}', '\u{2FDF}'),
+    ("Ideographic Description Characters", '\u{2FF0}', '\u{2FFF}'),
+    ("CJK Symbols and Punctuation", '\u{3000}', '\u{303F}'),
+    ("Hiragana", '\u{3040}', '\u{309F}'),
+    ("Katakana", '\u{30A0}', '\u{30FF}'),
+    ("Bopomofo", '\u{3100}', '\u{312F}'),
+    ("Hangul Compatibility Jamo", '\u{3130}', '\u{318F}'),
+    ("Kanbun", '\u{3190}', '\u{319F}'),
+    ("Bopomofo Extended", '\u{31A0}', '\u{31BF}'),
+    ("CJK Strokes", '\u{31C0}', '\u{31EF}'),
+    ("Katakana Phonetic Extensions", '\u{31F0}', '\u{31FF}'),
+    ("Enclosed CJK Letters and Months", '\u{3200}', '\u{32FF}'),
+    ("CJK Compatibility", '\u{3300}', '\u{33FF}'),
+    ("CJK Unified Ideographs Extension A", '\u{3400}', '\u{4DBF}'),
+    ("Yijing Hexagram Symbols", '\u{4DC0}', '\u{4DFF}'),
+    ("CJK Unified Ideographs", '\u{4E00}', '\u{9FFF}'),
+    ("Yi Syllables", '\u{A000}', '\u{A48F}'),
+    ("Yi Radicals", '\u{A490}', '\u{A4CF}'),
+    ("Lisu", '\u{A4D0}', '\u{A4FF}'),
+    ("Vai", '\u{A500}', '\u{A63F}'),
+    ("Cyrillic Extended-B", '\u{A640}', '\u{A69F}'),
+    ("Bamum", '\u{A6A0}', '\u{A6FF}'),
+    ("Modifier Tone Letters", '\u{A700}', '\u{A71F}'),
+    ("Latin Extended-D", '\u{A720}', '\u{A7FF}'),
+    ("Syloti Nagri", '\u{A800}', '\u{A82F}'),
+    ("Common Indic Number Forms", '\u{A830}', '\u{A83F}'),
+    ("Phags-pa", '\u{A840}', '\u{A87F}'),
+    ("Saurashtra", '\u{A880}', '\u{A8DF}'),
+    ("Devanagari Extended", '\u{A8E0}', '\u{A8FF}'),
+    ("Kayah Li", '\u{A900}', '\u{A92F}'),
+    ("Rejang", '\u{A930}', '\u{A95F}'),
+    ("Hangul Jamo Extended-A", '\u{A960}', '\u{A97F}'),
+    ("Javanese", '\u{A980}', '\u{A9DF}'),
+    ("Myanmar Extended-B", '\u{A9E0}', '\u{A9FF}'),
+    ("Cham", '\u{AA00}', '\u{AA5F}'),
+    ("Myanmar Extended-A", '\u{AA60}', '\u{AA7F}'),
+    ("Tai Viet", '\u{AA80}', '\u{AADF}'),
+    ("Meetei Mayek Extensions", '\u{AAE0}', '\u{AAFF}'),
+    ("Ethiopic Extended-A", '\u{AB00}', '\u{AB2F}'),
+    ("Latin Extended-E", '\u{AB30}', '\u{AB6F}'),
+    ("Cherokee Supplement", '\u{AB70}', '\u{ABBF}'),
+    ("Meetei Mayek", '\u{ABC0}', '\u{ABFF}'),
+    ("Hangul Syllables", '\u{AC00}', '\u{D7AF}'),
+    ("Hangul Jamo Extended-B", '\u{D7B0}', '\u{D7FF}'),
+    ("Private Use Area", '\u{E000}', '\u{F8FF}'),
+    ("CJK Compatibility Ideographs", '\u{F900}', '\u{FAFF}'),
+    ("Alphabetic Presentation Forms", '\u{FB00}', '\u{FB4F}'),
+    ("Arabic Presentation Forms-A", '\u{FB50}', '\u{FDFF}'),
+    ("Variation Selectors", '\u{FE00}', '\u{FE0F}'),
+    ("Vertical Forms", '\u{FE10}', '\u{FE1F}'),
+    ("Combining Half Marks", '\u{FE20}', '\u{FE2F}'),
+    ("CJK Compatibility Forms", '\u{FE30}', '\u{FE4F}'),
+    ("Small Form Variants", '\u{FE50}', '\u{FE6F}'),
+    ("Arabic Presentation Forms-B", '\u{FE70}', '\u{FEFF}'),
+    ("Halfwidth and Fullwidth Forms", '\u{FF00}', '\u{FFEF}'),
+    ("Specials", '\u{FFF0}', '\u{FFFF}'),
+    ("Linear B Syllabary", '\u{10000}', '\u{1007F}'),
+    ("Linear B Ideograms", '\u{10080}', '\u{100FF}'),
+    ("Aegean Numbers", '\u{10100}', '\u{1013F}'),
+    ("Ancient Greek Numbers", '\u{10140}', '\u{1018F}'),
+    ("Ancient Symbols", '\u{10190}', '\u{101CF}'),
+    ("Phaistos Disc", '\u{101D0}', '\u{101FF}'),
+    ("Lycian", '\u{10280}', '\u{1029F}'),
+    ("Carian", '\u{102A0}', '\u{102DF}'),
+    ("Coptic Epact Numbers", '\u{102E0}', '\u{102FF}'),
+    ("Old Italic", '\u{10300}', '\u{1032F}'),
+    ("Gothic", '\u{10330}', '\u{1034F}'),
+    ("Old Permic", '\u{10350}', '\u{1037F}'),
+    ("Ugaritic", '\u{10380}', '\u{1039F}'),
+    ("Old Persian", '\u{103A0}', '\u{103DF}'),
+    ("Deseret", '\u{10400}', '\u{1044F}'),
+    ("Shavian", '\u{10450}', '\u{1047F}'),
+    ("Osmanya", '\u{10480}', '\u{104AF}'),
+    ("Osage", '\u{104B0}', '\u{104FF}'),
+    ("Elbasan", '\u{10500}', '\u{1052F}'),
+    ("Caucasian Albanian", '\u{10530}', '\u{1056F}'),
+    ("Vithkuqi", '\u{10570}', '\u{105BF}'),
+    ("Todhri", '\u{105C0}', '\u{105FF}'),
+    ("Linear A", '\u{10600}', '\u{1077F}'),
+    ("Latin Extended-F", '\u{10780}', '\u{107BF}'),
+    ("Cypriot Syllabary", '\u{10800}', '\u{1083F}'),
+    ("Imperial Aramaic", '\u{10840}', '\u{1085F}'),
+    ("Palmyrene", '\u{10860}', '\u{1087F}'),
+    ("Nabataean", '\u{10880}', '\u{108AF}'),
+    ("Hatran", '\u{108E0}', '\u{108FF}'),
+    ("Phoenician", '\u{10900}', '\u{1091F}'),
+    ("Lydian", '\u{10920}', '\u{1093F}'),
+    ("Meroitic Hieroglyphs", '\u{10980}', '\u{1099F}'),
+    ("Meroitic Cursive", '\u{109A0}', '\u{109FF}'),
+    ("Kharoshthi", '\u{10A00}', '\u{10A5F}'),
+    ("Old South Arabian", '\u{10A60}', '\u{10A7F}'),
+    ("Old North Arabian", '\u{10A80}', '\u{10A9F}'),
+    ("Manichaean", '\u{10AC0}', '\u{10AFF}'),
+    ("Avestan", '\u{10B00}', '\u{10B3F}'),
+    ("Inscriptional Parthian", '\u{10B40}', '\u{10B5F}'),
+    ("Inscriptional Pahlavi", '\u{10B60}', '\u{10B7F}'),
+    ("Psalter Pahlavi", '\u{10B80}', '\u{10BAF}'),
+    ("Old Turkic", '\u{10C00}', '\u{10C4F}'),
+    ("Old Hungarian", '\u{10C80}', '\u{10CFF}'),
+    ("Hanifi Rohingya", '\u{10D00}', '\u{10D3F}'),
+    ("Garay", '\u{10D40}', '\u{10D8F}'),
+    ("Rumi Numeral Symbols", '\u{10E60}', '\u{10E7F}'),
+    ("Yezidi", '\u{10E80}', '\u{10EBF}'),
+    ("Arabic Extended-C", '\u{10EC0}', '\u{10EFF}'),
+    ("Old Sogdian", '\u{10F00}', '\u{10F2F}'),
+    ("Sogdian", '\u{10F30}', '\u{10F6F}'),
+    ("Old Uyghur", '\u{10F70}', '\u{10FAF}'),
+    ("Chorasmian", '\u{10FB0}', '\u{10FDF}'),
+    ("Elymaic", '\u{10FE0}', '\u{10FFF}'),
+    ("Brahmi", '\u{11000}', '\u{1107F}'),
+    ("Kaithi", '\u{11080}', '\u{110CF}'),
+    ("Sora Sompeng", '\u{110D0}', '\u{110FF}'),
+    ("Chakma", '\u{11100}', '\u{1114F}'),
+    ("Mahajani", '\u{11150}', '\u{1117F}'),
+    ("Sharada", '\u{11180}', '\u{111DF}'),
+    ("Sinhala Archaic Numbers", '\u{111E0}', '\u{111FF}'),
+    ("Khojki", '\u{11200}', '\u{1124F}'),
+    ("Multani", '\u{11280}', '\u{112AF}'),
+    ("Khudawadi", '\u{112B0}', '\u{112FF}'),
+    ("Grantha", '\u{11300}', '\u{1137F}'),
+    ("Tulu-Tigalari", '\u{11380}', '\u{113FF}'),
+    ("Newa", '\u{11400}', '\u{1147F}'),
+    ("Tirhuta", '\u{11480}', '\u{114DF}'),
+    ("Siddham", '\u{11580}', '\u{115FF}'),
+    ("Modi", '\u{11600}', '\u{1165F}'),
+    ("Mongolian Supplement", '\u{11660}', '\u{1167F}'),
+    ("Takri", '\u{11680}', '\u{116CF}'),
+    ("Myanmar Extended-C", '\u{116D0}', '\u{116FF}'),
+    ("Ahom", '\u{11700}', '\u{1174F}'),
+    ("Dogra", '\u{11800}', '\u{1184F}'),
+    ("Warang Citi", '\u{118A0}', '\u{118FF}'),
+    ("Dives Akuru", '\u{11900}', '\u{1195F}'),
+    ("Nandinagari", '\u{119A0}', '\u{119FF}'),
+    ("Zanabazar Square", '\u{11A00}', '\u{11A4F}'),
+    ("Soyombo", '\u{11A50}', '\u{11AAF}'),
+    (
+        "Unified Canadian Aboriginal Syllabics Extended-A",
+        '\u{11AB0}',
+        '\u{11ABF}',
+    ),
+    ("Pau Cin Hau", '\u{11AC0}', '\u{11AFF}'),
+    ("Devanagari Extended-A", '\u{11B00}', '\u{11B5F}'),
+    ("Sunuwar", '\u{11BC0}', '\u{11BFF}'),
+    ("Bhaiksuki", '\u{11C00}', '\u{11C6F}'),
+    ("Marchen", '\u{11C70}', '\u{11CBF}'),
+    ("Masaram Gondi", '\u{11D00}', '\u{11D5F}'),
+    ("Gunjala Gondi", '\u{11D60}', '\u{11DAF}'),
+    ("Makasar", '\u{11EE0}', '\u{11EFF}'),
+    ("Kawi", '\u{11F00}', '\u{11F5F}'),
+    ("Lisu Supplement", '\u{11FB0}', '\u{11FBF}'),
+    ("Tamil Supplement", '\u{11FC0}', '\u{11FFF}'),
+    ("Cuneiform", '\u{12000}', '\u{123FF}'),
+    (
+        "Cuneiform Numbers and Punctuation",
+        '\u{12400}',
+        '\u{1247F}',
+    ),
+    ("Early Dynastic Cuneiform", '\u{12480}', '\u{1254F}'),
+    ("Cypro-Minoan", '\u{12F90}', '\u{12FFF}'),
+    ("Egyptian Hieroglyphs", '\u{13000}', '\u{1342F}'),
+    (
+        "Egyptian Hieroglyph Format Controls",
+        '\u{13430}',
+        '\u{1345F}',
+    ),
+    ("Egyptian Hieroglyphs Extended-A", '\u{13460}', '\u{143FF}'),
+    ("Anatolian Hieroglyphs", '\u{14400}', '\u{1467F}'),
+    ("Gurung Khema", '\u{16100}', '\u{1613F}'),
+    ("Bamum Supplement", '\u{16800}', '\u{16A3F}'),
+    ("Mro", '\u{16A40}', '\u{16A6F}'),
+    ("Tangsa", '\u{16A70}', '\u{16ACF}'),
+    ("Bassa Vah", '\u{16AD0}', '\u{16AFF}'),
+    ("Pahawh Hmong", '\u{16B00}', '\u{16B8F}'),
+    ("Kirat Rai", '\u{16D40}', '\u{16D7F}'),
+    ("Medefaidrin", '\u{16E40}', '\u{16E9F}'),
+    ("Miao", '\u{16F00}', '\u{16F9F}'),
+    (
+        "Ideographic Symbols and Punctuation",
+        '\u{16FE0}',
+        '\u{16FFF}',
+    ),
+    ("Tangut", '\u{17000}', '\u{187FF}'),
+    ("Tangut Components", '\u{18800}', '\u{18AFF}'),
+    ("Khitan Small Script", '\u{18B00}', '\u{18CFF}'),
+    ("Tangut Supplement", '\u{18D00}', '\u{18D7F}'),
+    ("Kana Extended-B", '\u{1AFF0}', '\u{1AFFF}'),
+    ("Kana Supplement", '\u{1B000}', '\u{1B0FF}'),
+    ("Kana Extended-A", '\u{1B100}', '\u{1B12F}'),
+    ("Small Kana Extension", '\u{1B130}', '\u{1B16F}'),
+    ("Nushu", '\u{1B170}', '\u{1B2FF}'),
+    ("Duployan", '\u{1BC00}', '\u{1BC9F}'),
+    ("Shorthand Format Controls", '\u{1BCA0}', '\u{1BCAF}'),
+    (
+        "Symbols for Legacy Computing Supplement",
+        '\u{1CC00}',
+        '\u{1CEBF}',
+    ),
+    ("Znamenny Musical Notation", '\u{1CF00}', '\u{1CFCF}'),
+    ("Byzantine Musical Symbols", '\u{1D000}', '\u{1D0FF}'),
+    ("Musical Symbols", '\u{1D100}', '\u{1D1FF}'),
+    ("Ancient Greek Musical Notation", '\u{1D200}', '\u{1D24F}'),
+    ("Kaktovik Numerals", '\u{1D2C0}', '\u{1D2DF}'),
+    ("Mayan Numerals", '\u{1D2E0}', '\u{1D2FF}'),
+    ("Tai Xuan Jing Symbols", '\u{1D300}', '\u{1D35F}'),
+    ("Counting Rod Numerals", '\u{1D360}', '\u{1D37F}'),
+    (
+        "Mathematical Alphanumeric Symbols",
+        '\u{1D400}',
+        '\u{1D7FF}',
+    ),
+    ("Sutton SignWriting", '\u{1D800}', '\u{1DAAF}'),
+    ("Latin Extended-G", '\u{1DF00}', '\u{1DFFF}'),
+    ("Glagolitic Supplement", '\u{1E000}', '\u{1E02F}'),
+    ("Cyrillic Extended-D", '\u{1E030}', '\u{1E08F}'),
+    ("Nyiakeng Puachue Hmong", '\u{1E100}', '\u{1E14F}'),
+    ("Toto", '\u{1E290}', '\u{1E2BF}'),
+    ("Wancho", '\u{1E2C0}', '\u{1E2FF}'),
+    ("Nag Mundari", '\u{1E4D0}', '\u{1E4FF}'),
+    ("Ol Onal", '\u{1E5D0}', '\u{1E5FF}'),
+    ("Ethiopic Extended-B", '\u{1E7E0}', '\u{1E7FF}'),
+    ("Mende Kikakui", '\u{1E800}', '\u{1E8DF}'),
+    ("Adlam", '\u{1E900}', '\u{1E95F}'),
+    ("Indic Siyaq Numbers", '\u{1EC70}', '\u{1ECBF}'),
+    ("Ottoman Siyaq Numbers", '\u{1ED00}', '\u{1ED4F}'),
+    (
+        "Arabic Mathematical Alphabetic Symbols",
+        '\u{1EE00}',
+        '\u{1EEFF}',
+    ),
+    ("Mahjong Tiles", '\u{1F000}', '\u{1F02F}'),
+    ("Domino Tiles", '\u{1F030}', '\u{1F09F}'),
+    ("Playing Cards", '\u{1F0A0}', '\u{1F0FF}'),
+    ("Enclosed Alphanumeric Supplement", '\u{1F100}', '\u{1F1FF}'),
+    ("Enclosed Ideographic Supplement", '\u{1F200}', '\u{1F2FF}'),
+    (
+        "Miscellaneous Symbols and Pictographs",
+        '\u{1F300}',
+        '\u{1F5FF}',
+    ),
+    ("Emoticons", '\u{1F600}', '\u{1F64F}'),
+    ("Ornamental Dingbats", '\u{1F650}', '\u{1F67F}'),
+    ("Transport and Map Symbols", '\u{1F680}', '\u{1F6FF}'),
+    ("Alchemical Symbols", '\u{1F700}', '\u{1F77F}'),
+    ("Geometric Shapes Extended", '\u{1F780}', '\u{1F7FF}'),
+    ("Supplemental Arrows-C", '\u{1F800}', '\u{1F8FF}'),
+    (
+        "Supplemental Symbols and Pictographs",
+        '\u{1F900}',
+        '\u{1F9FF}',
+    ),
+    ("Chess Symbols", '\u{1FA00}', '\u{1FA6F}'),
+    (
+        "Symbols and Pictographs Extended-A",
+        '\u{1FA70}',
+        '\u{1FAFF}',
+    ),
+    ("Symbols for Legacy Computing", '\u{1FB00}', '\u{1FBFF}'),
+    (
+        "CJK Unified Ideographs Extension B",
+        '\u{20000}',
+        '\u{2A6DF}',
+    ),
+    (
+        "CJK Unified Ideographs Extension C",
+        '\u{2A700}',
+        '\u{2B73F}',
+    ),
+    (
+        "CJK Unified Ideographs Extension D",
+        '\u{2B740}',
+        '\u{2B81F}',
+    ),
+    (
+        "CJK Unified Ideographs Extension E",
+        '\u{2B820}',
+        '\u{2CEAF}',
+    ),
+    (
+        "CJK Unified Ideographs Extension F",
+        '\u{2CEB0}',
+        '\u{2EBEF}',
+    ),
+    (
+        "CJK Unified Ideographs Extension I",
+        '\u{2EBF0}',
+        '\u{2EE5F}',
+    ),
+    (
+        "CJK Compatibility Ideographs Supplement",
+        '\u{2F800}',
+        '\u{2FA1F}',
+    ),
+    (
+        "CJK Unified Ideographs Extension G",
+        '\u{30000}',
+        '\u{3134F}',
+    ),
+    (
+        "CJK Unified Ideographs Extension H",
+        '\u{31350}',
+        '\u{323AF}',
+    ),
+    ("Tags", '\u{E0000}', '\u{E007F}'),
+    ("Variation Selectors Supplement", '\u{E0100}', '\u{E01EF}'),
+    ("Supplementary Private Use Area-A", '\u{F0000}', '\u{FFFFF}'),
+    (
+        "Supplementary Private Use Area-B",
+        '\u{100000}',
+        '\u{10FFFF}',
+    ),
+];
+
+/// A function for matching the characters in a Unicode block, whether or not they are assigned yet
+/// ```
+/// use human_regex::{beginning, end, one_or_more, subtract, unicode_block, unicode_script, UnicodeBlock, UnicodeScript};
+/// let regex_string = beginning()
+///     + one_or_more(unicode_block(UnicodeBlock::MathematicalOperators))
+///     + end();
+/// assert!(regex_string.to_regex().is_match("∀∃∑"));
+/// assert!(!regex_string.to_regex().is_match("+"));
+/// let cjk = subtract(
+///     unicode_block(UnicodeBlock::CjkUnifiedIdeographs),
+///     unicode_script(UnicodeScript::Han),
+/// );
+/// assert!(!cjk.to_regex().is_match("蟹"));
+/// ```
+pub fn unicode_block(block: UnicodeBlock) -> HumanRegex<SymbolClass<Custom>> {
+    CharSet::from(block.range()).to_class()
+}
+
+/// A function for matching the characters outside of a Unicode block
+/// ```
+/// use human_regex::{non_unicode_block, UnicodeBlock};
+/// let regex_string = non_unicode_block(UnicodeBlock::BasicLatin);
+/// assert!(regex_string.to_regex().is_match("é"));
+/// assert!(!regex_string.to_regex().is_match("e"));
+/// ```
+pub fn non_unicode_block(block: UnicodeBlock) -> HumanRegex<SymbolClass<Custom>> {
+    !unicode_block(block)
+}
@@ -0,0 +1,62 @@
+//! Functions for matching raw bytes in haystacks that are not valid UTF-8
+//!
+//! Each of these functions produces a [ByteClass] that carries its own Unicode-disabled scope
+//! (exactly as if it were wrapped in [disable_unicode](crate::disable_unicode)),
+//! so anything built from them can only be compiled with [HumanRegex::to_bytes_regex].
+//! Trying to call `to_regex` on such an expression is a compile error:
+//! ```compile_fail
+//! use human_regex::{byte, text};
+//! let regex_string = text("abc") + byte(0xFF);
+//! regex_string.to_regex();
+//! ```
+
+use super::humanregex::*;
+use std::marker::PhantomData as pd;
+
+/// A function for matching a single raw byte
+/// ```
+/// use human_regex::{byte, text};
+/// let regex_string = byte(0xFF) + text("abc");
+/// assert!(regex_string.to_bytes_regex().is_match(b"\xFFabc"));
+/// assert!(!regex_string.to_bytes_regex().is_match(b"\xFEabc"));
+/// ```
+pub fn byte(value: u8) -> HumanRegex<ByteClass> {
+    HumanRegex(format!(r"(?-u:[\x{:02X}])", value), pd::<ByteClass>)
+}
+
+/// A function for matching any raw byte within a range of bytes
+/// ```
+/// use human_regex::{beginning, byte_range, end, exactly};
+/// let regex_string = beginning() + exactly(2, byte_range(0x80..=0xBF)) + end();
+/// assert!(regex_string.to_bytes_regex().is_match(b"\x80\xBF"));
+/// assert!(!regex_string.to_bytes_regex().is_match(b"\x80\xC0"));
+/// ```
+/// # Panics
+/// If the range is empty
+/// ```should_panic
+/// use human_regex::byte_range;
+/// byte_range(0xC0..=0x80);
+/// ```
+pub fn byte_range(range: std::ops::RangeInclusive<u8>) -> HumanRegex<ByteClass> {
+    assert!(
+        range.start() <= range.end(),
+        "the range of bytes 0x{:02X}..=0x{:02X} is empty",
+        range.start(),
+        range.end()
+    );
+    HumanRegex(
+        format!(r"(?-u:[\x{:02X}-\x{:02X}])", range.start(), range.end()),
+        pd::<ByteClass>,
+    )
+}
+
+/// A function for matching any raw byte at all, including `\n`
+/// ```
+/// use human_regex::{any_byte, beginning, end, exactly};
+/// let regex_string = beginning() + exactly(3, any_byte()) + end();
+/// assert!(regex_string.to_bytes_regex().is_match(b"\x00\n\xFF"));
+/// assert!(!regex_string.to_bytes_regex().is_match(b"\x00\n"));
+/// ```
+pub fn any_byte() -> HumanRegex<ByteClass> {
+    byte_range(0x00..=0xFF)
+}
@@ -18,9 +18,8 @@ use std::marker::PhantomData as pd;
 /// assert_eq!("03", caps.get(2).unwrap().as_str());
 /// assert_eq!("14", caps.get(3).unwrap().as_str());
 /// ```
-
-pub fn capture<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("({})", target), pd::<SymbolChain>)
+pub fn capture<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("({})", target), pd::<T::Chain>)
 }
 
 /// Add a named capturing group around an expression
@@ -37,6 +36,6 @@ pub fn capture<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
 /// assert_eq!("03", &caps["month"]);
 /// assert_eq!("14", &caps["day"]);
 /// ```
-pub fn named_capture<T>(target: HumanRegex<T>, name: &str) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?P<{}>{})", name, target), pd::<SymbolChain>)
+pub fn named_capture<T: State>(target: HumanRegex<T>, name: &str) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?P<{}>{})", name, target), pd::<T::Chain>)
 }
@@ -0,0 +1,413 @@
+//! A character set value type with eager set algebra
+//!
+//! [and](crate::and), [xor](crate::xor) and [subtract](crate::subtract) build class syntax that the
+//! regex engine evaluates later, so the result can only be inspected by compiling it. A [CharSet]
+//! holds the characters themselves as sorted ranges, so set operations happen immediately, the result
+//! can be queried with [CharSet::contains] or iterated, and it renders as the shortest class that
+//! matches exactly those characters.
+
+use super::humanregex::*;
+use super::syntax::{self, FlagState};
+use std::fmt;
+use std::marker::PhantomData as pd;
+use std::ops::RangeInclusive;
+
+/// A set of Unicode scalar values, stored as sorted, non-overlapping, non-adjacent ranges
+/// ```
+/// use human_regex::CharSet;
+/// let vowels: CharSet = "aeiou".chars().collect();
+/// let consonants = CharSet::from('a'..='z') - &vowels;
+/// assert!(consonants.contains('x'));
+/// assert!(!consonants.contains('e'));
+/// assert_eq!(consonants.len(), 21);
+/// assert_eq!(consonants.to_string(), "[b-df-hj-np-tv-z]");
+/// assert!(consonants.to_class().to_regex().is_match("q"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    /// The ranges in the set, sorted, and merged whenever they overlap or touch
+    ranges: Vec<(char, char)>,
+}
+
+impl CharSet {
+    /// Creates an empty set
+    /// ```
+    /// use human_regex::CharSet;
+    /// assert!(CharSet::new().is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the set of every character
+    /// ```
+    /// use human_regex::CharSet;
+    /// assert_eq!(CharSet::all().len(), 0x10FFFF + 1 - 0x800);
+    /// ```
+    pub fn all() -> Self {
+        CharSet {
+            ranges: vec![('\0', char::MAX)],
+        }
+    }
+
+    /// Creates a set from ranges in any order, which may overlap
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from_ranges(&[('x', 'z'), ('a', 'c'), ('b', 'f')]);
+    /// assert_eq!(set.ranges(), &[('a', 'f'), ('x', 'z')]);
+    /// ```
+    pub fn from_ranges(ranges: &[(char, char)]) -> Self {
+        let mut ranges: Vec<(char, char)> = ranges
+            .iter()
+            .copied()
+            .filter(|(start, end)| start <= end)
+            .collect();
+        ranges.sort_unstable();
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match merged.last_mut() {
+                Some(last) if syntax::next_char(last.1).is_none_or(|next| next >= start) => {
+                    last.1 = last.1.max(end);
+                }
+                _ => merged.push((start, end)),
+            }
+        }
+        CharSet { ranges: merged }
+    }
+
+    /// Reads the characters matched by a character class, or `None` if the class can't be evaluated
+    /// on its own, for example because it only matches bytes
+    /// ```
+    /// use human_regex::{digit, within_range, CharSet};
+    /// let set = CharSet::from_class(&(within_range('0'..='9') & digit())).unwrap();
+    /// assert_eq!(set, CharSet::from('0'..='9'));
+    /// ```
+    pub fn from_class<T>(class: &HumanRegex<SymbolClass<T>>) -> Option<Self> {
+        FlagState::default()
+            .class_ranges(&class.0)
+            .map(|ranges| CharSet::from_ranges(&ranges))
+    }
+
+    /// Renders the set as a character class, see [Display](#impl-Display-for-CharSet)
+    /// ```
+    /// use human_regex::{text, CharSet};
+    /// let regex_string = text("gr") + CharSet::from_iter(['a', 'e']).to_class() + text("y");
+    /// assert!(regex_string.to_regex().is_match("grey"));
+    /// assert!(!regex_string.to_regex().is_match("groy"));
+    /// ```
+    pub fn to_class(&self) -> HumanRegex<SymbolClass<Custom>> {
+        HumanRegex(self.to_string(), pd::<SymbolClass<Custom>>)
+    }
+
+    /// The ranges in the set, sorted and merged
+    /// ```
+    /// use human_regex::CharSet;
+    /// assert_eq!(CharSet::from_iter(['c', 'a', 'b', 'z']).ranges(), &[('a', 'c'), ('z', 'z')]);
+    /// ```
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    /// Returns whether the set contains a character
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('a'..='f');
+    /// assert!(set.contains('c'));
+    /// assert!(!set.contains('g'));
+    /// ```
+    pub fn contains(&self, chr: char) -> bool {
+        self.ranges
+            .binary_search_by(|&(start, end)| {
+                if end < chr {
+                    std::cmp::Ordering::Less
+                } else if start > chr {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .is_ok()
+    }
+
+    /// The number of characters in the set
+    /// ```
+    /// use human_regex::CharSet;
+    /// assert_eq!(CharSet::from('\u{D000}'..='\u{EFFF}').len(), 0x2000 - 0x800);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|&(start, end)| {
+                let surrogates = if start < '\u{E000}' && end > '\u{D7FF}' {
+                    0x800
+                } else {
+                    0
+                };
+                (end as usize) - (start as usize) + 1 - surrogates
+            })
+            .sum()
+    }
+
+    /// Returns whether the set has no characters
+    /// ```
+    /// use human_regex::CharSet;
+    /// assert!(CharSet::from('a'..='z').intersection(&CharSet::from('0'..='9')).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterates over the characters in the set in order
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from_iter(['c', 'a', 'b']);
+    /// assert_eq!(set.iter().collect::<String>(), "abc");
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = char> + '_ {
+        self.ranges.iter().flat_map(|&(start, end)| start..=end)
+    }
+
+    /// The characters in either set
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('a'..='c').union(&CharSet::from('d'..='f'));
+    /// assert_eq!(set.ranges(), &[('a', 'f')]);
+    /// ```
+    pub fn union(&self, other: &CharSet) -> CharSet {
+        let mut ranges = self.ranges.clone();
+        ranges.extend_from_slice(&other.ranges);
+        CharSet::from_ranges(&ranges)
+    }
+
+    /// The characters in both sets
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('a'..='m').intersection(&CharSet::from('k'..='z'));
+    /// assert_eq!(set.ranges(), &[('k', 'm')]);
+    /// ```
+    pub fn intersection(&self, other: &CharSet) -> CharSet {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (left.peek(), right.peek())
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        CharSet { ranges }
+    }
+
+    /// The characters in this set but not the other
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('0'..='9').difference(&CharSet::from_iter(['4']));
+    /// assert_eq!(set.ranges(), &[('0', '3'), ('5', '9')]);
+    /// ```
+    pub fn difference(&self, other: &CharSet) -> CharSet {
+        self.intersection(&other.complement())
+    }
+
+    /// The characters in exactly one of the sets
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('a'..='g').symmetric_difference(&CharSet::from('b'..='h'));
+    /// assert_eq!(set.ranges(), &[('a', 'a'), ('h', 'h')]);
+    /// ```
+    pub fn symmetric_difference(&self, other: &CharSet) -> CharSet {
+        self.difference(other).union(&other.difference(self))
+    }
+
+    /// Every character not in the set
+    /// ```
+    /// use human_regex::CharSet;
+    /// let set = CharSet::from('\0'..='\u{7F}').complement();
+    /// assert_eq!(set.ranges(), &[('\u{80}', char::MAX)]);
+    /// ```
+    pub fn complement(&self) -> CharSet {
+        CharSet {
+            ranges: syntax::complement(&self.ranges),
+        }
+    }
+}
+
+/// Renders the shortest class matching exactly the characters in the set, choosing between listing
+/// the characters and negating their complement, and escaping every character that could be read as
+/// class syntax
+/// ```
+/// use human_regex::CharSet;
+/// assert_eq!(CharSet::from_iter([']', '-', '^']).to_string(), r"[\-\]\^]");
+/// assert_eq!(CharSet::from_iter(['a']).complement().to_string(), "[^a]");
+/// assert_eq!(CharSet::new().to_string(), r"[^\x{0}-\x{10FFFF}]");
+/// ```
+impl fmt::Display for CharSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let complement = self.complement();
+        if self.is_empty() {
+            return write!(f, "[^{}]", class_body(&complement.ranges));
+        }
+        let (listed, negated) = (class_body(&self.ranges), class_body(&complement.ranges));
+        if negated.is_empty() || listed.len() <= negated.len() {
+            write!(f, "[{}]", listed)
+        } else {
+            write!(f, "[^{}]", negated)
+        }
+    }
+}
+
+/// Write the items of a class matching sorted ranges, without the surrounding brackets
+fn class_body(ranges: &[(char, char)]) -> String {
+    let mut body = String::new();
+    for &(start, end) in ranges {
+        body.push_str(&escape_class_char(start));
+        if syntax::next_char(start) == Some(end) {
+            body.push_str(&escape_class_char(end));
+        } else if start != end {
+            body.push('-');
+            body.push_str(&escape_class_char(end));
+        }
+    }
+    body
+}
+
+/// Escape a character so it stands for itself inside a bracketed class, even under the verbose flag
+pub(crate) fn escape_class_char(chr: char) -> String {
+    match chr {
+        '\\' | '[' | ']' | '-' | '^' | '&' | '~' | '#' => format!("\\{}", chr),
+        chr if chr.is_whitespace() || chr.is_control() || is_invisible(chr) => {
+            format!("\\x{{{:X}}}", chr as u32)
+        }
+        chr => chr.to_string(),
+    }
+}
+
+/// Return whether a character has no visible glyph: format characters, private use and specials
+fn is_invisible(chr: char) -> bool {
+    matches!(
+        chr,
+        '\u{200B}'..='\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{206F}'
+            | '\u{E000}'..='\u{F8FF}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{FEFF}'
+            | '\u{FFF0}'..='\u{FFFF}'
+            | '\u{E0000}'..
+    )
+}
+
+/// ```
+/// use human_regex::CharSet;
+/// assert_eq!(CharSet::from('a'..='z').len(), 26);
+/// ```
+impl From<RangeInclusive<char>> for CharSet {
+    fn from(range: RangeInclusive<char>) -> Self {
+        CharSet::from_ranges(&[(*range.start(), *range.end())])
+    }
+}
+
+/// ```
+/// use human_regex::CharSet;
+/// let set: CharSet = "hello".chars().collect();
+/// assert_eq!(set.iter().collect::<String>(), "ehlo");
+/// ```
+impl FromIterator<char> for CharSet {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let ranges: Vec<(char, char)> = iter.into_iter().map(|chr| (chr, chr)).collect();
+        CharSet::from_ranges(&ranges)
+    }
+}
+
+/// ```
+/// use human_regex::{CharSet, HumanRegex};
+/// let class = HumanRegex::from(CharSet::from('a'..='c'));
+/// assert_eq!(class.to_string(), "[a-c]");
+/// ```
+impl From<CharSet> for HumanRegex<SymbolClass<Custom>> {
+    fn from(set: CharSet) -> Self {
+        set.to_class()
+    }
+}
+
+/// Implements an operator for owned and borrowed sets in terms of a [CharSet] method
+macro_rules! set_operator {
+    ($trait:ident, $method:ident, $function:ident) => {
+        impl std::ops::$trait<&CharSet> for &CharSet {
+            type Output = CharSet;
+
+            fn $method(self, rhs: &CharSet) -> CharSet {
+                self.$function(rhs)
+            }
+        }
+
+        impl std::ops::$trait<&CharSet> for CharSet {
+            type Output = CharSet;
+
+            fn $method(self, rhs: &CharSet) -> CharSet {
+                self.$function(rhs)
+            }
+        }
+
+        impl std::ops::$trait<CharSet> for CharSet {
+            type Output = CharSet;
+
+            fn $method(self, rhs: CharSet) -> CharSet {
+                self.$function(&rhs)
+            }
+        }
+    };
+}
+
+set_operator!(BitOr, bitor, union);
+set_operator!(BitAnd, bitand, intersection);
+set_operator!(Sub, sub, difference);
+set_operator!(BitXor, bitxor, symmetric_difference);
+
+/// The complement of a set
+/// ```
+/// use human_regex::CharSet;
+/// assert!((!CharSet::from('a'..='z')).contains('A'));
+/// ```
+impl std::ops::Not for CharSet {
+    type Output = CharSet;
+
+    fn not(self) -> CharSet {
+        self.complement()
+    }
+}
+
+/// The complement of a set
+/// ```
+/// use human_regex::CharSet;
+/// let letters = CharSet::from('a'..='z');
+/// assert!(!(!&letters).contains('a'));
+/// ```
+impl std::ops::Not for &CharSet {
+    type Output = CharSet;
+
+    fn not(self) -> CharSet {
+        self.complement()
+    }
+}
+
+/// ```
+/// use human_regex::CharSet;
+/// let set = CharSet::from('a'..='c');
+/// assert_eq!((&set).into_iter().count(), 3);
+/// ```
+impl<'a> IntoIterator for &'a CharSet {
+    type Item = char;
+    type IntoIter = Box<dyn Iterator<Item = char> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
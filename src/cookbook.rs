@@ -1,8 +1,12 @@
 //! # A Cookbook of Common Tasks
+//! Ready-made patterns for email addresses, URLs, IP addresses and other common formats are in
+//! [patterns](crate::patterns).
 //! ## Matching a date
 //! ```rust
 #![doc = include_str ! ("../examples/match_date.rs")]
 //! ```
+//! [datetime_format](crate::datetime_format) builds the same from `"%Y-%m-%d"`, with the month and
+//! day limited to their ranges and captured by name.
 //! ## Matching and Capturing HTML Tags
 //! ```rust
 #![doc = include_str ! ("../examples/match_html_tags.rs")]
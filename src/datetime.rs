@@ -0,0 +1,566 @@
+//! Functions for matching dates and times written in a known format
+//!
+//! [datetime_format] turns a strftime-style format, as used by `chrono` and C's `strftime`, into a
+//! pattern with a named capture for each field, so `%Y-%m-%d` matches `2024-02-29` and captures
+//! `year`, `month` and `day`. Each field only matches values in its range, like 01 to 12 for `%m`.
+//! [datetime_format_strict] also checks the day against the month and leap years. [iso8601],
+//! [rfc3339] and [rfc2822] match the standard formats.
+//!
+//! | Specifier | Matches | Capture |
+//! |-----------|---------|---------|
+//! | `%Y` | four-digit year | `year` |
+//! | `%y` | two-digit year | `year` |
+//! | `%C` | two-digit century | `century` |
+//! | `%m` | month, 01 to 12 | `month` |
+//! | `%b`, `%h` | abbreviated month name, like `Jan` | `month` |
+//! | `%B` | full month name, like `January` | `month` |
+//! | `%d` | day of the month, 01 to 31 | `day` |
+//! | `%e` | day of the month padded with a space, ` 1` to `31` | `day` |
+//! | `%j` | day of the year, 001 to 366 | `day_of_year` |
+//! | `%a` | abbreviated weekday name, like `Mon` | `weekday` |
+//! | `%A` | full weekday name, like `Monday` | `weekday` |
+//! | `%u` | weekday from Monday, 1 to 7 | `weekday` |
+//! | `%w` | weekday from Sunday, 0 to 6 | `weekday` |
+//! | `%U`, `%W` | week of the year, 00 to 53 | `week` |
+//! | `%V` | ISO week of the year, 01 to 53 | `week` |
+//! | `%H` | hour, 00 to 23 | `hour` |
+//! | `%I` | hour on a 12-hour clock, 01 to 12 | `hour` |
+//! | `%p` | `AM` or `PM` | `am_pm` |
+//! | `%P` | `am` or `pm` | `am_pm` |
+//! | `%M` | minute, 00 to 59 | `minute` |
+//! | `%S` | second, 00 to 60 for leap seconds | `second` |
+//! | `%f` | fractional seconds, 1 to 9 digits | `fraction` |
+//! | `%3f`, `%6f`, `%9f` | fractional seconds with exactly that many digits | `fraction` |
+//! | `%.f` | an optional `.` and fractional seconds | `fraction` |
+//! | `%.3f`, `%.6f`, `%.9f` | a `.` and fractional seconds with exactly that many digits | `fraction` |
+//! | `%z` | UTC offset, like `+0930` | `offset` |
+//! | `%:z` | UTC offset with a colon, like `+09:30` | `offset` |
+//! | `%#z` | `Z`, or a UTC offset with or without minutes and colon | `offset` |
+//! | `%Z` | time zone abbreviation, like `CET` | `zone` |
+//! | `%s` | seconds since the Unix epoch | `timestamp` |
+//! | `%D`, `%F` | `%m/%d/%y` and `%Y-%m-%d` | |
+//! | `%R`, `%T`, `%r` | `%H:%M`, `%H:%M:%S` and `%I:%M:%S %p` | |
+//! | `%t`, `%n`, `%%` | a tab, a newline and `%` | |
+//!
+//! Numeric fields take the padding flags `%-d`, without padding, `%_d`, padded with spaces, and
+//! `%0e`, padded with zeros. A capture name is only used by the first field with it.
+
+use super::humanregex::*;
+use super::*;
+use std::marker::PhantomData as pd;
+
+/// The abbreviated names of the months
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The full names of the months
+const FULL_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The abbreviated names of the weekdays
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// The full names of the weekdays
+const FULL_WEEKDAYS: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// Four-digit leap years: multiples of 4, except centuries that are not multiples of 400
+const LEAP_YEARS: &str = "[0-9]{2}(?:0[48]|[2468][048]|[13579][26])|(?:[02468][048]|[13579][26])00";
+
+/// Two-digit leap years, taken to be in 2000 to 2099
+const SHORT_LEAP_YEARS: &str = "0[048]|[2468][048]|[13579][26]";
+
+/// An error in a date and time format
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateTimeError {
+    /// What went wrong
+    pub message: String,
+    /// Where it went wrong, as a byte offset into the format
+    pub offset: usize,
+}
+
+impl fmt::Display for DateTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for DateTimeError {}
+
+/// Match dates and times written in a strftime-style format, with a named capture for each field
+/// ```
+/// use human_regex::{beginning, datetime_format, end};
+/// let timestamp = datetime_format("%Y-%m-%d %H:%M:%S%z").unwrap();
+/// let regex = (beginning() + timestamp + end()).to_regex();
+/// let caps = regex.captures("2024-02-29 13:05:59+0100").unwrap();
+/// assert_eq!(&caps["month"], "02");
+/// assert_eq!(&caps["offset"], "+0100");
+/// assert!(!regex.is_match("2024-13-01 13:05:59+0100"));
+/// assert!(!regex.is_match("2024-02-29 24:00:00+0100"));
+///
+/// let error = datetime_format("%Y-%Q").err().unwrap();
+/// assert_eq!(error.to_string(), "unknown specifier `%Q` at byte 3");
+/// ```
+pub fn datetime_format(format: &str) -> Result<HumanRegex<SymbolChain>, DateTimeError> {
+    let pieces = parse(format)?;
+    let mut names = Vec::new();
+    Ok(HumanRegex(render(&pieces, &mut names), pd::<SymbolChain>))
+}
+
+/// Match dates and times written in a strftime-style format like [datetime_format], also checking
+/// that the day exists in the month, including February 29 in leap years
+///
+/// The regex crate cannot tie captures together, so the year, month and day are captured together as
+/// `date` rather than separately. The other fields are captured as in [datetime_format].
+/// ```
+/// use human_regex::{beginning, datetime_format_strict, end};
+/// let regex = (beginning() + datetime_format_strict("%d/%m/%Y %H:%M").unwrap() + end()).to_regex();
+/// assert!(regex.is_match("29/02/2024 09:30"));
+/// assert!(!regex.is_match("29/02/2023 09:30"));
+/// assert!(!regex.is_match("31/04/2024 09:30"));
+/// assert_eq!(&regex.captures("29/02/2000 09:30").unwrap()["date"], "29/02/2000");
+/// ```
+pub fn datetime_format_strict(format: &str) -> Result<HumanRegex<SymbolChain>, DateTimeError> {
+    let pieces = parse(format)?;
+    let position = |specs: &[char]| {
+        pieces
+            .iter()
+            .position(|piece| matches!(piece, Piece::Field(field) if specs.contains(&field.spec)))
+    };
+    let (Some(month), Some(day)) = (position(&['m']), position(&['d', 'e'])) else {
+        return datetime_format(format);
+    };
+    let (mut first, mut last) = (month.min(day), month.max(day));
+    let between_literals = |from: usize, to: usize| {
+        pieces[from + 1..to]
+            .iter()
+            .all(|piece| matches!(piece, Piece::Literal(_)))
+    };
+    if !between_literals(first, last) {
+        return Err(DateTimeError {
+            message: "checking days needs the month and day next to each other".to_string(),
+            offset: format.len(),
+        });
+    }
+    if let Some(year) = position(&['Y', 'y']) {
+        if year < first && between_literals(year, first) {
+            first = year;
+        } else if year > last && between_literals(last, year) {
+            last = year;
+        }
+    }
+    let mut names = Vec::new();
+    let before = render(&pieces[..first], &mut names);
+    let date: Vec<String> = date_cases(&pieces[first..=last])
+        .iter()
+        .map(|case| render(case, &mut Vec::new()))
+        .collect();
+    let date = match names.contains(&"date") {
+        true => format!("(?:{})", date.join("|")),
+        false => format!("(?P<date>{})", date.join("|")),
+    };
+    names.push("date");
+    let after = render(&pieces[last + 1..], &mut names);
+    Ok(HumanRegex(
+        format!("{}{}{}", before, date, after),
+        pd::<SymbolChain>,
+    ))
+}
+
+/// Match a date and time in the extended format of ISO 8601, like `2024-02-29T13:05:59.123+01:00`,
+/// with optional fractional seconds and UTC offset
+/// ```
+/// use human_regex::{beginning, end, iso8601};
+/// let regex = (beginning() + iso8601() + end()).to_regex();
+/// assert!(regex.is_match("2024-02-29T13:05:59"));
+/// assert!(regex.is_match("2024-02-29T13:05:59.123Z"));
+/// assert!(regex.is_match("2024-02-29T13:05:59+0100"));
+/// assert!(!regex.is_match("2024-02-29 13:05:59"));
+/// ```
+pub fn iso8601() -> HumanRegex<SymbolChain> {
+    let offset = zero_or_one(nonescaped_text(&render(
+        &parse("%#z").expect("the offset format is valid"),
+        &mut Vec::new(),
+    )));
+    datetime_format("%Y-%m-%dT%H:%M:%S%.f").expect("the ISO 8601 format is valid") + offset
+}
+
+/// Match a date and time as RFC 3339 writes them for internet protocols, like
+/// `2024-02-29T13:05:59.123+01:00`, with a required UTC offset
+/// ```
+/// use human_regex::{beginning, end, rfc3339};
+/// let regex = (beginning() + rfc3339() + end()).to_regex();
+/// let caps = regex.captures("2024-02-29t13:05:59.5z").unwrap();
+/// assert_eq!(&caps["fraction"], "5");
+/// assert!(regex.is_match("2024-02-29T13:05:59-08:00"));
+/// assert!(!regex.is_match("2024-02-29T13:05:59"));
+/// assert!(!regex.is_match("2024-02-29T13:05:59+0800"));
+/// ```
+pub fn rfc3339() -> HumanRegex<SymbolChain> {
+    let offset = field('z', ":")
+        .expect("the offset specifier is valid")
+        .regex;
+    datetime_format("%Y-%m-%d").expect("the date format is valid")
+        + within_set(&['T', 't'])
+        + datetime_format("%H:%M:%S%.f").expect("the time format is valid")
+        + named_capture(nonescaped_text(&format!("[Zz]|{}", offset)), "offset")
+}
+
+/// Match a date and time as RFC 2822 writes them in email headers, like
+/// `Thu, 29 Feb 2024 13:05:59 +0100`, with optional weekday and seconds
+/// ```
+/// use human_regex::{beginning, end, rfc2822};
+/// let regex = (beginning() + rfc2822() + end()).to_regex();
+/// let caps = regex.captures("Thu, 29 Feb 2024 13:05:59 +0100").unwrap();
+/// assert_eq!(&caps["month"], "Feb");
+/// assert!(regex.is_match("1 Mar 2024 08:00 GMT"));
+/// assert!(!regex.is_match("Thu, 29 Feb 2024 25:05:59 +0100"));
+/// ```
+pub fn rfc2822() -> HumanRegex<SymbolChain> {
+    let weekday = named_capture(nonescaped_text(&WEEKDAYS.join("|")), "weekday");
+    let day = named_capture(
+        nonescaped_text(&format!(
+            "{}|{}",
+            number(1, 31, 2, Padding::Zero),
+            number(1, 9, 1, Padding::None)
+        )),
+        "day",
+    );
+    let zone = nonescaped_text(r"[+-][0-9]{4}|UT|GMT|[ECMP][SD]T");
+    zero_or_one(weekday + text(", "))
+        + day
+        + text(" ")
+        + datetime_format("%b %Y %H:%M").expect("the RFC 2822 format is valid")
+        + zero_or_one(
+            text(":") + named_capture(nonescaped_text(&number(0, 60, 2, Padding::Zero)), "second"),
+        )
+        + text(" ")
+        + named_capture(zone, "offset")
+}
+
+/// A part of a date and time format
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Piece {
+    /// Text matched as it is
+    Literal(String),
+    /// A field written with a specifier
+    Field(Field),
+}
+
+/// A field of a date and time format
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Field {
+    /// The letter of the specifier
+    spec: char,
+    /// The name of the capture around the field
+    name: &'static str,
+    /// The regex string matching the field
+    regex: String,
+    /// How the field is padded, if it is numeric
+    padding: Padding,
+    /// The text before the field, outside of the capture
+    prefix: &'static str,
+    /// Whether the field and its prefix may be left out
+    optional: bool,
+}
+
+/// How a numeric field is padded to its width
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Padding {
+    /// With zeros, as in `07`
+    Zero,
+    /// With spaces, as in ` 7`
+    Space,
+    /// Not at all, as in `7`
+    None,
+}
+
+/// Split a format into literals and fields
+fn parse(format: &str) -> Result<Vec<Piece>, DateTimeError> {
+    let mut pieces = Vec::new();
+    let mut chars = format.char_indices().peekable();
+    while let Some((offset, chr)) = chars.next() {
+        if chr != '%' {
+            match pieces.last_mut() {
+                Some(Piece::Literal(literal)) => literal.push(chr),
+                _ => pieces.push(Piece::Literal(chr.to_string())),
+            }
+            continue;
+        }
+        let mut modifiers = String::new();
+        while let Some(&(_, modifier)) = chars.peek() {
+            if !"-_0.:#".contains(modifier) && !modifier.is_ascii_digit() {
+                break;
+            }
+            modifiers.push(modifier);
+            chars.next();
+        }
+        let Some((_, spec)) = chars.next() else {
+            return Err(DateTimeError {
+                message: "`%` at the end of the format".to_string(),
+                offset,
+            });
+        };
+        let specifier = format!("%{}{}", modifiers, spec);
+        let unknown = || DateTimeError {
+            message: format!("unknown specifier `{}`", specifier),
+            offset,
+        };
+        let composite = match spec {
+            'D' => Some("%m/%d/%y"),
+            'F' => Some("%Y-%m-%d"),
+            'R' => Some("%H:%M"),
+            'T' => Some("%H:%M:%S"),
+            'r' => Some("%I:%M:%S %p"),
+            _ => None,
+        };
+        if let Some(composite) = composite {
+            if !modifiers.is_empty() {
+                return Err(unknown());
+            }
+            for piece in parse(composite)? {
+                match (pieces.last_mut(), piece) {
+                    (Some(Piece::Literal(literal)), Piece::Literal(more)) => {
+                        literal.push_str(&more)
+                    }
+                    (_, piece) => pieces.push(piece),
+                }
+            }
+            continue;
+        }
+        let literal = match (spec, modifiers.as_str()) {
+            ('%', "") => Some('%'),
+            ('t', "") => Some('\t'),
+            ('n', "") => Some('\n'),
+            _ => None,
+        };
+        if let Some(literal) = literal {
+            match pieces.last_mut() {
+                Some(Piece::Literal(text)) => text.push(literal),
+                _ => pieces.push(Piece::Literal(literal.to_string())),
+            }
+            continue;
+        }
+        let field = field(spec, &modifiers).ok_or_else(unknown)?;
+        pieces.push(Piece::Field(field));
+    }
+    Ok(pieces)
+}
+
+/// Return the field written by a specifier letter with its modifiers, if they are valid together
+fn field(spec: char, modifiers: &str) -> Option<Field> {
+    let padding = match modifiers {
+        "" => None,
+        "0" => Some(Padding::Zero),
+        "_" => Some(Padding::Space),
+        "-" => Some(Padding::None),
+        _ => None,
+    };
+    let numeric = |name: &'static str, lo: u32, hi: u32, width: usize, default: Padding| {
+        let padding = match (modifiers, padding) {
+            ("", _) => default,
+            (_, Some(padding)) => padding,
+            (_, None) => return None,
+        };
+        Some(Field {
+            spec,
+            name,
+            regex: number(lo, hi, width, padding),
+            padding,
+            prefix: "",
+            optional: false,
+        })
+    };
+    let named = |name: &'static str, regex: String| {
+        modifiers.is_empty().then_some(Field {
+            spec,
+            name,
+            regex,
+            padding: Padding::None,
+            prefix: "",
+            optional: false,
+        })
+    };
+    match spec {
+        'Y' => named("year", "[0-9]{4}".to_string()),
+        'y' => numeric("year", 0, 99, 2, Padding::Zero),
+        'C' => numeric("century", 0, 99, 2, Padding::Zero),
+        'm' => numeric("month", 1, 12, 2, Padding::Zero),
+        'b' | 'h' => named("month", MONTHS.join("|")),
+        'B' => named("month", FULL_MONTHS.join("|")),
+        'd' => numeric("day", 1, 31, 2, Padding::Zero),
+        'e' => numeric("day", 1, 31, 2, Padding::Space),
+        'j' => numeric("day_of_year", 1, 366, 3, Padding::Zero),
+        'a' => named("weekday", WEEKDAYS.join("|")),
+        'A' => named("weekday", FULL_WEEKDAYS.join("|")),
+        'u' => named("weekday", "[1-7]".to_string()),
+        'w' => named("weekday", "[0-6]".to_string()),
+        'U' | 'W' => numeric("week", 0, 53, 2, Padding::Zero),
+        'V' => numeric("week", 1, 53, 2, Padding::Zero),
+        'H' => numeric("hour", 0, 23, 2, Padding::Zero),
+        'I' => numeric("hour", 1, 12, 2, Padding::Zero),
+        'p' => named("am_pm", "AM|PM".to_string()),
+        'P' => named("am_pm", "am|pm".to_string()),
+        'M' => numeric("minute", 0, 59, 2, Padding::Zero),
+        'S' => numeric("second", 0, 60, 2, Padding::Zero),
+        's' => named("timestamp", "-?[0-9]+".to_string()),
+        'Z' => named("zone", "[A-Z]{2,5}".to_string()),
+        'z' => {
+            let hours = number(0, 23, 2, Padding::Zero);
+            let minutes = number(0, 59, 2, Padding::Zero);
+            let regex = match modifiers {
+                "" => format!("[+-]{}{}", hours, minutes),
+                ":" => format!("[+-]{}:{}", hours, minutes),
+                "#" => format!("Z|[+-]{}(?::?{})?", hours, minutes),
+                _ => return None,
+            };
+            Some(Field {
+                spec,
+                name: "offset",
+                regex,
+                padding: Padding::None,
+                prefix: "",
+                optional: false,
+            })
+        }
+        'f' => {
+            let (dot, digits) = match modifiers.strip_prefix('.') {
+                Some(digits) => (true, digits),
+                None => (false, modifiers),
+            };
+            let regex = match digits {
+                "" => "[0-9]{1,9}".to_string(),
+                digits => match digits.parse::<u8>() {
+                    Ok(count @ 1..=9) => format!("[0-9]{{{}}}", count),
+                    _ => return None,
+                },
+            };
+            Some(Field {
+                spec,
+                name: "fraction",
+                regex,
+                padding: Padding::None,
+                prefix: if dot { r"\." } else { "" },
+                optional: dot && digits.is_empty(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Return the regex string for the numbers from `lo` to `hi`, padded to a width
+fn number(lo: u32, hi: u32, width: usize, padding: Padding) -> String {
+    let options = NumberOptions {
+        zero_pad: width,
+        ..Default::default()
+    };
+    match padding {
+        Padding::Zero => number_in_range_with(lo.into()..=hi.into(), &options).to_string(),
+        Padding::None => number_in_range(lo.into()..=hi.into()).to_string(),
+        Padding::Space => {
+            let mut branches = Vec::new();
+            for length in (1..=width as u32).rev() {
+                let shortest = if length == 1 {
+                    0
+                } else {
+                    10u32.pow(length - 1)
+                };
+                let (first, last) = (lo.max(shortest), hi.min(10u32.pow(length) - 1));
+                if first <= last {
+                    let spaces = " ".repeat(width - length as usize);
+                    let digits = number_in_range(first.into()..=last.into());
+                    branches.push(format!("{}{}", spaces, digits));
+                }
+            }
+            format!("(?:{})", branches.join("|"))
+        }
+    }
+}
+
+/// Return the regex string for a sequence of pieces, capturing each field under its name unless it
+/// has none or an earlier field used it
+fn render(pieces: &[Piece], names: &mut Vec<&'static str>) -> String {
+    let mut rendered = HumanRegex(String::new(), pd::<SymbolChain>);
+    for piece in pieces {
+        rendered = match piece {
+            Piece::Literal(literal) => rendered + text(literal.as_str()),
+            Piece::Field(field) => {
+                let value = nonescaped_text(&field.regex);
+                let value = match field.name.is_empty() || names.contains(&field.name) {
+                    true => value,
+                    false => {
+                        names.push(field.name);
+                        named_capture(value, field.name)
+                    }
+                };
+                let value = nonescaped_text(field.prefix) + value;
+                match field.optional {
+                    true => rendered + zero_or_one(value),
+                    false => rendered + value,
+                }
+            }
+        };
+    }
+    rendered.to_string()
+}
+
+/// Return the pieces of a date for each combination of months, days and years that go together,
+/// without captures
+fn date_cases(pieces: &[Piece]) -> Vec<Vec<Piece>> {
+    let cases: [(&[u32], u32, u32, bool); 4] = [
+        (&[1, 3, 5, 7, 8, 10, 12], 1, 31, false),
+        (&[4, 6, 9, 11], 1, 30, false),
+        (&[2], 1, 28, false),
+        (&[2], 29, 29, true),
+    ];
+    cases
+        .iter()
+        .map(|&(months, first_day, last_day, leap)| {
+            pieces
+                .iter()
+                .map(|piece| match piece {
+                    Piece::Field(field) => {
+                        let padding = field.padding;
+                        let regex = match field.spec {
+                            'm' => months
+                                .iter()
+                                .map(|&month| number(month, month, 2, padding))
+                                .collect::<Vec<String>>()
+                                .join("|"),
+                            'd' | 'e' => number(first_day, last_day, 2, padding),
+                            'Y' if leap => LEAP_YEARS.to_string(),
+                            'y' if leap => SHORT_LEAP_YEARS.to_string(),
+                            _ => field.regex.clone(),
+                        };
+                        Piece::Field(Field {
+                            name: "",
+                            regex,
+                            ..field.clone()
+                        })
+                    }
+                    literal => literal.clone(),
+                })
+                .collect()
+        })
+        .collect()
+}
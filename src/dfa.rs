@@ -0,0 +1,188 @@
+//! Functions for precompiling patterns into serialized DFAs
+//!
+//! Compiling a large Unicode pattern can take a noticeable amount of time. To avoid paying that cost
+//! at runtime, a [HumanRegex] can be compiled into a [regex-automata](https://docs.rs/regex-automata)
+//! DFA ahead of time and serialized to bytes, typically from a build script:
+//! ```ignore
+//! // build.rs
+//! use human_regex::{beginning, digit, dfa::{write_dfa, DfaKind}, end, exactly, text};
+//!
+//! fn main() {
+//!     let date = beginning() + exactly(4, digit()) + text("-") + exactly(2, digit()) + end();
+//!     write_dfa("date", &date, DfaKind::Dense).unwrap();
+//! }
+//! ```
+//! The binary then deserializes a ready-to-run matcher without compiling anything:
+//! ```ignore
+//! // main.rs
+//! use human_regex::dfa::{Automaton, Input};
+//!
+//! fn main() {
+//!     let date = human_regex::include_dense_dfa!(concat!(env!("OUT_DIR"), "/date.dfa"));
+//!     assert!(date.try_search_fwd(&Input::new("2023-01")).unwrap().is_some());
+//! }
+//! ```
+
+use super::humanregex::*;
+use regex_automata::nfa::thompson;
+use regex_automata::util::syntax;
+use std::path::PathBuf;
+
+#[doc(no_inline)]
+pub use regex_automata::dfa::dense::BuildError;
+#[doc(no_inline)]
+pub use regex_automata::dfa::{dense, sparse, Automaton};
+#[doc(no_inline)]
+pub use regex_automata::util::wire::AlignAs;
+#[doc(no_inline)]
+pub use regex_automata::{HalfMatch, Input};
+
+/// The kind of DFA to build
+pub enum DfaKind {
+    /// A dense DFA, which is faster to search but uses more memory
+    Dense,
+    /// A sparse DFA, which is smaller but slower to search
+    Sparse,
+}
+
+/// The byte order to serialize a DFA with
+#[derive(Clone, Copy)]
+pub enum Endian {
+    /// Little endian byte order
+    Little,
+    /// Big endian byte order
+    Big,
+    /// The byte order of the machine the code is running on
+    Native,
+}
+
+/// The parser and compiler settings for a pattern in a state, allowing invalid UTF-8 only for raw bytes
+pub(crate) fn automaton_configs<T: State>() -> (syntax::Config, thompson::Config) {
+    (
+        syntax::Config::new().utf8(!T::BYTES),
+        thompson::Config::new().utf8(!T::BYTES),
+    )
+}
+
+impl<T: State> HumanRegex<T> {
+    /// Convert to a dense DFA, or the error that stopped it from being built, such as exceeding a size limit
+    /// ```
+    /// use human_regex::{byte, digit, one_or_more, text, dfa::{Automaton, HalfMatch, Input}};
+    /// let dfa = (text("foo") + one_or_more(digit())).to_dense_dfa().unwrap();
+    /// assert_eq!(
+    ///     dfa.try_search_fwd(&Input::new("foo12345")).unwrap(),
+    ///     Some(HalfMatch::must(0, 8))
+    /// );
+    /// // Raw bytes compile to a DFA that searches haystacks which aren't valid UTF-8
+    /// let dfa = (text("a") + byte(0xFF)).to_dense_dfa().unwrap();
+    /// assert!(dfa.try_search_fwd(&Input::new(b"a\xFF")).unwrap().is_some());
+    /// ```
+    pub fn to_dense_dfa(&self) -> Result<dense::DFA<Vec<u32>>, Box<BuildError>> {
+        let (syntax, thompson) = automaton_configs::<T>();
+        dense::Builder::new()
+            .syntax(syntax)
+            .thompson(thompson)
+            .build(&self.0)
+            .map_err(Box::new)
+    }
+
+    /// Convert to a sparse DFA, or the error that stopped it from being built
+    /// ```
+    /// use human_regex::{digit, one_or_more, text, dfa::{Automaton, Input}};
+    /// let dfa = (text("foo") + one_or_more(digit())).to_sparse_dfa().unwrap();
+    /// assert!(dfa.try_search_fwd(&Input::new("foo1")).unwrap().is_some());
+    /// assert!(dfa.try_search_fwd(&Input::new("bar1")).unwrap().is_none());
+    /// ```
+    pub fn to_sparse_dfa(&self) -> Result<sparse::DFA<Vec<u8>>, Box<BuildError>> {
+        self.to_dense_dfa()?.to_sparse().map_err(Box::new)
+    }
+
+    /// Compile to a DFA and serialize it to bytes, or return the error that stopped the DFA from being built.
+    ///
+    /// The bytes of a dense DFA must be stored at an address aligned to 4 bytes to be deserialized,
+    /// see [include_dense_dfa](crate::include_dense_dfa). Sparse DFAs have no alignment requirement.
+    /// ```
+    /// use human_regex::{digit, exactly, text, dfa::{sparse, Automaton, DfaKind, Endian, Input}};
+    /// let bytes = (exactly(2, digit()) + text(":") + exactly(2, digit()))
+    ///     .to_dfa_bytes(DfaKind::Sparse, Endian::Native)
+    ///     .unwrap();
+    /// let (dfa, _) = sparse::DFA::from_bytes(&bytes).unwrap();
+    /// assert!(dfa.try_search_fwd(&Input::new("at 12:30")).unwrap().is_some());
+    /// ```
+    pub fn to_dfa_bytes(&self, kind: DfaKind, endian: Endian) -> Result<Vec<u8>, Box<BuildError>> {
+        Ok(match kind {
+            DfaKind::Dense => {
+                let dfa = self.to_dense_dfa()?;
+                let (bytes, padding) = match endian {
+                    Endian::Little => dfa.to_bytes_little_endian(),
+                    Endian::Big => dfa.to_bytes_big_endian(),
+                    Endian::Native => dfa.to_bytes_native_endian(),
+                };
+                bytes[padding..].to_vec()
+            }
+            DfaKind::Sparse => {
+                let dfa = self.to_sparse_dfa()?;
+                match endian {
+                    Endian::Little => dfa.to_bytes_little_endian(),
+                    Endian::Big => dfa.to_bytes_big_endian(),
+                    Endian::Native => dfa.to_bytes_native_endian(),
+                }
+            }
+        })
+    }
+}
+
+/// A function for build scripts that compiles a [HumanRegex] to a DFA and writes it to `$OUT_DIR/{name}.dfa`,
+/// using the byte order of the compilation target. Returns the path of the written file, or an
+/// [InvalidInput](std::io::ErrorKind::InvalidInput) error if the DFA can't be built.
+///
+/// Load the result with [include_dense_dfa](crate::include_dense_dfa) or [include_sparse_dfa](crate::include_sparse_dfa).
+pub fn write_dfa<T: State>(
+    name: &str,
+    regex: &HumanRegex<T>,
+    kind: DfaKind,
+) -> std::io::Result<PathBuf> {
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            "OUT_DIR is not set, write_dfa should be called from a build script",
+        )
+    })?;
+    let endian = match std::env::var("CARGO_CFG_TARGET_ENDIAN").as_deref() {
+        Ok("big") => Endian::Big,
+        Ok("little") => Endian::Little,
+        _ => Endian::Native,
+    };
+    let path = PathBuf::from(out_dir).join(format!("{}.dfa", name));
+    let bytes = regex
+        .to_dfa_bytes(kind, endian)
+        .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidInput, error))?;
+    std::fs::write(&path, bytes)?;
+    Ok(path)
+}
+
+/// Deserialize a dense DFA from a file written by [write_dfa](crate::dfa::write_dfa), embedding its bytes in the binary
+/// with the alignment that deserialization requires. Takes a path in the same form as [include_bytes].
+#[macro_export]
+macro_rules! include_dense_dfa {
+    ($path:expr) => {{
+        static ALIGNED: &$crate::dfa::AlignAs<[u8], u32> = &$crate::dfa::AlignAs {
+            _align: [],
+            bytes: *include_bytes!($path),
+        };
+        $crate::dfa::dense::DFA::from_bytes(&ALIGNED.bytes)
+            .expect("serialized DFA should be valid")
+            .0
+    }};
+}
+
+/// Deserialize a sparse DFA from a file written by [write_dfa](crate::dfa::write_dfa), embedding its bytes in the binary.
+/// Takes a path in the same form as [include_bytes].
+#[macro_export]
+macro_rules! include_sparse_dfa {
+    ($path:expr) => {{
+        $crate::dfa::sparse::DFA::from_bytes(include_bytes!($path))
+            .expect("serialized DFA should be valid")
+            .0
+    }};
+}
@@ -0,0 +1,891 @@
+//! Functions for exporting patterns to the syntax of other regex engines
+//!
+//! [HumanRegex::to_dialect] renders a pattern so that it matches the same text in another engine.
+//! Constructs are translated rather than copied where the engines disagree: named groups use the
+//! target's syntax, class set operations like [and](crate::and), [subtract](crate::subtract) and
+//! [xor](crate::xor) are expanded into plain ranges, flags are scoped or resolved ahead of time, and
+//! `beginning()`/`end()` become the target's text anchors. When a construct cannot be expressed
+//! in the target, a [DialectError] names it instead.
+//!
+//! Some classes are rendered with the target's closest native equivalent rather than expanded:
+//! Unicode [digit](crate::digit), [word](crate::word) and [whitespace](crate::whitespace) become
+//! `\d`, `\w` and `\s` in Python and .NET, and the locale-dependent `[[:digit:]]`, `[[:alnum:]_]`
+//! and `[[:space:]]` in POSIX.
+
+use super::humanregex::*;
+use super::syntax::{self, ascii_name, FlagState};
+use regex_syntax::ast::{self, Ast};
+use std::sync::OnceLock;
+
+/// The regex engines that patterns can be exported to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Dialect {
+    /// ECMAScript regular expressions, compiled with the `u` flag (`new RegExp(pattern, "u")`).
+    /// Forward slashes are escaped, so the output can also be pasted into a `/.../u` literal.
+    JavaScript,
+    /// Python's `re` module, matching against `str`
+    Python,
+    /// PCRE2, as used by PHP and many command line tools
+    Pcre,
+    /// POSIX extended regular expressions, as used by `grep -E` and PostgreSQL's `~`.
+    /// Characters are written as is, and ranges outside ASCII follow the collation of the locale.
+    /// NUL cannot be written, and neither can `\` in a bracket expression, where PostgreSQL reads it
+    /// as an escape.
+    Posix,
+    /// Go's `regexp` package (RE2 syntax)
+    Go,
+    /// .NET's `System.Text.RegularExpressions`
+    DotNet,
+}
+
+impl fmt::Display for Dialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Dialect::JavaScript => "JavaScript",
+            Dialect::Python => "Python",
+            Dialect::Pcre => "PCRE",
+            Dialect::Posix => "POSIX",
+            Dialect::Go => "Go",
+            Dialect::DotNet => ".NET",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Dialect {
+    /// Return whether case-insensitivity can be scoped to a group, like `(?i:...)`
+    fn scoped_case(&self) -> bool {
+        matches!(
+            self,
+            Dialect::Python | Dialect::Pcre | Dialect::Go | Dialect::DotNet
+        )
+    }
+
+    /// Return whether lookahead and lookbehind are supported
+    fn lookaround(&self) -> bool {
+        matches!(
+            self,
+            Dialect::JavaScript | Dialect::Python | Dialect::Pcre | Dialect::DotNet
+        )
+    }
+}
+
+/// The error returned when a pattern cannot be exported to or imported from a [Dialect]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DialectError {
+    /// The regex string is not valid
+    Invalid(String),
+    /// The regex string contains a construct that the dialect cannot express
+    Unsupported {
+        /// The dialect being exported to
+        dialect: Dialect,
+        /// A description of the construct
+        construct: String,
+    },
+    /// The imported pattern uses a feature of its dialect that this crate cannot express
+    CannotImport {
+        /// The dialect being imported from
+        dialect: Dialect,
+        /// A description of the feature, with the text of the pattern that uses it
+        feature: String,
+        /// The byte offset of the feature in the imported text
+        offset: usize,
+    },
+}
+
+impl fmt::Display for DialectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DialectError::Invalid(error) => write!(f, "invalid regex: {}", error),
+            DialectError::Unsupported { dialect, construct } => {
+                write!(f, "{} cannot express {}", dialect, construct)
+            }
+            DialectError::CannotImport {
+                dialect,
+                feature,
+                offset,
+            } => write!(
+                f,
+                "this crate cannot express the {} {} at byte {}",
+                dialect, feature, offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DialectError {}
+
+impl<T> HumanRegex<T> {
+    /// Render the pattern in the syntax of another regex engine
+    /// ```
+    /// use human_regex::{beginning, digit, end, exactly, named_capture, text, Dialect};
+    /// let regex_string = beginning()
+    ///     + named_capture(exactly(4, digit()), "year")
+    ///     + text("-")
+    ///     + named_capture(exactly(2, digit()), "month")
+    ///     + end();
+    /// assert_eq!(
+    ///     regex_string.to_dialect(Dialect::JavaScript).unwrap(),
+    ///     r"^(?<year>\p{Nd}{4})-(?<month>\p{Nd}{2})$"
+    /// );
+    /// assert_eq!(
+    ///     regex_string.to_dialect(Dialect::Python).unwrap(),
+    ///     r"\A(?P<year>\d{4})-(?P<month>\d{2})\Z"
+    /// );
+    /// assert!(regex_string.to_dialect(Dialect::Posix).is_err());
+    /// ```
+    /// Class set operations are expanded into plain ranges:
+    /// ```
+    /// use human_regex::{subtract, within_range, within_set, Dialect};
+    /// let regex_string = subtract(within_range('0'..='9'), within_set(&['4']));
+    /// assert_eq!(regex_string.to_dialect(Dialect::Go).unwrap(), "[0-35-9]");
+    /// assert_eq!(regex_string.to_dialect(Dialect::Posix).unwrap(), "[0-35-9]");
+    /// ```
+    pub fn to_dialect(&self, dialect: Dialect) -> Result<String, DialectError> {
+        let ast = syntax::parse(&self.0).map_err(DialectError::Invalid)?;
+        let mut renderer = Renderer {
+            dialect,
+            pattern: &self.0,
+            grouped: false,
+            captured: false,
+        };
+        let (rendered, _) = renderer.render(&ast, FlagState::default())?;
+        if renderer.grouped && renderer.captured {
+            return Err(renderer.unsupported(
+                "a non-capturing group without shifting the numbering of capture groups",
+            ));
+        }
+        Ok(rendered)
+    }
+}
+
+/// How tightly a rendered piece of a pattern binds, used to decide where groups are needed
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Precedence {
+    /// A piece containing a top-level `|`
+    Alternation,
+    /// A sequence of several atoms, or nothing at all
+    Concatenation,
+    /// A single character, class, group or assertion that can be repeated as is
+    Atom,
+}
+
+/// The inside of a JavaScript or PCRE class matching the same characters as a Unicode `\w`
+const UNICODE_WORD: &str = r"\p{Alphabetic}\p{M}\p{Nd}\p{Pc}\p{Join_Control}";
+
+/// General categories with every accepted spelling (normalized) and the short name used when rendering
+const CATEGORIES: &[(&[&str], &str)] = &[
+    (&["l", "letter"], "L"),
+    (&["lc", "casedletter"], "LC"),
+    (&["lu", "uppercaseletter"], "Lu"),
+    (&["ll", "lowercaseletter"], "Ll"),
+    (&["lt", "titlecaseletter"], "Lt"),
+    (&["lm", "modifierletter"], "Lm"),
+    (&["lo", "otherletter"], "Lo"),
+    (&["m", "mark", "combiningmark"], "M"),
+    (&["mn", "nonspacingmark"], "Mn"),
+    (&["mc", "spacingmark"], "Mc"),
+    (&["me", "enclosingmark"], "Me"),
+    (&["n", "number"], "N"),
+    (&["nd", "decimalnumber", "digit"], "Nd"),
+    (&["nl", "letternumber"], "Nl"),
+    (&["no", "othernumber"], "No"),
+    (&["p", "punctuation", "punct"], "P"),
+    (&["pc", "connectorpunctuation"], "Pc"),
+    (&["pd", "dashpunctuation"], "Pd"),
+    (&["ps", "openpunctuation"], "Ps"),
+    (&["pe", "closepunctuation"], "Pe"),
+    (&["pi", "initialpunctuation"], "Pi"),
+    (&["pf", "finalpunctuation"], "Pf"),
+    (&["po", "otherpunctuation"], "Po"),
+    (&["s", "symbol"], "S"),
+    (&["sm", "mathsymbol"], "Sm"),
+    (&["sc", "currencysymbol"], "Sc"),
+    (&["sk", "modifiersymbol"], "Sk"),
+    (&["so", "othersymbol"], "So"),
+    (&["z", "separator"], "Z"),
+    (&["zs", "spaceseparator"], "Zs"),
+    (&["zl", "lineseparator"], "Zl"),
+    (&["zp", "paragraphseparator"], "Zp"),
+    (&["c", "other"], "C"),
+    (&["cc", "control", "cntrl"], "Cc"),
+    (&["cf", "format"], "Cf"),
+    (&["cs", "surrogate"], "Cs"),
+    (&["co", "privateuse"], "Co"),
+    (&["cn", "unassigned"], "Cn"),
+];
+
+/// Normalize the name of a Unicode property the way the regex crate compares them
+fn normalize(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .filter(|chr| !matches!(chr, ' ' | '_' | '-'))
+        .flat_map(char::to_lowercase)
+        .collect();
+    match name.strip_prefix("is") {
+        Some(rest) if !rest.is_empty() && name != "isc" => rest.to_string(),
+        _ => name,
+    }
+}
+
+/// Return the short name of a general category, if the name is one
+fn category(name: &str) -> Option<&'static str> {
+    let name = normalize(name);
+    CATEGORIES
+        .iter()
+        .find(|(names, _)| names.contains(&name.as_str()))
+        .map(|(_, short)| *short)
+}
+
+/// The kinds of Unicode property that dialects name differently
+enum Property<'a> {
+    /// A general category, by its short name
+    Category(&'static str),
+    /// A script, by the name it was written with
+    Script(&'a str),
+    /// The script extensions property, by the name it was written with
+    ScriptExtensions(&'a str),
+    /// A binary property such as `Alphabetic`, by the name it was written with
+    Binary(&'a str),
+}
+
+/// Tracks what has been rendered so far while exporting a pattern
+struct Renderer<'p> {
+    /// The dialect being exported to
+    dialect: Dialect,
+    /// The regex string being exported
+    pattern: &'p str,
+    /// Whether a POSIX group was added that would shift capture numbering
+    grouped: bool,
+    /// Whether the pattern contains any capture groups
+    captured: bool,
+}
+
+impl<'p> Renderer<'p> {
+    /// Build an error for a construct the dialect cannot express
+    fn unsupported(&self, construct: &str) -> DialectError {
+        DialectError::Unsupported {
+            dialect: self.dialect,
+            construct: construct.to_string(),
+        }
+    }
+
+    /// Render a node of the syntax tree with the flags in effect around it
+    fn render(
+        &mut self,
+        node: &Ast,
+        state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        match node {
+            Ast::Empty(_) => Ok((String::new(), Precedence::Concatenation)),
+            Ast::Flags(_) => Ok((String::new(), Precedence::Concatenation)),
+            Ast::Literal(literal) if !state.unicode && !literal.c.is_ascii() => {
+                Err(self.unsupported("a raw byte"))
+            }
+            Ast::Literal(literal) => self.literal(literal.c, state),
+            Ast::Dot(_) if !state.unicode => Err(self.unsupported("a `.` matching raw bytes")),
+            Ast::Dot(_) => Ok((self.dot(state), Precedence::Atom)),
+            Ast::Assertion(assertion) => self.assertion(&assertion.kind, state),
+            Ast::ClassPerl(class) => self.class(node, &class.span, state),
+            Ast::ClassUnicode(class) => self.class(node, &class.span, state),
+            Ast::ClassBracketed(class) => self.class(node, &class.span, state),
+            Ast::Repetition(repetition) => self.repetition(repetition, state),
+            Ast::Group(group) => self.group(group, state),
+            Ast::Alternation(alternation) => {
+                let branches = alternation
+                    .asts
+                    .iter()
+                    .map(|branch| {
+                        self.render(branch, state)
+                            .map(|rendered| self.at_least(rendered, Precedence::Concatenation))
+                    })
+                    .collect::<Result<Vec<String>, DialectError>>()?;
+                Ok((branches.join("|"), Precedence::Alternation))
+            }
+            Ast::Concat(concat) => self.concat(&concat.asts, state),
+        }
+    }
+
+    /// Render a sequence of nodes, applying standalone flags like `(?i)` to the nodes after them
+    fn concat(
+        &mut self,
+        nodes: &[Ast],
+        mut state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        let mut rendered = String::new();
+        let mut count = 0;
+        let mut last = Precedence::Concatenation;
+        for (idx, node) in nodes.iter().enumerate() {
+            if let Ast::Flags(flags) = node {
+                let inner = state.apply(&flags.flags);
+                if self.dialect.scoped_case() && inner.case_insensitive != state.case_insensitive {
+                    let rest = self.concat(&nodes[idx + 1..], inner)?;
+                    rendered.push_str(&scoped_case(inner.case_insensitive, &rest.0));
+                    return Ok((rendered, Precedence::Concatenation));
+                }
+                state = inner;
+                continue;
+            }
+            let piece = self.render(node, state)?;
+            if piece.0.is_empty() {
+                continue;
+            }
+            last = piece.1;
+            count += 1;
+            rendered.push_str(&self.at_least(piece, Precedence::Concatenation));
+        }
+        match count {
+            1 => Ok((rendered, last)),
+            _ => Ok((rendered, Precedence::Concatenation)),
+        }
+    }
+
+    /// Wrap a rendered piece in a group if it binds less tightly than required
+    fn at_least(
+        &mut self,
+        (rendered, precedence): (String, Precedence),
+        required: Precedence,
+    ) -> String {
+        if precedence >= required && !(required == Precedence::Atom && rendered.is_empty()) {
+            rendered
+        } else if self.dialect == Dialect::Posix {
+            self.grouped = true;
+            format!("({})", rendered)
+        } else {
+            format!("(?:{})", rendered)
+        }
+    }
+
+    /// Render a group
+    fn group(
+        &mut self,
+        group: &ast::Group,
+        state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        match &group.kind {
+            ast::GroupKind::CaptureIndex(_) => {
+                self.captured = true;
+                let (inner, _) = self.render(&group.ast, state)?;
+                Ok((format!("({})", inner), Precedence::Atom))
+            }
+            ast::GroupKind::CaptureName { name, .. } => {
+                self.captured = true;
+                let (inner, _) = self.render(&group.ast, state)?;
+                match self.dialect {
+                    Dialect::JavaScript | Dialect::DotNet => {
+                        Ok((format!("(?<{}>{})", name.name, inner), Precedence::Atom))
+                    }
+                    Dialect::Python | Dialect::Pcre | Dialect::Go => {
+                        Ok((format!("(?P<{}>{})", name.name, inner), Precedence::Atom))
+                    }
+                    Dialect::Posix => Err(self.unsupported("named capture groups")),
+                }
+            }
+            ast::GroupKind::NonCapturing(flags) => {
+                let inner = state.apply(flags);
+                let rendered = self.render(&group.ast, inner)?;
+                if self.dialect.scoped_case() && inner.case_insensitive != state.case_insensitive {
+                    Ok((
+                        scoped_case(inner.case_insensitive, &rendered.0),
+                        Precedence::Atom,
+                    ))
+                } else {
+                    Ok(rendered)
+                }
+            }
+        }
+    }
+
+    /// Render a repetition
+    fn repetition(
+        &mut self,
+        repetition: &ast::Repetition,
+        state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        let inner = self.render(&repetition.ast, state)?;
+        let inner = self.at_least(inner, Precedence::Atom);
+        let operator = match &repetition.op.kind {
+            ast::RepetitionKind::ZeroOrOne => "?".to_string(),
+            ast::RepetitionKind::ZeroOrMore => "*".to_string(),
+            ast::RepetitionKind::OneOrMore => "+".to_string(),
+            ast::RepetitionKind::Range(ast::RepetitionRange::Exactly(n)) => format!("{{{}}}", n),
+            ast::RepetitionKind::Range(ast::RepetitionRange::AtLeast(n)) => format!("{{{},}}", n),
+            ast::RepetitionKind::Range(ast::RepetitionRange::Bounded(n, m)) => {
+                format!("{{{},{}}}", n, m)
+            }
+        };
+        let lazy = if state.greedy(repetition) {
+            ""
+        } else if self.dialect == Dialect::Posix {
+            return Err(self.unsupported("lazy repetitions"));
+        } else {
+            "?"
+        };
+        Ok((format!("{}{}{}", inner, operator, lazy), Precedence::Atom))
+    }
+
+    /// Render a literal character
+    fn literal(&self, chr: char, state: FlagState) -> Result<(String, Precedence), DialectError> {
+        if state.case_insensitive && !self.dialect.scoped_case() {
+            let ranges = state
+                .class_ranges(&regex_syntax::escape(&chr.to_string()))
+                .ok_or_else(|| self.unsupported("a case-insensitive literal"))?;
+            if ranges != [(chr, chr)] {
+                return Ok((self.ranges(&ranges)?, Precedence::Atom));
+            }
+        }
+        let meta = match self.dialect {
+            Dialect::Posix => r".[\()*+?{|^$",
+            Dialect::JavaScript => r"\.+*?()|[]{}^$/",
+            _ => r"\.+*?()|[]{}^$",
+        };
+        let rendered = if meta.contains(chr) {
+            format!(r"\{}", chr)
+        } else {
+            match (self.dialect, chr) {
+                (Dialect::Posix, '\0') => return Err(self.unsupported("a NUL character")),
+                (Dialect::Posix, chr) => chr.to_string(),
+                (_, '\n') => r"\n".to_string(),
+                (_, '\t') => r"\t".to_string(),
+                (_, '\r') => r"\r".to_string(),
+                (_, chr) if printable(chr) || chr == ' ' => chr.to_string(),
+                (Dialect::DotNet, chr) if chr as u32 > 0xFFFF => {
+                    let mut units = [0; 2];
+                    chr.encode_utf16(&mut units)
+                        .iter()
+                        .map(|unit| format!(r"\u{:04X}", unit))
+                        .collect()
+                }
+                (_, chr) => self.code_point(chr)?,
+            }
+        };
+        Ok((rendered, Precedence::Atom))
+    }
+
+    /// Render `.` with the flags in effect
+    fn dot(&self, state: FlagState) -> String {
+        match (self.dialect, state.dot_matches_new_line, state.crlf) {
+            (Dialect::Posix, true, _) => ".".to_string(),
+            (Dialect::Posix, false, false) => "[^\n]".to_string(),
+            (Dialect::Posix, false, true) => "[^\r\n]".to_string(),
+            (_, true, _) => r"[\s\S]".to_string(),
+            (_, false, true) => r"[^\r\n]".to_string(),
+            (Dialect::JavaScript, false, false) => r"[^\n]".to_string(),
+            (_, false, false) => ".".to_string(),
+        }
+    }
+
+    /// Render an empty match
+    fn assertion(
+        &self,
+        kind: &ast::AssertionKind,
+        state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        use ast::AssertionKind::*;
+        let start_text = match self.dialect {
+            Dialect::JavaScript | Dialect::Posix => "^",
+            _ => r"\A",
+        };
+        let end_text = match self.dialect {
+            Dialect::JavaScript | Dialect::Posix => "$",
+            Dialect::Python => r"\Z",
+            _ => r"\z",
+        };
+        let rendered = match kind {
+            StartText => start_text.to_string(),
+            EndText => end_text.to_string(),
+            StartLine | EndLine if !state.multi_line => {
+                let anchor = if *kind == StartLine {
+                    start_text
+                } else {
+                    end_text
+                };
+                anchor.to_string()
+            }
+            StartLine | EndLine if state.crlf => {
+                return Err(self.unsupported("line anchors in CRLF mode"))
+            }
+            StartLine | EndLine => {
+                let anchor = if *kind == StartLine { "^" } else { "$" };
+                match self.dialect {
+                    Dialect::JavaScript if *kind == StartLine => r"(?<![^\n])".to_string(),
+                    Dialect::JavaScript => r"(?![^\n])".to_string(),
+                    Dialect::Posix => return Err(self.unsupported("multi-line anchors")),
+                    _ => format!("(?m:{})", anchor),
+                }
+            }
+            WordBoundary | NotWordBoundary => {
+                let native = if *kind == WordBoundary { r"\b" } else { r"\B" };
+                match (self.dialect, state.unicode) {
+                    (Dialect::JavaScript | Dialect::Pcre | Dialect::Go, false) => {
+                        native.to_string()
+                    }
+                    (Dialect::Python, false) => format!("(?a:{})", native),
+                    (Dialect::Python | Dialect::DotNet, true) => native.to_string(),
+                    _ => {
+                        let word = self.word_class(state)?;
+                        if *kind == WordBoundary {
+                            format!("(?:(?<={w})(?!{w})|(?<!{w})(?={w}))", w = word)
+                        } else {
+                            format!("(?:(?<={w})(?={w})|(?<!{w})(?!{w}))", w = word)
+                        }
+                    }
+                }
+            }
+            WordBoundaryStart | WordBoundaryStartAngle => {
+                format!("(?:(?<!{w})(?={w}))", w = self.word_class(state)?)
+            }
+            WordBoundaryEnd | WordBoundaryEndAngle => {
+                format!("(?:(?<={w})(?!{w}))", w = self.word_class(state)?)
+            }
+            WordBoundaryStartHalf => format!("(?<!{})", self.word_class(state)?),
+            WordBoundaryEndHalf => format!("(?!{})", self.word_class(state)?),
+        };
+        Ok((rendered, Precedence::Atom))
+    }
+
+    /// Render a class matching word characters for use inside lookaround
+    fn word_class(&self, state: FlagState) -> Result<String, DialectError> {
+        if !self.dialect.lookaround() {
+            return Err(self.unsupported(if state.unicode {
+                "Unicode word boundaries"
+            } else {
+                "ASCII word boundaries"
+            }));
+        }
+        Ok(match (self.dialect, state.unicode) {
+            (_, false) => "[0-9A-Za-z_]".to_string(),
+            (Dialect::Python | Dialect::DotNet, true) => r"\w".to_string(),
+            (_, true) => format!("[{}]", UNICODE_WORD),
+        })
+    }
+
+    /// Render a character class, natively where the dialect has an exact equivalent and as plain ranges otherwise
+    fn class(
+        &mut self,
+        node: &Ast,
+        span: &ast::Span,
+        state: FlagState,
+    ) -> Result<(String, Precedence), DialectError> {
+        let ranges = state.class_ranges(syntax::source(self.pattern, span));
+        if ranges.is_none() && !state.unicode {
+            return Err(self.unsupported("a class of raw bytes"));
+        }
+        let native = if state.case_insensitive && !self.dialect.scoped_case() {
+            None
+        } else {
+            match node {
+                Ast::ClassPerl(class) => self.perl(class, state),
+                Ast::ClassUnicode(class) => self.unicode(class),
+                Ast::ClassBracketed(class) => self.bracketed(class, state)?,
+                _ => None,
+            }
+        };
+        if let Some(rendered) = native {
+            return Ok((rendered, Precedence::Atom));
+        }
+        let ranges = ranges.ok_or_else(|| self.unsupported("a class of raw bytes"))?;
+        Ok((self.ranges(&ranges)?, Precedence::Atom))
+    }
+
+    /// Render `\d`, `\w` or `\s`
+    fn perl(&self, class: &ast::ClassPerl, state: FlagState) -> Option<String> {
+        use ast::ClassPerlKind::*;
+        let negated = class.negated;
+        let bracket = |inner: &str| format!("[{}{}]", if negated { "^" } else { "" }, inner);
+        let escape = |lower: &str| {
+            if negated {
+                lower.to_uppercase()
+            } else {
+                lower.to_string()
+            }
+        };
+        let property = |name: &str| format!(r"\{}{{{}}}", if negated { "P" } else { "p" }, name);
+        match (&class.kind, state.unicode, self.dialect) {
+            (Digit, false, _) => Some(bracket("0-9")),
+            (Word, false, _) => Some(bracket("0-9A-Za-z_")),
+            (Space, false, Dialect::Posix) => Some(bracket("[:space:]")),
+            (Space, false, Dialect::JavaScript) => Some(bracket(r"\t\n\u{B}\f\r ")),
+            (Space, false, Dialect::DotNet) => Some(bracket(r"\t\n\u000B\f\r ")),
+            (Space, false, Dialect::Python) => Some(bracket(r"\t\n\x0B\f\r ")),
+            (Space, false, _) => Some(bracket(r"\t\n\x{B}\f\r ")),
+            (Digit, true, Dialect::Python | Dialect::DotNet) => Some(escape(r"\d")),
+            (Word, true, Dialect::Python | Dialect::DotNet) => Some(escape(r"\w")),
+            (Space, true, Dialect::Python | Dialect::DotNet) => Some(escape(r"\s")),
+            (Digit, true, Dialect::Posix) => Some(bracket("[:digit:]")),
+            (Word, true, Dialect::Posix) => Some(bracket("[:alnum:]_")),
+            (Space, true, Dialect::Posix) => Some(bracket("[:space:]")),
+            (Digit, true, _) => Some(property("Nd")),
+            (Word, true, Dialect::JavaScript | Dialect::Pcre) => Some(bracket(UNICODE_WORD)),
+            (Space, true, Dialect::JavaScript | Dialect::Pcre) => Some(property("White_Space")),
+            (_, true, _) => None,
+        }
+    }
+
+    /// Render `\p{..}` and `\P{..}`
+    fn unicode(&self, class: &ast::ClassUnicode) -> Option<String> {
+        let (property, negated) = match &class.kind {
+            ast::ClassUnicodeKind::OneLetter(letter) => (
+                Property::Category(category(&letter.to_string())?),
+                class.negated,
+            ),
+            ast::ClassUnicodeKind::Named(name) => match category(name) {
+                Some(short) => (Property::Category(short), class.negated),
+                None if FlagState::default()
+                    .translate(&format!(r"\p{{Script={}}}", name))
+                    .is_some() =>
+                {
+                    (Property::Script(name), class.negated)
+                }
+                None => (Property::Binary(name), class.negated),
+            },
+            ast::ClassUnicodeKind::NamedValue { op, name, value } => {
+                let negated = class.negated != (*op == ast::ClassUnicodeOpKind::NotEqual);
+                match normalize(name).as_str() {
+                    "gc" | "generalcategory" => (Property::Category(category(value)?), negated),
+                    "sc" | "script" => (Property::Script(value), negated),
+                    "scx" | "scriptextensions" => (Property::ScriptExtensions(value), negated),
+                    _ => return None,
+                }
+            }
+        };
+        let name = match (property, self.dialect) {
+            (_, Dialect::Python | Dialect::Posix) => return None,
+            (Property::Category("LC"), Dialect::Go | Dialect::DotNet) => return None,
+            (Property::Category("Cn"), Dialect::Go) => return None,
+            (Property::Category(short), _) => short.to_string(),
+            (Property::Script(name), Dialect::JavaScript) => format!("Script={}", name),
+            (Property::Script(name), Dialect::Pcre | Dialect::Go) => name.to_string(),
+            (Property::ScriptExtensions(name), Dialect::JavaScript) => {
+                format!("Script_Extensions={}", name)
+            }
+            (Property::ScriptExtensions(name), Dialect::Pcre) => format!("scx:{}", name),
+            (Property::Binary(name), Dialect::JavaScript | Dialect::Pcre) => name.to_string(),
+            _ => return None,
+        };
+        Some(format!(r"\{}{{{}}}", if negated { "P" } else { "p" }, name))
+    }
+
+    /// Render a bracketed class natively if it only contains literals, ranges and ASCII classes
+    fn bracketed(
+        &self,
+        class: &ast::ClassBracketed,
+        state: FlagState,
+    ) -> Result<Option<String>, DialectError> {
+        let items = match &class.kind {
+            ast::ClassSet::Item(ast::ClassSetItem::Union(union)) => union.items.iter().collect(),
+            ast::ClassSet::Item(item) => vec![item],
+            ast::ClassSet::BinaryOp(_) => return Ok(None),
+        };
+        let mut singles = Vec::new();
+        let mut native = String::new();
+        for item in items {
+            match item {
+                ast::ClassSetItem::Literal(literal) => singles.push((literal.c, literal.c)),
+                ast::ClassSetItem::Range(range) => singles.push((range.start.c, range.end.c)),
+                ast::ClassSetItem::Ascii(class) if !class.negated => {
+                    if self.dialect == Dialect::Posix {
+                        native.push_str(&format!("[:{}:]", ascii_name(&class.kind)));
+                    } else {
+                        match ascii_ranges(&class.kind) {
+                            Some(ranges) => singles.extend(ranges),
+                            None => return Ok(None),
+                        }
+                    }
+                }
+                ast::ClassSetItem::Perl(class) => match self.perl(class, state).and_then(unbracket)
+                {
+                    Some(rendered) => native.push_str(&rendered),
+                    None => return Ok(None),
+                },
+                ast::ClassSetItem::Unicode(class) => {
+                    match self.unicode(class).and_then(unbracket) {
+                        Some(rendered) => native.push_str(&rendered),
+                        None => return Ok(None),
+                    }
+                }
+                _ => return Ok(None),
+            }
+        }
+        if singles.is_empty() && native.is_empty() {
+            return Ok(None);
+        }
+        let inner = match singles.is_empty() {
+            true => String::new(),
+            false => self.class_items(&singles)?,
+        };
+        Ok(Some(format!(
+            "[{}{}{}]",
+            if class.negated { "^" } else { "" },
+            native,
+            inner
+        )))
+    }
+
+    /// Render a class from a sorted list of ranges, negating it if that is shorter
+    fn ranges(&self, ranges: &[(char, char)]) -> Result<String, DialectError> {
+        let complement = syntax::complement(ranges);
+        if ranges.len() == 1 && ranges[0].0 == ranges[0].1 {
+            return self.literal_in_class(ranges[0].0, true);
+        }
+        if complement.is_empty() {
+            return Ok(self.dot(FlagState {
+                dot_matches_new_line: true,
+                ..Default::default()
+            }));
+        }
+        if complement.len() < ranges.len() || ranges.is_empty() {
+            Ok(format!("[^{}]", self.class_items(&complement)?))
+        } else {
+            Ok(format!("[{}]", self.class_items(ranges)?))
+        }
+    }
+
+    /// Render the inside of a bracketed class from a list of ranges
+    fn class_items(&self, ranges: &[(char, char)]) -> Result<String, DialectError> {
+        if self.dialect != Dialect::Posix {
+            return ranges
+                .iter()
+                .map(|&(start, end)| match (start, end) {
+                    (start, end) if start == end => self.literal_in_class(start, false),
+                    (start, end) => Ok(format!(
+                        "{}-{}",
+                        self.literal_in_class(start, false)?,
+                        self.literal_in_class(end, false)?
+                    )),
+                })
+                .collect();
+        }
+        // POSIX brackets have no escapes, so `]` goes first, `-` goes last and `^` is never first
+        let mut first = String::new();
+        let mut middle = String::new();
+        let mut last = String::new();
+        for &(mut start, mut end) in ranges {
+            for special in [']', '^', '-', '['] {
+                if start == special {
+                    push_special(special, &mut first, &mut middle, &mut last);
+                    if start == end {
+                        start = char::MAX;
+                        break;
+                    }
+                    start = syntax::next_char(start).unwrap_or(char::MAX);
+                }
+                if end == special && start <= end {
+                    push_special(special, &mut first, &mut middle, &mut last);
+                    if start == end {
+                        start = char::MAX;
+                        break;
+                    }
+                    end = syntax::previous_char(end);
+                }
+            }
+            if start == char::MAX && end != char::MAX {
+                continue;
+            }
+            if start == '\0' {
+                return Err(self.unsupported("a NUL character"));
+            }
+            if start <= end && (start == '\\' || end == '\\') {
+                return Err(self.unsupported("a backslash in a bracket expression"));
+            }
+            if start == end {
+                middle.push(start);
+            } else if start < end {
+                middle.push_str(&format!("{}-{}", start, end));
+            }
+        }
+        if middle.is_empty() && first.is_empty() && last.is_empty() {
+            return Err(self.unsupported("an empty class"));
+        }
+        if middle.is_empty() && first.is_empty() {
+            return Ok(last);
+        }
+        Ok(format!("{}{}{}", first, middle, last))
+    }
+
+    /// Render a character inside a class, or on its own when `alone` is set
+    fn literal_in_class(&self, chr: char, alone: bool) -> Result<String, DialectError> {
+        if alone {
+            return Ok(self.literal(chr, FlagState::default())?.0);
+        }
+        match (self.dialect, chr) {
+            (Dialect::Posix, chr) => Ok(chr.to_string()),
+            (_, '\\' | ']' | '[' | '^' | '-') => Ok(format!(r"\{}", chr)),
+            (Dialect::JavaScript, '/') => Ok(r"\/".to_string()),
+            (_, '\n') => Ok(r"\n".to_string()),
+            (_, '\t') => Ok(r"\t".to_string()),
+            (_, '\r') => Ok(r"\r".to_string()),
+            (_, chr) if printable(chr) || chr == ' ' => Ok(chr.to_string()),
+            (_, chr) => self.code_point(chr),
+        }
+    }
+
+    /// Render a character by its code point
+    fn code_point(&self, chr: char) -> Result<String, DialectError> {
+        let code = chr as u32;
+        match self.dialect {
+            Dialect::JavaScript => Ok(format!(r"\u{{{:X}}}", code)),
+            Dialect::Python if code <= 0xFF => Ok(format!(r"\x{:02X}", code)),
+            Dialect::Python if code <= 0xFFFF => Ok(format!(r"\u{:04X}", code)),
+            Dialect::Python => Ok(format!(r"\U{:08X}", code)),
+            Dialect::Pcre | Dialect::Go => Ok(format!(r"\x{{{:X}}}", code)),
+            Dialect::DotNet if code <= 0xFFFF => Ok(format!(r"\u{:04X}", code)),
+            Dialect::DotNet => Err(self.unsupported("code points above U+FFFF in a class")),
+            Dialect::Posix => Ok(chr.to_string()),
+        }
+    }
+}
+
+/// Wrap a rendered piece in a group that enables or disables case-insensitivity
+fn scoped_case(case_insensitive: bool, rendered: &str) -> String {
+    format!(
+        "(?{}:{})",
+        if case_insensitive { "i" } else { "-i" },
+        rendered
+    )
+}
+
+/// Return the inside of a rendered class so it can be nested in another, unless it is negated
+fn unbracket(rendered: String) -> Option<String> {
+    match rendered.strip_prefix('[') {
+        Some(inner) if inner.starts_with('^') => None,
+        Some(inner) => inner.strip_suffix(']').map(str::to_string),
+        None => Some(rendered),
+    }
+}
+
+/// Place a character that is special inside POSIX brackets where it is taken literally
+fn push_special(special: char, first: &mut String, middle: &mut String, last: &mut String) {
+    match special {
+        ']' if !first.contains(']') => first.push(']'),
+        '-' if !last.contains('-') => last.push('-'),
+        '^' | '[' if !middle.contains(special) => {
+            if first.is_empty() && middle.is_empty() {
+                last.insert(0, special)
+            } else {
+                middle.push(special)
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Return whether a character can be written as is
+fn printable(chr: char) -> bool {
+    /// The ranges of combining marks, which are escaped so they do not attach to the previous character
+    static MARKS: OnceLock<Vec<(char, char)>> = OnceLock::new();
+    let marks = MARKS.get_or_init(|| {
+        FlagState::default()
+            .class_ranges(r"\p{M}")
+            .unwrap_or_default()
+    });
+    chr.is_ascii_graphic()
+        || (!chr.is_ascii()
+            && chr.is_alphanumeric()
+            && !marks.iter().any(|&(start, end)| start <= chr && chr <= end))
+}
+
+/// Return the ranges of an ASCII class
+fn ascii_ranges(kind: &ast::ClassAsciiKind) -> Option<Vec<(char, char)>> {
+    FlagState::default().class_ranges(&format!("[[:{}:]]", ascii_name(kind)))
+}
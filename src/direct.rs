@@ -4,29 +4,50 @@ use super::humanregex::*;
 use regex::escape;
 use std::marker::PhantomData as pd;
 
-/// Add matching text to the regex string. Text that is added through this function is automatically escaped.
+/// Add matching text to the regex string. Text that is added through this function is automatically escaped,
+/// including whitespace so that it still matches inside of [ignore_whitespace](crate::ignore_whitespace).
 /// ```
 /// let regex_string = human_regex::text("asdf");
 /// assert!(regex_string.to_regex().is_match("asdf"));
 /// assert!(!regex_string.to_regex().is_match("asddf"));
 /// ```
+/// Escaping whitespace changes the regex string, though not what it matches: spaces are written `\ `
+/// and other whitespace as `\x{..}`, where earlier versions wrote them unescaped.
+/// ```
+/// let regex_string = human_regex::text("a b\tc");
+/// assert_eq!(regex_string.to_string(), r"(?:a\ b\x{9}c)");
+/// assert!(regex_string.to_regex().is_match("a b\tc"));
+/// ```
 pub fn text<T>(text: T) -> HumanRegex<LiteralSymbolChain>
 where
     T: Into<String> + fmt::Display,
 {
     HumanRegex(
-        format!("(?:{})", escape(&*text.to_string())),
+        format!("(?:{})", escape_whitespace(&escape(&text.to_string()))),
         pd::<LiteralSymbolChain>,
     )
 }
 
+/// Escapes the whitespace that [escape] leaves alone, which would otherwise be ignored in verbose mode
+pub(crate) fn escape_whitespace(text: &str) -> String {
+    text.chars()
+        .map(|chr| match chr {
+            ' ' => r"\ ".to_string(),
+            chr if chr.is_whitespace() => format!(r"\x{{{:X}}}", chr as u32),
+            chr => chr.to_string(),
+        })
+        .collect()
+}
+
 /// Escapes an entire list for use in something like an [or] or an [and] expression.
 ///
 /// See the [cookbook] stop words example for an example of the utility of this function.
+/// Like [text], it escapes whitespace as well, so that the items still match inside of
+/// [ignore_whitespace](crate::ignore_whitespace).
 /// ```
 /// use human_regex::direct::escape_all;
-/// let escaped_vec = escape_all(&vec!["et-al", "short-term", "full-scale"]);
-/// assert_eq!(escaped_vec, vec![r"et\-al", r"short\-term", r"full\-scale"]);
+/// let escaped_vec = escape_all(&vec!["et-al", "short-term", "full scale"]);
+/// assert_eq!(escaped_vec, vec![r"et\-al", r"short\-term", r"full\ scale"]);
 ///```
 pub fn escape_all<T>(options: &[T]) -> Vec<String>
 where
@@ -34,7 +55,7 @@ where
 {
     options
         .iter()
-        .map(|string| escape(&string.to_string()))
+        .map(|string| escape_whitespace(&escape(&string.to_string())))
         .collect()
 }
 
@@ -46,5 +67,5 @@ where
 /// assert!(!regex_string.to_regex().is_match("007"));
 /// ```
 pub fn nonescaped_text(text: &str) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{})", text.to_string()), pd::<SymbolChain>)
+    HumanRegex(format!("(?:{})", text), pd::<SymbolChain>)
 }
@@ -0,0 +1,300 @@
+//! Functions for exporting the automaton behind a pattern as a Graphviz graph
+//!
+//! [HumanRegex::to_dot] draws the Thompson NFA or the DFA that [regex-automata](https://docs.rs/regex-automata)
+//! compiles a pattern into, in the [DOT](https://graphviz.org/doc/info/lang.html) language. Render the
+//! result with `dot -Tsvg` to see why a pattern matches what it does, for instance how the greedy
+//! `<.+>` in the HTML tag example keeps following `>` instead of stopping at the first one.
+//!
+//! The automata work on bytes, so edges are labeled with byte ranges and non-ASCII characters appear as
+//! their UTF-8 encoding. The graph shows an anchored search, starting at the beginning of the haystack.
+
+use super::dfa::automaton_configs;
+use super::humanregex::*;
+use regex_automata::dfa::{dense, Automaton as _, StartKind};
+use regex_automata::nfa::thompson::{self, State as NfaState, NFA};
+use regex_automata::util::primitives::StateID;
+use regex_automata::{Anchored, Input};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// The automaton to export with [HumanRegex::to_dot]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DotAutomaton {
+    /// The Thompson NFA, with empty transitions for alternation, repetition, assertions and capture groups
+    Nfa,
+    /// The DFA built from the NFA by determinization, which has one transition per byte but no captures
+    Dfa,
+    /// The DFA after minimization, with equivalent states merged
+    MinimalDfa,
+}
+
+/// The error returned when the automaton behind a pattern cannot be built, such as when it exceeds a size limit
+#[derive(Clone, Debug)]
+pub enum DotError {
+    /// The Thompson NFA could not be compiled
+    Nfa(Box<thompson::BuildError>),
+    /// The DFA could not be determinized or minimized
+    Dfa(Box<dense::BuildError>),
+}
+
+impl fmt::Display for DotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DotError::Nfa(error) => write!(f, "could not build the NFA: {}", error),
+            DotError::Dfa(error) => write!(f, "could not build the DFA: {}", error),
+        }
+    }
+}
+
+impl std::error::Error for DotError {}
+
+impl<T: State> HumanRegex<T> {
+    /// Export the automaton behind the pattern as a graph in the DOT language.
+    ///
+    /// Byte transitions are labeled with readable ranges such as `a-z`, and the empty transitions of the
+    /// NFA are dashed, numbered in order of preference and annotated with the capture slots they set.
+    /// In the DFAs, a match is reported one byte late, so match states are entered on the byte after the
+    /// match or on `EOI`, the end of the input. DFAs give up on non-ASCII bytes around Unicode word
+    /// boundaries, so those edges are left out. Patterns of raw bytes draw edges for bytes that aren't valid UTF-8.
+    /// ```
+    /// use human_regex::{digit, named_capture, one_or_more, text, DotAutomaton};
+    /// let regex_string = text("v") + named_capture(one_or_more(digit()), "major");
+    /// let nfa = regex_string.to_dot(DotAutomaton::Nfa).unwrap();
+    /// assert!(nfa.starts_with("digraph nfa {"));
+    /// assert!(nfa.contains(r#"label="v""#));
+    /// assert!(nfa.contains(r#"open group 1 \"major\" (slot 2)"#));
+    /// let dfa = regex_string.to_dot(DotAutomaton::MinimalDfa).unwrap();
+    /// assert!(dfa.contains(r#"label="0-9""#));
+    /// assert!(dfa.contains("doublecircle"));
+    /// ```
+    pub fn to_dot(&self, automaton: DotAutomaton) -> Result<String, DotError> {
+        let (syntax, thompson) = automaton_configs::<T>();
+        match automaton {
+            DotAutomaton::Nfa => {
+                let nfa = thompson::Compiler::new()
+                    .syntax(syntax)
+                    .configure(thompson)
+                    .build(&self.0)
+                    .map_err(|error| DotError::Nfa(Box::new(error)))?;
+                Ok(nfa_to_dot(&nfa))
+            }
+            DotAutomaton::Dfa | DotAutomaton::MinimalDfa => {
+                let dfa = dense::Builder::new()
+                    .configure(
+                        dense::Config::new()
+                            .start_kind(StartKind::Anchored)
+                            .unicode_word_boundary(true)
+                            .minimize(automaton == DotAutomaton::MinimalDfa),
+                    )
+                    .syntax(syntax)
+                    .thompson(thompson)
+                    .build(&self.0)
+                    .map_err(|error| DotError::Dfa(Box::new(error)))?;
+                let name = match automaton {
+                    DotAutomaton::MinimalDfa => "minimal_dfa",
+                    _ => "dfa",
+                };
+                Ok(dfa_to_dot(&dfa, name))
+            }
+        }
+    }
+}
+
+/// Draw the states of an NFA that can be reached from its anchored start state
+fn nfa_to_dot(nfa: &NFA) -> String {
+    let mut dot = header("nfa");
+    let start = nfa.start_anchored();
+    dot.push_str(&format!("  start -> {};\n", start.as_usize()));
+    let mut seen = vec![false; nfa.states().len()];
+    let mut queue = VecDeque::from([start]);
+    seen[start.as_usize()] = true;
+    while let Some(id) = queue.pop_front() {
+        let mut edges: Vec<(StateID, String, bool)> = Vec::new();
+        match nfa.state(id) {
+            NfaState::ByteRange { trans } => {
+                edges.push((trans.next, byte_range(trans.start, trans.end), false))
+            }
+            NfaState::Sparse(sparse) => {
+                let ranges = sparse.transitions.iter().map(|t| (t.start, t.end, t.next));
+                edges.extend(merge_by_target(ranges));
+            }
+            NfaState::Dense(dense) => {
+                let ranges = dense
+                    .transitions
+                    .iter()
+                    .enumerate()
+                    .map(|(byte, next)| (byte as u8, byte as u8, *next));
+                edges.extend(merge_by_target(ranges));
+            }
+            NfaState::Look { look, next } => {
+                edges.push((*next, format!("{:?}", look), true));
+            }
+            NfaState::Union { alternates } => {
+                for (idx, next) in alternates.iter().enumerate() {
+                    edges.push((*next, format!("ε{}", idx + 1), true));
+                }
+            }
+            NfaState::BinaryUnion { alt1, alt2 } => {
+                edges.push((*alt1, "ε1".to_string(), true));
+                edges.push((*alt2, "ε2".to_string(), true));
+            }
+            NfaState::Capture {
+                next,
+                pattern_id,
+                group_index,
+                slot,
+            } => {
+                let action = if slot.as_usize() % 2 == 0 {
+                    "open"
+                } else {
+                    "close"
+                };
+                let name = match nfa
+                    .group_info()
+                    .to_name(*pattern_id, group_index.as_usize())
+                {
+                    Some(name) => format!(" \"{}\"", name),
+                    None => String::new(),
+                };
+                edges.push((
+                    *next,
+                    format!(
+                        "{} group {}{} (slot {})",
+                        action,
+                        group_index.as_usize(),
+                        name,
+                        slot.as_usize()
+                    ),
+                    true,
+                ));
+            }
+            NfaState::Fail => {
+                dot.push_str(&format!(
+                    "  {} [label=\"{}\\nfail\"];\n",
+                    id.as_usize(),
+                    id.as_usize()
+                ));
+            }
+            NfaState::Match { .. } => {
+                dot.push_str(&format!("  {} [shape=doublecircle];\n", id.as_usize()));
+            }
+        }
+        for (next, label, empty) in edges {
+            let style = if empty { ", style=dashed" } else { "" };
+            dot.push_str(&format!(
+                "  {} -> {} [label=\"{}\"{}];\n",
+                id.as_usize(),
+                next.as_usize(),
+                dot_escape(&label),
+                style
+            ));
+            if !seen[next.as_usize()] {
+                seen[next.as_usize()] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Draw the states of a DFA that can be reached from its anchored start state, leaving out the dead and quit states
+fn dfa_to_dot(dfa: &dense::DFA<Vec<u32>>, name: &str) -> String {
+    let mut dot = header(name);
+    let start = dfa
+        .start_state_forward(&Input::new("").anchored(Anchored::Yes))
+        .unwrap();
+    let mut numbers = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+    dot.push_str("  start -> 0;\n");
+    while let Some(id) = queue.pop_front() {
+        let number = numbers[&id];
+        if dfa.is_match_state(id) {
+            dot.push_str(&format!("  {} [shape=doublecircle];\n", number));
+        }
+        let bytes = (0..=255u8).map(|byte| (byte, byte, dfa.next_state(id, byte)));
+        let mut edges = merge_by_target(bytes);
+        edges.push((dfa.next_eoi_state(id), "EOI".to_string(), false));
+        for (next, label, _) in edges {
+            if dfa.is_dead_state(next) || dfa.is_quit_state(next) {
+                continue;
+            }
+            let count = numbers.len();
+            let next_number = *numbers.entry(next).or_insert_with(|| {
+                queue.push_back(next);
+                count
+            });
+            dot.push_str(&format!(
+                "  {} -> {} [label=\"{}\"];\n",
+                number,
+                next_number,
+                dot_escape(&label)
+            ));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
+
+/// Start a graph with the settings shared by every automaton
+fn header(name: &str) -> String {
+    format!(
+        "digraph {} {{\n  rankdir=LR;\n  node [shape=circle];\n  start [shape=point];\n",
+        name
+    )
+}
+
+/// Merge byte range transitions that lead to the same state into a single labeled edge per state,
+/// in the order the states are first reached
+fn merge_by_target(
+    transitions: impl Iterator<Item = (u8, u8, StateID)>,
+) -> Vec<(StateID, String, bool)> {
+    let mut order = Vec::new();
+    let mut ranges: BTreeMap<StateID, Vec<(u8, u8)>> = BTreeMap::new();
+    for (start, end, next) in transitions {
+        let target = ranges.entry(next).or_insert_with(|| {
+            order.push(next);
+            Vec::new()
+        });
+        match target.last_mut() {
+            Some(last) if last.1 as u16 + 1 == start as u16 => last.1 = end,
+            _ => target.push((start, end)),
+        }
+    }
+    order
+        .into_iter()
+        .map(|next| {
+            let label = ranges[&next]
+                .iter()
+                .map(|(start, end)| byte_range(*start, *end))
+                .collect::<Vec<String>>()
+                .join(", ");
+            (next, label, false)
+        })
+        .collect()
+}
+
+/// Write a range of bytes readably, like `a-z` or `\xC2-\xDF`
+fn byte_range(start: u8, end: u8) -> String {
+    if start == end {
+        readable_byte(start)
+    } else {
+        format!("{}-{}", readable_byte(start), readable_byte(end))
+    }
+}
+
+/// Write a byte as its ASCII character if it is printable, or as a hex escape otherwise
+fn readable_byte(byte: u8) -> String {
+    match byte {
+        b' ' => "space".to_string(),
+        b'\n' => r"\n".to_string(),
+        b'\t' => r"\t".to_string(),
+        b'\r' => r"\r".to_string(),
+        byte if byte.is_ascii_graphic() => (byte as char).to_string(),
+        byte => format!(r"\x{:02X}", byte),
+    }
+}
+
+/// Escape text for use inside of a quoted DOT string
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', r"\\").replace('"', "\\\"")
+}
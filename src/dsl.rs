@@ -0,0 +1,1327 @@
+//! A textual language for writing patterns with the builder vocabulary
+//!
+//! Patterns can be written as text, for instance in configuration files or on the command line, using
+//! the same names as the builder functions. Items are separated by commas and a function takes its
+//! arguments after its name, without parentheses, so that the pattern
+//! ```text
+//! beginning, exactly 4 digit, "-", capture month: exactly 2 digit, end
+//! ```
+//! is the same as
+//! ```
+//! use human_regex::{beginning, capture, digit, end, exactly, named_capture, parse_dsl, text};
+//! let regex_string = beginning()
+//!     + exactly(4, digit())
+//!     + text("-")
+//!     + named_capture(exactly(2, digit()), "month")
+//!     + end();
+//! assert_eq!(
+//!     parse_dsl(r#"beginning, exactly 4 digit, "-", capture month: exactly 2 digit, end"#)
+//!         .unwrap()
+//!         .to_string(),
+//!     regex_string.to_string()
+//! );
+//! ```
+//! The vocabulary is:
+//!
+//! | DSL | Builder function |
+//! |:----|:-----------------|
+//! | `"text"` or `text "text"` | [text](crate::text) |
+//! | `nonescaped_text "regex"` | [nonescaped_text](crate::nonescaped_text) |
+//! | `any`, `digit`, `word`, `whitespace`, `non_digit`, ... | [any](crate::any), [digit](crate::digit), ... |
+//! | `alphanumeric`, `hexdigit`, `punctuation`, ... | the functions of the [ascii](crate::ascii) module |
+//! | `beginning`, `end`, `word_boundary`, ... | [beginning](crate::beginning), [end](crate::end), ... |
+//! | `within_range 'a'..='z'`, `without_range 'a'..='z'` | [within_range](crate::within_range), [without_range](crate::without_range) |
+//! | `within_set ['a', 'e']`, `without_set ['a', 'e']` | [within_set](crate::within_set), [without_set](crate::without_set) |
+//! | `unicode_category CurrencySymbol`, `non_unicode_category ...` | [unicode_category](crate::unicode_category), ... |
+//! | `unicode_script Greek`, `non_unicode_script Greek` | [unicode_script](crate::unicode_script), ... |
+//! | `unicode_script_extensions Greek`, `non_unicode_script_extensions ...` | [unicode_script_extensions](crate::unicode_script_extensions), ... |
+//! | `unicode_property Emoji`, `non_unicode_property Emoji` | [unicode_property](crate::unicode_property), ... |
+//! | `unicode_block BasicLatin`, `non_unicode_block BasicLatin` | [unicode_block](crate::unicode_block), ... |
+//! | `exactly 4 x`, `at_least 1 x`, `between 2 5 x` | [exactly](crate::exactly), [at_least](crate::at_least), [between](crate::between) |
+//! | `one_or_more x`, `zero_or_more x`, `zero_or_one x` | [one_or_more](crate::one_or_more), ... |
+//! | `lazy one_or_more x` | [lazy](crate::HumanRegex::lazy) |
+//! | `capture x`, `capture name: x` or `named_capture name: x` | [capture](crate::capture), [named_capture](crate::named_capture) |
+//! | `or [x, y, z]` | [or](crate::or) |
+//! | `and x y`, `xor x y`, `subtract x y` | [and](crate::and), [xor](crate::xor), [subtract](crate::subtract) |
+//! | `case_insensitive x`, `multi_line_mode x`, ... | the functions of the [flags](crate::flags) module |
+//! | `byte 0xFF`, `byte_range 0x80..=0xBF`, `any_byte` | [byte](crate::byte), [byte_range](crate::byte_range), [any_byte](crate::any_byte) |
+//! | `comment "why" x` | [comment](crate::HumanRegex::comment) |
+//! | `(x, y)` | a sequence used as a single argument |
+//!
+//! Strings and characters use Rust escapes such as `\n`, `\"` and `\u{1F600}`, and `#` starts a comment
+//! that runs to the end of the line. A [PatternLibrary] adds named patterns that other patterns can use
+//! by name, and [HumanRegex::to_dsl] writes any pattern back in the DSL. Patterns that match raw bytes
+//! are parsed with [parse_bytes_dsl].
+
+use super::humanregex::*;
+use super::syntax;
+use super::verbose::comment_of;
+use super::*;
+use regex_syntax::ast::{self, Ast};
+use std::marker::PhantomData as pd;
+
+/// An error in a pattern written in the DSL
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DslError {
+    /// What went wrong
+    pub message: String,
+    /// Where it went wrong, as a byte offset into the source
+    pub offset: usize,
+}
+
+impl fmt::Display for DslError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.message, self.offset)
+    }
+}
+
+impl std::error::Error for DslError {}
+
+/// Parse a pattern written in the DSL
+/// ```
+/// use human_regex::parse_dsl;
+/// let regex_string = parse_dsl(r#"one_or_more within_range 'a'..='z', "@", or ["example", "test"], ".com""#).unwrap();
+/// assert!(regex_string.to_regex().is_match("user@example.com"));
+/// assert_eq!(
+///     parse_dsl("exactly 4 digits").err().unwrap().to_string(),
+///     "unknown function or pattern `digits` at byte 10"
+/// );
+/// ```
+pub fn parse_dsl(source: &str) -> Result<HumanRegex, DslError> {
+    Parser::new(source, 0, &[]).pattern()
+}
+
+/// Parse a pattern written in the DSL that may match raw bytes, which can only be matched against `&[u8]`
+/// ```
+/// use human_regex::{parse_bytes_dsl, parse_dsl};
+/// let regex_string = parse_bytes_dsl(r#""caf", one_or_more byte_range 0x80..=0xFF"#).unwrap();
+/// assert!(regex_string.to_bytes_regex().is_match(b"caf\xE9"));
+/// assert_eq!(
+///     parse_dsl("byte 0xFF").err().unwrap().to_string(),
+///     "the pattern matches raw bytes, so it must be parsed with parse_bytes_dsl at byte 0"
+/// );
+/// ```
+pub fn parse_bytes_dsl(source: &str) -> Result<HumanRegex<ByteChain>, DslError> {
+    let parser = Parser {
+        bytes: true,
+        ..Parser::new(source, 0, &[])
+    };
+    let regex = parser.pattern()?;
+    Ok(HumanRegex(regex.0, pd::<ByteChain>))
+}
+
+/// Parse a Rust expression made of builder functions, like `exactly(4, digit()) + text("-")`, as it would
+/// be written in code. Functions may be prefixed with `hr::` or `human_regex::`, and enum variants with
+/// the name of their enum.
+/// ```
+/// use human_regex::{digit, exactly, parse_builder_expression, text};
+/// let regex_string = parse_builder_expression(
+///     r#"hr::exactly(4, hr::digit()) + hr::text("-") + hr::one_or_more(hr::unicode_script(hr::UnicodeScript::Greek)).lazy()"#,
+/// )
+/// .unwrap();
+/// assert!(regex_string.to_regex().is_match("2024-αβ"));
+/// assert_eq!(
+///     parse_builder_expression("exactly(4, digit()) + text(\"-\")").unwrap().to_string(),
+///     (exactly(4, digit()) + text("-")).to_string()
+/// );
+/// ```
+pub fn parse_builder_expression(source: &str) -> Result<HumanRegex, DslError> {
+    Parser::builder(source).pattern()
+}
+
+/// A collection of named patterns written in the DSL, which can refer to the patterns defined before them
+///
+/// Each definition starts at the beginning of a line with a name and `=`, and continues on the
+/// following lines that start with whitespace. Lines starting with `#` are comments.
+/// ```
+/// use human_regex::PatternLibrary;
+/// let library = PatternLibrary::parse(r#"
+/// ## Dates
+/// year = exactly 4 digit
+/// month = exactly 2 digit
+/// date = capture year: year, "-",
+///     capture month: month
+/// "#).unwrap();
+/// assert_eq!(library.names().collect::<Vec<_>>(), ["year", "month", "date"]);
+/// let date = library.get("date").unwrap().to_regex();
+/// assert_eq!(&date.captures("on 2024-02").unwrap()["month"], "02");
+/// let shouted = library.parse_pattern("case_insensitive date").unwrap();
+/// assert!(shouted.to_regex().is_match("2024-02"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PatternLibrary {
+    /// The names and regex strings of the patterns, in order of definition
+    patterns: Vec<(String, String)>,
+}
+
+impl PatternLibrary {
+    /// Parse the definitions of a library
+    pub fn parse(source: &str) -> Result<Self, DslError> {
+        let mut library = PatternLibrary::default();
+        let mut definitions: Vec<(usize, usize)> = Vec::new();
+        let mut offset = 0;
+        for line in source.split_inclusive('\n') {
+            let continues = line.starts_with([' ', '\t']) || line.trim().is_empty();
+            match definitions.last_mut() {
+                _ if line.trim_start().starts_with('#') => {}
+                Some((_, end)) if continues => *end = offset + line.len(),
+                _ if continues => {}
+                _ => definitions.push((offset, offset + line.len())),
+            }
+            offset += line.len();
+        }
+        for (start, end) in definitions {
+            let definition = &source[start..end];
+            let (name, expression) = match definition.split_once('=') {
+                Some((name, expression)) if is_name(name.trim()) => (name.trim(), expression),
+                _ => {
+                    return Err(DslError {
+                        message: "expected a definition like `name = pattern`".to_string(),
+                        offset: start,
+                    })
+                }
+            };
+            if library.get(name).is_some() {
+                return Err(DslError {
+                    message: format!("`{}` is already defined", name),
+                    offset: start,
+                });
+            }
+            let base = end - expression.len();
+            let regex = Parser::new(expression, base, &library.patterns).pattern()?;
+            library.patterns.push((name.to_string(), regex.0));
+        }
+        Ok(library)
+    }
+
+    /// Return the pattern with the given name
+    pub fn get(&self, name: &str) -> Option<HumanRegex> {
+        self.patterns
+            .iter()
+            .find(|(defined, _)| defined == name)
+            .map(|(_, regex)| HumanRegex(regex.clone(), pd::<SymbolChain>))
+    }
+
+    /// Return the names of the patterns, in order of definition
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.patterns.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Parse a pattern written in the DSL that may use the patterns of the library by name
+    pub fn parse_pattern(&self, source: &str) -> Result<HumanRegex, DslError> {
+        Parser::new(source, 0, &self.patterns).pattern()
+    }
+}
+
+/// Return whether text can be used as a name in the DSL
+fn is_name(text: &str) -> bool {
+    let mut chars = text.chars();
+    chars
+        .next()
+        .is_some_and(|chr| chr.is_ascii_alphabetic() || chr == '_')
+        && chars.all(|chr| chr.is_ascii_alphanumeric() || chr == '_')
+}
+
+/// What a parsed item can be used for
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// A character class, which [and], [xor] and [subtract] accept
+    Class,
+    /// A repetition, which [lazy](HumanRegex::lazy) accepts
+    Repetition,
+    /// Anything else
+    Chain,
+}
+
+/// A parsed item with its kind
+struct Item {
+    /// The regex string of the item
+    regex: HumanRegex,
+    /// What the item can be used for
+    kind: Kind,
+}
+
+impl Item {
+    /// Wrap a builder result with its kind
+    fn new<T>(regex: HumanRegex<T>, kind: Kind) -> Self {
+        Item {
+            regex: HumanRegex(regex.0, pd::<SymbolChain>),
+            kind,
+        }
+    }
+}
+
+/// Wrap a character class
+fn class<T>(regex: HumanRegex<T>) -> Result<Item, DslError> {
+    Ok(Item::new(regex, Kind::Class))
+}
+
+/// Wrap a repetition
+fn repetition<T>(regex: HumanRegex<T>) -> Result<Item, DslError> {
+    Ok(Item::new(regex, Kind::Repetition))
+}
+
+/// Wrap anything that is neither a character class nor a repetition
+fn chain<T>(regex: HumanRegex<T>) -> Result<Item, DslError> {
+    Ok(Item::new(regex, Kind::Chain))
+}
+
+/// The functions without arguments, with the regex strings they build and their kinds
+fn nullary_functions() -> [(&'static str, String, Kind); 36] {
+    [
+        ("any", any().0, Kind::Class),
+        ("digit", digit().0, Kind::Class),
+        ("non_digit", non_digit().0, Kind::Class),
+        ("word", word().0, Kind::Class),
+        ("non_word", non_word().0, Kind::Class),
+        ("whitespace", whitespace().0, Kind::Class),
+        ("non_whitespace", non_whitespace().0, Kind::Class),
+        ("alphanumeric", alphanumeric().0, Kind::Class),
+        ("non_alphanumeric", non_alphanumeric().0, Kind::Class),
+        ("alphabetic", alphabetic().0, Kind::Class),
+        ("non_alphabetic", non_alphabetic().0, Kind::Class),
+        ("lowercase", lowercase().0, Kind::Class),
+        ("non_lowercase", non_lowercase().0, Kind::Class),
+        ("uppercase", uppercase().0, Kind::Class),
+        ("non_uppercase", non_uppercase().0, Kind::Class),
+        ("hexdigit", hexdigit().0, Kind::Class),
+        ("non_hexdigit", non_hexdigit().0, Kind::Class),
+        ("ascii", ascii().0, Kind::Class),
+        ("non_ascii", non_ascii().0, Kind::Class),
+        ("blank", blank().0, Kind::Class),
+        ("non_blank", non_blank().0, Kind::Class),
+        ("control", control().0, Kind::Class),
+        ("non_control", non_control().0, Kind::Class),
+        ("graphical", graphical().0, Kind::Class),
+        ("non_graphical", non_graphical().0, Kind::Class),
+        ("printable", printable().0, Kind::Class),
+        ("non_printable", non_printable().0, Kind::Class),
+        ("punctuation", punctuation().0, Kind::Class),
+        ("non_punctuation", non_punctuation().0, Kind::Class),
+        ("word_boundary", word_boundary().0, Kind::Chain),
+        ("non_word_boundary", non_word_boundary().0, Kind::Chain),
+        ("beginning", beginning().0, Kind::Chain),
+        ("end", end().0, Kind::Chain),
+        ("beginning_of_text", beginning_of_text().0, Kind::Chain),
+        ("end_of_text", end_of_text().0, Kind::Chain),
+        ("any_byte", any_byte().0, Kind::Chain),
+    ]
+}
+
+/// Return the regex string and kind of a function without arguments
+fn nullary_function(name: &str) -> Option<(String, Kind)> {
+    nullary_functions()
+        .into_iter()
+        .find(|(function, _, _)| *function == name)
+        .map(|(_, regex, kind)| (regex, kind))
+}
+
+/// A function that sets a flag for its argument
+type FlagFunction = fn(HumanRegex) -> HumanRegex;
+
+/// The functions that set flags
+const FLAG_FUNCTIONS: [(&str, FlagFunction); 9] = [
+    ("case_insensitive", case_insensitive),
+    ("case_sensitive", case_sensitive),
+    ("multi_line_mode", multi_line_mode),
+    ("dot_matches_newline_too", dot_matches_newline_too),
+    ("disable_unicode", disable_unicode),
+    ("enable_unicode", enable_unicode),
+    ("crlf_mode", crlf_mode),
+    ("swap_greed", swap_greed),
+    ("ignore_whitespace", ignore_whitespace),
+];
+
+/// Return the function that sets the flag with the given name
+fn flag_function(name: &str) -> Option<FlagFunction> {
+    FLAG_FUNCTIONS
+        .into_iter()
+        .find(|(function, _)| *function == name)
+        .map(|(_, apply)| apply)
+}
+
+/// A recursive descent parser for the DSL
+struct Parser<'s> {
+    /// The text being parsed
+    source: &'s str,
+    /// The position in the text
+    position: usize,
+    /// The offset of the text within a larger source, for error messages
+    base: usize,
+    /// The patterns that can be used by name
+    library: &'s [(String, String)],
+    /// Whether the text uses the syntax of Rust builder expressions instead of the DSL
+    builder: bool,
+    /// Whether the pattern may match raw bytes
+    bytes: bool,
+}
+
+impl<'s> Parser<'s> {
+    /// Start parsing a text
+    fn new(source: &'s str, base: usize, library: &'s [(String, String)]) -> Self {
+        Parser {
+            source,
+            position: 0,
+            base,
+            library,
+            builder: false,
+            bytes: false,
+        }
+    }
+
+    /// Start parsing a Rust builder expression
+    fn builder(source: &'s str) -> Self {
+        Parser {
+            builder: true,
+            ..Parser::new(source, 0, &[])
+        }
+    }
+
+    /// Build an error at a position of the text
+    fn error<T>(&self, message: impl Into<String>, position: usize) -> Result<T, DslError> {
+        Err(DslError {
+            message: message.into(),
+            offset: self.base + position,
+        })
+    }
+
+    /// Parse a whole pattern and check that it is a valid regex
+    fn pattern(mut self) -> Result<HumanRegex, DslError> {
+        let regex = self.sequence()?;
+        self.skip_space();
+        if self.position < self.source.len() {
+            let expected = format!("expected `{}` or the end of the pattern", self.joiner());
+            return self.error(expected, self.position);
+        }
+        let checked = match self.bytes {
+            true => regex::bytes::Regex::new(&regex.0).map(|_| ()),
+            false => regex::Regex::new(&regex.0).map(|_| ()),
+        };
+        if let Err(error) = checked {
+            if !self.bytes && regex::bytes::Regex::new(&regex.0).is_ok() {
+                let message =
+                    "the pattern matches raw bytes, so it must be parsed with parse_bytes_dsl";
+                return self.error(message, 0);
+            }
+            return self.error(format!("the pattern is not a valid regex: {}", error), 0);
+        }
+        Ok(regex)
+    }
+
+    /// Parse items separated by commas
+    fn sequence(&mut self) -> Result<HumanRegex, DslError> {
+        let mut regex = self.item()?.regex;
+        while self.eat(self.joiner()) {
+            regex = regex + self.item()?.regex;
+        }
+        Ok(regex)
+    }
+
+    /// Return the character that joins the items of a sequence
+    fn joiner(&self) -> char {
+        match self.builder {
+            true => '+',
+            false => ',',
+        }
+    }
+
+    /// Consume the parenthesis opening the arguments of a function, in builder expressions
+    fn open(&mut self) -> Result<(), DslError> {
+        match self.builder {
+            true => self.expect("("),
+            false => Ok(()),
+        }
+    }
+
+    /// Consume the comma between the arguments of a function, in builder expressions
+    fn separator(&mut self) -> Result<(), DslError> {
+        match self.builder {
+            true => self.expect(","),
+            false => Ok(()),
+        }
+    }
+
+    /// Consume the parenthesis closing the arguments of a function, in builder expressions
+    fn close(&mut self) -> Result<(), DslError> {
+        match self.builder {
+            true => self.expect(")"),
+            false => Ok(()),
+        }
+    }
+
+    /// Consume the opening of a list, which is borrowed in builder expressions
+    fn open_list(&mut self) -> Result<(), DslError> {
+        if self.builder {
+            self.eat('&');
+        }
+        self.expect("[")
+    }
+
+    /// Parse a variant of an enum, optionally prefixed with the path of the enum
+    fn variant(&mut self, enumeration: &str) -> Result<(&'s str, usize), DslError> {
+        let (mut name, mut position) = self.name()?;
+        while ["hr", "human_regex", enumeration].contains(&name)
+            && self.source[self.position..].starts_with("::")
+        {
+            self.position += 2;
+            (name, position) = self.name()?;
+        }
+        Ok((name, position))
+    }
+
+    /// Skip whitespace and comments
+    fn skip_space(&mut self) {
+        loop {
+            let rest = &self.source[self.position..];
+            let trimmed = rest.trim_start();
+            self.position += rest.len() - trimmed.len();
+            if trimmed.starts_with('#') {
+                self.position += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Return the next character after any whitespace, without consuming it
+    fn peek(&mut self) -> Option<char> {
+        self.skip_space();
+        self.source[self.position..].chars().next()
+    }
+
+    /// Consume the given character if it comes next
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.position += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume the given text, or fail
+    fn expect(&mut self, expected: &str) -> Result<(), DslError> {
+        self.skip_space();
+        if self.source[self.position..].starts_with(expected) {
+            self.position += expected.len();
+            Ok(())
+        } else {
+            self.error(format!("expected `{}`", expected), self.position)
+        }
+    }
+
+    /// Parse a name, returning it with its position
+    fn name(&mut self) -> Result<(&'s str, usize), DslError> {
+        self.skip_space();
+        let start = self.position;
+        let rest = &self.source[start..];
+        let length = rest
+            .find(|chr: char| !(chr.is_ascii_alphanumeric() || chr == '_'))
+            .unwrap_or(rest.len());
+        if length == 0 || !is_name(&rest[..length]) {
+            return self.error("expected a name", start);
+        }
+        self.position += length;
+        Ok((&rest[..length], start))
+    }
+
+    /// Parse a number of repetitions
+    fn number(&mut self) -> Result<u32, DslError> {
+        self.skip_space();
+        let start = self.position;
+        let rest = &self.source[start..];
+        let length = rest
+            .find(|chr: char| !chr.is_ascii_digit())
+            .unwrap_or(rest.len());
+        match rest[..length].parse() {
+            Ok(number) if number <= MAX_REPETITIONS => {
+                self.position += length;
+                Ok(number)
+            }
+            _ => self.error(
+                format!("expected a number from 0 to {}", MAX_REPETITIONS),
+                start,
+            ),
+        }
+    }
+
+    /// Parse a byte, written in hexadecimal like `0xFF` or in decimal
+    fn byte(&mut self) -> Result<u8, DslError> {
+        self.skip_space();
+        let start = self.position;
+        let rest = &self.source[start..];
+        let (digits, radix) = match rest.strip_prefix("0x") {
+            Some(hex) => (hex, 16),
+            None => (rest, 10),
+        };
+        let length = digits
+            .find(|chr: char| !chr.is_ascii_alphanumeric())
+            .unwrap_or(digits.len());
+        match u8::from_str_radix(&digits[..length], radix) {
+            Ok(value) => {
+                self.position += rest.len() - digits.len() + length;
+                Ok(value)
+            }
+            Err(_) => self.error("expected a byte from 0x00 to 0xFF", start),
+        }
+    }
+
+    /// Parse the characters between quotes, handling escapes
+    fn quoted(&mut self, quote: char) -> Result<String, DslError> {
+        self.skip_space();
+        let start = self.position;
+        if !self.source[start..].starts_with(quote) {
+            let what = if quote == '"' {
+                "a string"
+            } else {
+                "a character"
+            };
+            return self.error(format!("expected {}", what), start);
+        }
+        let mut text = String::new();
+        let mut chars = self.source[start + 1..].char_indices();
+        while let Some((idx, chr)) = chars.next() {
+            let at = start + 1 + idx;
+            match chr {
+                chr if chr == quote => {
+                    self.position = at + 1;
+                    return Ok(text);
+                }
+                '\\' => match chars.next() {
+                    Some((_, 'n')) => text.push('\n'),
+                    Some((_, 'r')) => text.push('\r'),
+                    Some((_, 't')) => text.push('\t'),
+                    Some((_, '0')) => text.push('\0'),
+                    Some((_, chr @ ('\\' | '"' | '\''))) => text.push(chr),
+                    Some((idx, 'u')) => {
+                        let rest = &self.source[start + 2 + idx..];
+                        let code = rest
+                            .strip_prefix('{')
+                            .and_then(|rest| rest.split_once('}'))
+                            .and_then(|(hex, _)| {
+                                u32::from_str_radix(hex, 16).ok().map(|code| (hex, code))
+                            });
+                        match code
+                            .and_then(|(hex, code)| char::from_u32(code).map(|chr| (hex, chr)))
+                        {
+                            Some((hex, chr)) => {
+                                text.push(chr);
+                                for _ in 0..hex.len() + 2 {
+                                    chars.next();
+                                }
+                            }
+                            None => return self.error("invalid unicode escape", at),
+                        }
+                    }
+                    _ => return self.error("invalid escape", at),
+                },
+                chr => text.push(chr),
+            }
+        }
+        self.error("unterminated quote", start)
+    }
+
+    /// Parse a single character in single quotes
+    fn character(&mut self) -> Result<char, DslError> {
+        self.skip_space();
+        let start = self.position;
+        let text = self.quoted('\'')?;
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(chr), None) => Ok(chr),
+            _ => self.error("expected a single character", start),
+        }
+    }
+
+    /// Parse one item, such as a function with its arguments or a string
+    fn item(&mut self) -> Result<Item, DslError> {
+        let mut item = self.atom()?;
+        while self.builder && self.eat('.') {
+            let (method, position) = self.name()?;
+            match method {
+                "lazy" if item.kind == Kind::Repetition => {
+                    self.expect("(")?;
+                    self.expect(")")?;
+                    item = Item::new(item.regex.lazy(), Kind::Chain);
+                }
+                "lazy" => return self.error("`lazy` needs a repetition", position),
+                "comment" => {
+                    self.expect("(")?;
+                    let text = self.quoted('"')?;
+                    self.expect(")")?;
+                    item = Item::new(item.regex.comment(&text), Kind::Chain);
+                }
+                _ => return self.error(format!("unknown method `{}`", method), position),
+            }
+        }
+        Ok(item)
+    }
+
+    /// Parse one item without the methods called on it
+    fn atom(&mut self) -> Result<Item, DslError> {
+        match self.peek() {
+            Some('"') => return Ok(Item::new(text(self.quoted('"')?), Kind::Chain)),
+            Some('(') => {
+                self.position += 1;
+                let regex = self.sequence()?;
+                self.expect(")")?;
+                return Ok(Item::new(regex, Kind::Chain));
+            }
+            _ => {}
+        }
+        let (mut name, mut start) = self.name()?;
+        if self.builder && ["hr", "human_regex"].contains(&name) && self.eat(':') {
+            self.expect(":")?;
+            (name, start) = self.name()?;
+        }
+        match name {
+            "text" => {
+                self.open()?;
+                let text = text(self.quoted('"')?);
+                self.close()?;
+                chain(text)
+            }
+            "nonescaped_text" => {
+                self.open()?;
+                self.skip_space();
+                let position = self.position;
+                let regex = self.quoted('"')?;
+                if let Err(error) = regex_syntax::ast::parse::Parser::new().parse(&regex) {
+                    return self.error(format!("invalid regex: {}", error.kind()), position);
+                }
+                self.close()?;
+                chain(nonescaped_text(&regex))
+            }
+            name if nullary_function(name).is_some() => {
+                self.open()?;
+                self.close()?;
+                let (regex, kind) = nullary_function(name).unwrap_or_else(|| unreachable!());
+                Ok(Item::new(HumanRegex(regex, pd::<SymbolChain>), kind))
+            }
+            "within_range" | "without_range" => {
+                self.open()?;
+                let from = self.character()?;
+                self.expect("..=")?;
+                let to = self.character()?;
+                self.close()?;
+                match name {
+                    "within_range" => class(within_range(from..=to)),
+                    _ => class(without_range(from..=to)),
+                }
+            }
+            "within_set" | "without_set" => {
+                self.open()?;
+                self.open_list()?;
+                let mut set = vec![self.character()?];
+                while self.eat(',') {
+                    set.push(self.character()?);
+                }
+                self.expect("]")?;
+                self.close()?;
+                match name {
+                    "within_set" => class(within_set(&set)),
+                    _ => class(without_set(&set)),
+                }
+            }
+            "byte" => {
+                self.open()?;
+                let value = self.byte()?;
+                self.close()?;
+                chain(byte(value))
+            }
+            "byte_range" => {
+                self.open()?;
+                self.skip_space();
+                let position = self.position;
+                let from = self.byte()?;
+                self.expect("..=")?;
+                let to = self.byte()?;
+                self.close()?;
+                if from > to {
+                    return self.error("the range of bytes is empty", position);
+                }
+                chain(byte_range(from..=to))
+            }
+            "unicode_category" | "non_unicode_category" => {
+                self.open()?;
+                let (variant, position) = self.variant("UnicodeCategory")?;
+                self.close()?;
+                let category = UnicodeCategory::ALL
+                    .into_iter()
+                    .find(|category| format!("{:?}", category) == variant);
+                match (category, name) {
+                    (Some(category), "unicode_category") => class(unicode_category(category)),
+                    (Some(category), _) => class(non_unicode_category(category)),
+                    (None, _) => {
+                        self.error(format!("unknown Unicode category `{}`", variant), position)
+                    }
+                }
+            }
+            "unicode_script"
+            | "non_unicode_script"
+            | "unicode_script_extensions"
+            | "non_unicode_script_extensions" => {
+                self.open()?;
+                let (variant, position) = self.variant("UnicodeScript")?;
+                self.close()?;
+                let script = UnicodeScript::ALL
+                    .into_iter()
+                    .find(|script| format!("{:?}", script) == variant);
+                match (script, name) {
+                    (Some(script), "unicode_script") => class(unicode_script(script)),
+                    (Some(script), "non_unicode_script") => class(non_unicode_script(script)),
+                    (Some(script), "unicode_script_extensions") => {
+                        class(unicode_script_extensions(script))
+                    }
+                    (Some(script), _) => class(non_unicode_script_extensions(script)),
+                    (None, _) => {
+                        self.error(format!("unknown Unicode script `{}`", variant), position)
+                    }
+                }
+            }
+            "unicode_property" | "non_unicode_property" => {
+                self.open()?;
+                let (variant, position) = self.variant("UnicodeProperty")?;
+                self.close()?;
+                let property = UnicodeProperty::ALL
+                    .into_iter()
+                    .find(|property| format!("{:?}", property) == variant);
+                match (property, name) {
+                    (Some(property), "unicode_property") => class(unicode_property(property)),
+                    (Some(property), _) => class(non_unicode_property(property)),
+                    (None, _) => {
+                        self.error(format!("unknown Unicode property `{}`", variant), position)
+                    }
+                }
+            }
+            "unicode_block" | "non_unicode_block" => {
+                self.open()?;
+                let (variant, position) = self.variant("UnicodeBlock")?;
+                self.close()?;
+                let block = UnicodeBlock::ALL
+                    .into_iter()
+                    .find(|block| format!("{:?}", block) == variant);
+                match (block, name) {
+                    (Some(block), "unicode_block") => class(unicode_block(block)),
+                    (Some(block), _) => class(non_unicode_block(block)),
+                    (None, _) => {
+                        self.error(format!("unknown Unicode block `{}`", variant), position)
+                    }
+                }
+            }
+            "exactly" | "at_least" => {
+                self.open()?;
+                let n = self.number()?;
+                self.separator()?;
+                let target = self.item()?.regex;
+                self.close()?;
+                match name {
+                    "exactly" => repetition(exactly(n, target)),
+                    _ => repetition(at_least(n, target)),
+                }
+            }
+            "between" => {
+                self.open()?;
+                let n = self.number()?;
+                self.separator()?;
+                self.skip_space();
+                let position = self.position;
+                let m = self.number()?;
+                if n > m {
+                    return self.error("the range of repetitions is empty", position);
+                }
+                self.separator()?;
+                let target = self.item()?.regex;
+                self.close()?;
+                repetition(between(n, m, target))
+            }
+            "one_or_more" | "zero_or_more" | "zero_or_one" => {
+                self.open()?;
+                let target = self.item()?.regex;
+                self.close()?;
+                match name {
+                    "one_or_more" => repetition(one_or_more(target)),
+                    "zero_or_more" => repetition(zero_or_more(target)),
+                    _ => repetition(zero_or_one(target)),
+                }
+            }
+            "lazy" if !self.builder => {
+                self.skip_space();
+                let position = self.position;
+                let item = self.item()?;
+                if item.kind != Kind::Repetition {
+                    return self.error("`lazy` needs a repetition", position);
+                }
+                chain(item.regex.lazy())
+            }
+            "capture" | "named_capture" if self.builder => {
+                self.open()?;
+                let target = self.item()?.regex;
+                let named = match name {
+                    "named_capture" => {
+                        self.separator()?;
+                        Some(self.quoted('"')?)
+                    }
+                    _ => None,
+                };
+                self.close()?;
+                match named {
+                    Some(group) => chain(named_capture(target, &group)),
+                    None => chain(capture(target)),
+                }
+            }
+            "capture" | "named_capture" => {
+                let checkpoint = self.position;
+                let named = match self.name() {
+                    Ok((group, _)) if self.eat(':') => Some(group),
+                    _ if name == "named_capture" => {
+                        return self.error("expected a group name and `:`", checkpoint)
+                    }
+                    _ => {
+                        self.position = checkpoint;
+                        None
+                    }
+                };
+                let target = self.item()?.regex;
+                match named {
+                    Some(group) => chain(named_capture(target, group)),
+                    None => chain(capture(target)),
+                }
+            }
+            "or" => {
+                self.open()?;
+                self.open_list()?;
+                let mut options = vec![self.item()?.regex];
+                while self.eat(',') {
+                    options.push(self.item()?.regex);
+                }
+                self.expect("]")?;
+                self.close()?;
+                chain(or(&options))
+            }
+            "and" | "xor" | "subtract" => {
+                self.open()?;
+                let mut classes = Vec::new();
+                for idx in 0..2 {
+                    if idx == 1 {
+                        self.separator()?;
+                    }
+                    self.skip_space();
+                    let position = self.position;
+                    let item = self.item()?;
+                    if item.kind != Kind::Class {
+                        return self.error(format!("`{}` needs a character class", name), position);
+                    }
+                    classes.push(HumanRegex(item.regex.0, pd::<SymbolClass<Custom>>));
+                }
+                self.close()?;
+                let rhs = classes.pop().unwrap_or_else(|| unreachable!());
+                let lhs = classes.pop().unwrap_or_else(|| unreachable!());
+                match name {
+                    "and" => class(and(lhs, rhs)),
+                    "xor" => class(xor(lhs, rhs)),
+                    _ => class(subtract(lhs, rhs)),
+                }
+            }
+            name if flag_function(name).is_some() => {
+                let apply = flag_function(name).unwrap_or_else(|| unreachable!());
+                self.open()?;
+                let target = self.item()?.regex;
+                self.close()?;
+                chain(apply(target))
+            }
+            "comment" if !self.builder => {
+                let text = self.quoted('"')?;
+                chain(self.item()?.regex.comment(&text))
+            }
+            name => match self.library.iter().find(|(defined, _)| defined == name) {
+                Some((_, regex)) => chain(nonescaped_text(regex)),
+                None => self.error(format!("unknown function or pattern `{}`", name), start),
+            },
+        }
+    }
+}
+
+impl<T> HumanRegex<T> {
+    /// Write the pattern in the DSL, with the builder functions that build it. Parts of the regex string
+    /// that no function builds, such as syntax imported from other dialects, are written with
+    /// `nonescaped_text`, so that [parse_dsl], or [parse_bytes_dsl] for patterns matching raw bytes, always
+    /// gives back an equivalent pattern.
+    /// ```
+    /// use human_regex::{beginning, digit, end, exactly, named_capture, nonescaped_text, parse_dsl, text};
+    /// let regex_string = beginning()
+    ///     + exactly(4, digit())
+    ///     + text("-")
+    ///     + named_capture(exactly(2, digit()), "month")
+    ///     + end();
+    /// let dsl = regex_string.to_dsl();
+    /// assert_eq!(dsl, r#"beginning, exactly 4 digit, "-", capture month: exactly 2 digit, end"#);
+    /// assert_eq!(parse_dsl(&dsl).unwrap().to_string(), regex_string.to_string());
+    /// assert_eq!(nonescaped_text(r"\d{3,1001}").to_dsl(), r#"nonescaped_text "\\d{3,1001}""#);
+    /// ```
+    pub fn to_dsl(&self) -> String {
+        let parsed = match syntax::parse_with_comments(&self.0) {
+            Ok(parsed) => parsed,
+            Err(_) => return format!("nonescaped_text {}", quote(&self.0, '"')),
+        };
+        let printer = Printer {
+            pattern: &self.0,
+            comments: &parsed.comments,
+        };
+        match printer.pieces(&parsed.ast) {
+            pieces if pieces.is_empty() => quote("", '"'),
+            pieces => join(pieces),
+        }
+    }
+}
+
+/// A part of a sequence written in the DSL
+enum Piece {
+    /// Literal text, which is merged with neighbouring text into a single string
+    Text(String),
+    /// Anything else, already written in the DSL
+    Code(String),
+}
+
+/// Write the pieces of a sequence separated by commas
+fn join(pieces: Vec<Piece>) -> String {
+    pieces
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Text(text) => quote(&text, '"'),
+            Piece::Code(code) => code,
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Write text between quotes, escaping it the way the parser expects
+fn quote(text: &str, quote: char) -> String {
+    let mut quoted = String::from(quote);
+    for chr in text.chars() {
+        match chr {
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            '\0' => quoted.push_str("\\0"),
+            chr if chr == quote => {
+                quoted.push('\\');
+                quoted.push(chr);
+            }
+            chr if chr.is_control() => quoted.push_str(&format!("\\u{{{:X}}}", chr as u32)),
+            chr => quoted.push(chr),
+        }
+    }
+    quoted.push(quote);
+    quoted
+}
+
+/// Writes the syntax tree of a regex string in the DSL
+struct Printer<'p> {
+    /// The regex string
+    pattern: &'p str,
+    /// The comments found in verbose sections of the pattern, including those added by [HumanRegex::comment]
+    comments: &'p [ast::Comment],
+}
+
+impl Printer<'_> {
+    /// Write a node as the pieces of a sequence, flattening sequences and groups that only group
+    fn pieces(&self, node: &Ast) -> Vec<Piece> {
+        match node {
+            Ast::Empty(_) => Vec::new(),
+            Ast::Literal(literal) => vec![Piece::Text(literal.c.to_string())],
+            Ast::Concat(concat) => {
+                let mut pieces = Vec::new();
+                for piece in concat.asts.iter().flat_map(|node| self.pieces(node)) {
+                    match (pieces.last_mut(), piece) {
+                        (Some(Piece::Text(text)), Piece::Text(more)) => text.push_str(&more),
+                        (_, piece) => pieces.push(piece),
+                    }
+                }
+                pieces
+            }
+            Ast::Group(group) if only_groups(group) && self.comment(group).is_none() => {
+                self.pieces(&group.ast)
+            }
+            node => vec![Piece::Code(self.atom(node))],
+        }
+    }
+
+    /// Write a node as a single argument, in parentheses if it is a sequence
+    fn item(&self, node: &Ast) -> String {
+        let mut pieces = self.pieces(node);
+        match pieces.len() {
+            0 => quote("", '"'),
+            1 => join(vec![pieces.remove(0)]),
+            _ => format!("({})", join(pieces)),
+        }
+    }
+
+    /// Write a node that is neither literal text nor a sequence
+    fn atom(&self, node: &Ast) -> String {
+        let written = match node {
+            Ast::Repetition(repetition) => self.repetition(repetition),
+            Ast::Group(group) => byte_function(syntax::source(self.pattern, node.span()))
+                .or_else(|| self.group(group)),
+            Ast::Alternation(alternation) => Some(format!(
+                "or [{}]",
+                alternation
+                    .asts
+                    .iter()
+                    .map(|node| self.item(node))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )),
+            Ast::ClassBracketed(class) => self
+                .class_function(node.span())
+                .or_else(|| self.bracketed(class)),
+            Ast::Dot(_) | Ast::Assertion(_) | Ast::ClassPerl(_) | Ast::ClassUnicode(_) => {
+                self.class_function(node.span())
+            }
+            _ => None,
+        };
+        written.unwrap_or_else(|| self.fallback(node.span()))
+    }
+
+    /// Write the part of the regex string covered by a span as a `nonescaped_text`
+    fn fallback(&self, span: &ast::Span) -> String {
+        format!(
+            "nonescaped_text {}",
+            quote(syntax::source(self.pattern, span), '"')
+        )
+    }
+
+    /// Return the function without arguments, or the Unicode class, that builds exactly the given part of the regex string
+    fn class_function(&self, span: &ast::Span) -> Option<String> {
+        let source = syntax::source(self.pattern, span);
+        if let Some((name, _, _)) = nullary_functions()
+            .into_iter()
+            .find(|(_, regex, _)| regex == source)
+        {
+            return Some(name.to_string());
+        }
+        for category in UnicodeCategory::ALL {
+            if unicode_category(category).0 == source {
+                return Some(format!("unicode_category {:?}", category));
+            }
+            if non_unicode_category(category).0 == source {
+                return Some(format!("non_unicode_category {:?}", category));
+            }
+        }
+        for script in UnicodeScript::ALL {
+            if unicode_script(script).0 == source {
+                return Some(format!("unicode_script {:?}", script));
+            }
+            if non_unicode_script(script).0 == source {
+                return Some(format!("non_unicode_script {:?}", script));
+            }
+            if unicode_script_extensions(script).0 == source {
+                return Some(format!("unicode_script_extensions {:?}", script));
+            }
+            if non_unicode_script_extensions(script).0 == source {
+                return Some(format!("non_unicode_script_extensions {:?}", script));
+            }
+        }
+        for property in UnicodeProperty::ALL {
+            if unicode_property(property).0 == source {
+                return Some(format!("unicode_property {:?}", property));
+            }
+            if non_unicode_property(property).0 == source {
+                return Some(format!("non_unicode_property {:?}", property));
+            }
+        }
+        for block in UnicodeBlock::ALL {
+            if unicode_block(block).0 == source {
+                return Some(format!("unicode_block {:?}", block));
+            }
+            if non_unicode_block(block).0 == source {
+                return Some(format!("non_unicode_block {:?}", block));
+            }
+        }
+        None
+    }
+
+    /// Write a repetition, if its counts fit the repetition functions
+    fn repetition(&self, repetition: &ast::Repetition) -> Option<String> {
+        let target = self.item(&repetition.ast);
+        let count = |n: u32| (n <= MAX_REPETITIONS).then_some(n);
+        let written = match &repetition.op.kind {
+            ast::RepetitionKind::ZeroOrOne => format!("zero_or_one {}", target),
+            ast::RepetitionKind::ZeroOrMore => format!("zero_or_more {}", target),
+            ast::RepetitionKind::OneOrMore => format!("one_or_more {}", target),
+            ast::RepetitionKind::Range(ast::RepetitionRange::Exactly(n)) => {
+                format!("exactly {} {}", count(*n)?, target)
+            }
+            ast::RepetitionKind::Range(ast::RepetitionRange::AtLeast(n)) => {
+                format!("at_least {} {}", count(*n)?, target)
+            }
+            ast::RepetitionKind::Range(ast::RepetitionRange::Bounded(n, m)) => {
+                format!("between {} {} {}", count(*n)?, count(*m)?, target)
+            }
+        };
+        match repetition.greedy {
+            true => Some(written),
+            false => Some(format!("lazy {}", written)),
+        }
+    }
+
+    /// Write a capture group, a group setting flags or a group made by [HumanRegex::comment]
+    fn group(&self, group: &ast::Group) -> Option<String> {
+        let target = self.item(&group.ast);
+        match &group.kind {
+            ast::GroupKind::CaptureIndex(_) => Some(format!("capture {}", target)),
+            ast::GroupKind::CaptureName { name, .. } => {
+                Some(format!("capture {}: {}", name.name, target))
+            }
+            ast::GroupKind::NonCapturing(flags) => {
+                // The functions of the group would write raw bytes as characters
+                let source = syntax::source(self.pattern, &group.span);
+                syntax::FlagState::default().translate(source)?;
+                if let Some((comment, commented)) = self.comment(group) {
+                    return Some(format!(
+                        "comment {} {}",
+                        quote(&comment, '"'),
+                        self.item(commented)
+                    ));
+                }
+                let mut functions = Vec::new();
+                let mut negated = false;
+                for item in &flags.items {
+                    let flag = match item.kind {
+                        ast::FlagsItemKind::Negation => {
+                            negated = true;
+                            continue;
+                        }
+                        ast::FlagsItemKind::Flag(flag) => flag,
+                    };
+                    let negation = if negated { "-" } else { "" };
+                    let single = format!("(?{}{}:)", negation, syntax::flag_letter(flag));
+                    let (name, _) = FLAG_FUNCTIONS.into_iter().find(|(_, apply)| {
+                        apply(HumanRegex(String::new(), pd::<SymbolChain>)).0 == single
+                    })?;
+                    functions.push(name);
+                }
+                functions.push(&target);
+                Some(functions.join(" "))
+            }
+        }
+    }
+
+    /// Return the text of a group made by [HumanRegex::comment] and the part of the pattern it comments
+    fn comment<'a>(&self, group: &'a ast::Group) -> Option<(String, &'a Ast)> {
+        match (&group.kind, &*group.ast) {
+            (ast::GroupKind::NonCapturing(flags), Ast::Concat(concat))
+                if flags.items.is_empty() && concat.asts.len() == 2 =>
+            {
+                match &concat.asts[0] {
+                    Ast::Group(marker) => {
+                        comment_of(marker, self.comments).map(|comment| (comment, &concat.asts[1]))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Write a bracketed class with the range, set and set operation functions
+    fn bracketed(&self, class: &ast::ClassBracketed) -> Option<String> {
+        match &class.kind {
+            ast::ClassSet::Item(item) => self.class_item(item, class.negated),
+            ast::ClassSet::BinaryOp(operation) if !class.negated => self.operation(operation),
+            _ => None,
+        }
+    }
+
+    /// Write a set operation between two classes
+    fn operation(&self, operation: &ast::ClassSetBinaryOp) -> Option<String> {
+        let function = match operation.kind {
+            ast::ClassSetBinaryOpKind::Intersection => "and",
+            ast::ClassSetBinaryOpKind::Difference => "subtract",
+            ast::ClassSetBinaryOpKind::SymmetricDifference => "xor",
+        };
+        let operand = |set: &ast::ClassSet| match set {
+            ast::ClassSet::Item(item) => self.class_item(item, false),
+            ast::ClassSet::BinaryOp(operation) => self.operation(operation),
+        };
+        Some(format!(
+            "{} {} {}",
+            function,
+            operand(&operation.lhs)?,
+            operand(&operation.rhs)?
+        ))
+    }
+
+    /// Write an item of a bracketed class as a class of its own
+    fn class_item(&self, item: &ast::ClassSetItem, negated: bool) -> Option<String> {
+        let prefix = match negated {
+            false => "within",
+            true => "without",
+        };
+        match item {
+            ast::ClassSetItem::Range(range) => Some(format!(
+                "{}_range {}..={}",
+                prefix,
+                quote(&range.start.c.to_string(), '\''),
+                quote(&range.end.c.to_string(), '\'')
+            )),
+            ast::ClassSetItem::Literal(literal) => Some(format!(
+                "{}_set [{}]",
+                prefix,
+                quote(&literal.c.to_string(), '\'')
+            )),
+            ast::ClassSetItem::Union(union) if !union.items.is_empty() => {
+                let chars = union
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        ast::ClassSetItem::Literal(literal) => {
+                            Some(quote(&literal.c.to_string(), '\''))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<String>>>()?;
+                Some(format!("{}_set [{}]", prefix, chars.join(", ")))
+            }
+            _ if negated => None,
+            ast::ClassSetItem::Perl(_) | ast::ClassSetItem::Unicode(_) => {
+                self.class_function(item.span())
+            }
+            ast::ClassSetItem::Ascii(ascii) => {
+                let source = format!("[{}]", syntax::source(self.pattern, &ascii.span));
+                nullary_functions()
+                    .into_iter()
+                    .find(|(_, regex, _)| *regex == source)
+                    .map(|(name, _, _)| name.to_string())
+            }
+            ast::ClassSetItem::Bracketed(class) => self
+                .class_function(&class.span)
+                .or_else(|| self.bracketed(class)),
+            _ => None,
+        }
+    }
+}
+
+/// Return whether a group only groups, without capturing or setting flags
+fn only_groups(group: &ast::Group) -> bool {
+    matches!(&group.kind, ast::GroupKind::NonCapturing(flags) if flags.items.is_empty())
+}
+
+/// Return the byte function that builds exactly the given group
+fn byte_function(source: &str) -> Option<String> {
+    let hex = |digits: &str| match digits.len() {
+        2 => u8::from_str_radix(digits, 16).ok(),
+        _ => None,
+    };
+    let inner = source.strip_prefix(r"(?-u:[\x")?.strip_suffix("])")?;
+    match inner.split_once(r"-\x") {
+        None => Some(format!("byte 0x{:02X}", hex(inner)?)),
+        Some(_) if source == any_byte().0 => Some("any_byte".to_string()),
+        Some((from, to)) => Some(format!(
+            "byte_range 0x{:02X}..=0x{:02X}",
+            hex(from)?,
+            hex(to)?
+        )),
+    }
+}
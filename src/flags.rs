@@ -2,7 +2,10 @@
 // i     case-insensitive: letters match both upper and lower case
 // m     multi-line mode: ^ and $ match begin/end of line
 // s     allow . to match \n
+// R     enables CRLF mode: when multi-line mode is enabled, \r\n is used
+// U     swap the meaning of x* and x*?
 // u     Unicode support (enabled by default)
+// x     verbose mode, ignores whitespace and allow line comments (starting with `#`)
 
 use super::humanregex::*;
 use std::marker::PhantomData as pd;
@@ -15,21 +18,187 @@ use std::marker::PhantomData as pd;
 /// assert!(regex_string.to_regex().is_match("spongebob"));
 /// assert!(!regex_string.to_regex().is_match("PaTrIcK"));
 /// ```
-pub fn case_insensitive<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?i:{})", target), pd::<SymbolChain>)
+pub fn case_insensitive<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?i:{})", target), pd::<T::Chain>)
+}
+
+/// Makes matches case sensitive again, for instance inside of [case_insensitive].
+/// ```
+/// use human_regex::{case_insensitive, case_sensitive, text};
+/// let regex_string = case_insensitive(text("sponge") + case_sensitive(text("Bob")));
+/// assert!(regex_string.to_regex().is_match("SPONGEBob"));
+/// assert!(!regex_string.to_regex().is_match("SPONGEBOB"));
+/// ```
+pub fn case_sensitive<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?-i:{})", target), pd::<T::Chain>)
 }
 
 /// Enables multiline mode, which will allow `beginning()` and `end()` to match the beginning and end of lines
-pub fn multi_line_mode<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?m:{})", target), pd::<SymbolChain>)
+pub fn multi_line_mode<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?m:{})", target), pd::<T::Chain>)
 }
 
 /// A function that will allow `.` to match newlines (`\n`)
-pub fn dot_matches_newline_too<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?s:{})", target), pd::<SymbolChain>)
+pub fn dot_matches_newline_too<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?s:{})", target), pd::<T::Chain>)
 }
 
 /// A function to disable unicode support
-pub fn disable_unicode<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?-u:{})", target), pd::<SymbolChain>)
+pub fn disable_unicode<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?-u:{})", target), pd::<T::Chain>)
+}
+
+/// A function to enable unicode support again, for instance inside of [disable_unicode]
+/// ```
+/// use human_regex::{disable_unicode, enable_unicode, text, word};
+/// let regex_string = disable_unicode(word() + enable_unicode(word()));
+/// assert!(regex_string.to_regex().is_match("aé"));
+/// assert!(!regex_string.to_regex().is_match("éa"));
+/// ```
+pub fn enable_unicode<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?u:{})", target), pd::<T::Chain>)
+}
+
+/// Enables CRLF mode, which, in multi-line mode, lets `beginning()` and `end()` treat `\r\n` as a line terminator
+/// ```
+/// use human_regex::{beginning, crlf_mode, end, multi_line_mode, text};
+/// let regex_string = crlf_mode(multi_line_mode(beginning() + text("b") + end()));
+/// assert!(regex_string.to_regex().is_match("a\r\nb\r\nc"));
+/// assert!(!multi_line_mode(beginning() + text("b") + end()).to_regex().is_match("a\r\nb\r\nc"));
+/// ```
+pub fn crlf_mode<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?R:{})", target), pd::<T::Chain>)
+}
+
+/// Swaps the meaning of greedy and [lazy](HumanRegex::lazy) repetitions
+/// ```
+/// use human_regex::{any, one_or_more, swap_greed, text};
+/// let regex_string = swap_greed(text("<") + one_or_more(any()) + text(">"));
+/// assert_eq!(regex_string.to_regex().find("<a><b>").unwrap().as_str(), "<a>");
+/// ```
+pub fn swap_greed<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?U:{})", target), pd::<T::Chain>)
+}
+
+/// Ignores whitespace and allows `#` line comments in the regex string. Text added with [text](crate::text)
+/// is escaped so that it still matches literally.
+/// ```
+/// use human_regex::{ignore_whitespace, nonescaped_text, text};
+/// let regex_string = ignore_whitespace(nonescaped_text("a b c") + text(" d"));
+/// assert!(regex_string.to_regex().is_match("abc d"));
+/// assert!(!regex_string.to_regex().is_match("a b c d"));
+/// ```
+pub fn ignore_whitespace<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?x:{})", target), pd::<T::Chain>)
+}
+
+/// A set of flags to enable or disable together with [with_flags].
+///
+/// Each flag is `Some(true)` to enable it, `Some(false)` to disable it, or `None` to inherit it from the surrounding expression.
+/// ```
+/// use human_regex::Flags;
+/// let flags = Flags {
+///     case_insensitive: Some(true),
+///     multi_line: Some(false),
+///     ..Default::default()
+/// };
+/// assert_eq!(flags.to_string(), "case-insensitive, not multi-line");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Flags {
+    /// `i`, see [case_insensitive] and [case_sensitive]
+    pub case_insensitive: Option<bool>,
+    /// `m`, see [multi_line_mode]
+    pub multi_line: Option<bool>,
+    /// `s`, see [dot_matches_newline_too]
+    pub dot_matches_new_line: Option<bool>,
+    /// `R`, see [crlf_mode]
+    pub crlf: Option<bool>,
+    /// `U`, see [swap_greed]
+    pub swap_greed: Option<bool>,
+    /// `x`, see [ignore_whitespace]
+    pub ignore_whitespace: Option<bool>,
+    /// `u`, see [disable_unicode] and [enable_unicode]
+    pub unicode: Option<bool>,
+}
+
+impl Flags {
+    /// Each flag with its letter in the regex syntax and its description
+    fn entries(&self) -> [(Option<bool>, char, &'static str); 7] {
+        [
+            (self.case_insensitive, 'i', "case-insensitive"),
+            (self.multi_line, 'm', "multi-line"),
+            (self.dot_matches_new_line, 's', "dot matches newline"),
+            (self.crlf, 'R', "CRLF"),
+            (self.swap_greed, 'U', "swap greed"),
+            (self.ignore_whitespace, 'x', "ignore whitespace"),
+            (self.unicode, 'u', "Unicode"),
+        ]
+    }
+
+    /// Render the flags as they appear in a group, such as `is-m`
+    /// ```
+    /// use human_regex::Flags;
+    /// let flags = Flags {
+    ///     case_insensitive: Some(true),
+    ///     dot_matches_new_line: Some(true),
+    ///     unicode: Some(false),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(flags.to_syntax(), "is-u");
+    /// ```
+    pub fn to_syntax(&self) -> String {
+        let enabled: String = self
+            .entries()
+            .iter()
+            .filter(|(state, _, _)| *state == Some(true))
+            .map(|(_, letter, _)| letter)
+            .collect();
+        let disabled: String = self
+            .entries()
+            .iter()
+            .filter(|(state, _, _)| *state == Some(false))
+            .map(|(_, letter, _)| letter)
+            .collect();
+        if disabled.is_empty() {
+            enabled
+        } else {
+            format!("{}-{}", enabled, disabled)
+        }
+    }
+}
+
+// Describe the flags in plain words
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let descriptions: Vec<String> = self
+            .entries()
+            .iter()
+            .filter_map(|(state, _, description)| match state {
+                Some(true) => Some(description.to_string()),
+                Some(false) => Some(format!("not {}", description)),
+                None => None,
+            })
+            .collect();
+        write!(f, "{}", descriptions.join(", "))
+    }
+}
+
+/// Enables and disables several flags at once for an expression
+/// ```
+/// use human_regex::{beginning, text, with_flags, Flags};
+/// let flags = Flags {
+///     case_insensitive: Some(true),
+///     multi_line: Some(true),
+///     ..Default::default()
+/// };
+/// let regex_string = with_flags(flags, beginning() + text("bob"));
+/// assert_eq!(regex_string.to_string(), "(?im:^(?:bob))");
+/// assert!(regex_string.to_regex().is_match("patrick\nBOB"));
+/// ```
+pub fn with_flags<T: State>(flags: Flags, target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(
+        format!("(?{}:{})", flags.to_syntax(), target),
+        pd::<T::Chain>,
+    )
 }
@@ -0,0 +1,194 @@
+//! Functions for showing matches and capture groups in color
+//!
+//! [HumanRegex::highlight_ansi] marks every match in a haystack with ANSI escape sequences for terminals,
+//! and [HumanRegex::highlight_html] marks them with `<mark>` elements followed by a legend of the capture
+//! groups. Each capture group gets its own color, cycling through a palette of six, and nested groups
+//! are shown in the color of the innermost group. Both compile the pattern on every call, so the
+//! functions [highlight_ansi] and [highlight_html] take a regex compiled once for highlighting many haystacks.
+
+use super::humanregex::*;
+use std::ops::Range;
+
+/// The ANSI color codes of capture groups, in order
+const ANSI_COLORS: [&str; 6] = ["32", "33", "34", "35", "36", "31"];
+
+/// The HTML background colors of capture groups, in order
+const HTML_COLORS: [&str; 6] = [
+    "#b8e6b8", "#ffe08a", "#a8c8ff", "#f0b0f0", "#a8ecec", "#ffb0a8",
+];
+
+/// A step in the output of a highlighted haystack
+enum Mark {
+    /// Text from the haystack
+    Text(Range<usize>),
+    /// The start of a match, for group 0, or of a capture group
+    Open(usize),
+    /// The end of the innermost open match or capture group
+    Close,
+}
+
+impl<T: State<Chain = SymbolChain>> HumanRegex<T> {
+    /// Highlight every match in the haystack with ANSI escape sequences, underlining matches and coloring
+    /// each capture group
+    /// ```
+    /// use human_regex::{capture, digit, one_or_more, text};
+    /// let regex_string = text("v") + capture(one_or_more(digit()));
+    /// assert_eq!(
+    ///     regex_string.highlight_ansi("use v12"),
+    ///     "use \x1b[1;4mv\x1b[0m\x1b[1;4;32m12\x1b[0m"
+    /// );
+    /// ```
+    pub fn highlight_ansi(&self, haystack: &str) -> String {
+        highlight_ansi(&self.to_regex(), haystack)
+    }
+
+    /// Highlight every match in the haystack as HTML, with a `<mark>` element around each match and each
+    /// capture group, followed by a legend naming the groups
+    /// ```
+    /// use human_regex::{digit, exactly, named_capture, text};
+    /// let regex_string = named_capture(exactly(2, digit()), "hour") + text(":");
+    /// assert_eq!(
+    ///     regex_string.highlight_html("at 09:"),
+    ///     concat!(
+    ///         r#"<div class="human-regex-highlight"><pre>at <mark class="match">"#,
+    ///         r#"<mark class="group group-1" style="background-color: #b8e6b8" title="hour">09</mark>:"#,
+    ///         r#"</mark></pre><ul class="legend">"#,
+    ///         r#"<li><mark class="group group-1" style="background-color: #b8e6b8">hour</mark></li>"#,
+    ///         r#"</ul></div>"#
+    ///     )
+    /// );
+    /// ```
+    pub fn highlight_html(&self, haystack: &str) -> String {
+        highlight_html(&self.to_regex(), haystack)
+    }
+}
+
+/// Highlight every match of a compiled regex in the haystack with ANSI escape sequences, like
+/// [HumanRegex::highlight_ansi] without compiling the pattern again
+/// ```
+/// use human_regex::{capture, digit, highlight_ansi, one_or_more, text};
+/// let regex = (text("v") + capture(one_or_more(digit()))).to_regex();
+/// for line in ["use v12", "use v3"] {
+///     assert!(highlight_ansi(&regex, line).starts_with("use \x1b[1;4mv\x1b[0m"));
+/// }
+/// ```
+pub fn highlight_ansi(regex: &regex::Regex, haystack: &str) -> String {
+    let mut highlighted = String::new();
+    let mut groups = Vec::new();
+    for mark in marks(regex, haystack) {
+        match mark {
+            Mark::Text(range) => match groups.last() {
+                None => highlighted.push_str(&haystack[range]),
+                Some(0) => highlighted.push_str(&format!("\x1b[1;4m{}\x1b[0m", &haystack[range])),
+                Some(group) => highlighted.push_str(&format!(
+                    "\x1b[1;4;{}m{}\x1b[0m",
+                    ANSI_COLORS[(group - 1) % ANSI_COLORS.len()],
+                    &haystack[range]
+                )),
+            },
+            Mark::Open(group) => groups.push(group),
+            Mark::Close => {
+                groups.pop();
+            }
+        }
+    }
+    highlighted
+}
+
+/// Highlight every match of a compiled regex in the haystack as HTML, like [HumanRegex::highlight_html]
+/// without compiling the pattern again
+/// ```
+/// use human_regex::{digit, highlight_html, one_or_more};
+/// let regex = one_or_more(digit()).to_regex();
+/// assert!(highlight_html(&regex, "a 1").contains(r#"a <mark class="match">1</mark>"#));
+/// ```
+pub fn highlight_html(regex: &regex::Regex, haystack: &str) -> String {
+    let names: Vec<String> = regex
+        .capture_names()
+        .enumerate()
+        .map(|(idx, name)| match name {
+            Some(name) => name.to_string(),
+            None => format!("group {}", idx),
+        })
+        .collect();
+    let mut highlighted = String::from(r#"<div class="human-regex-highlight"><pre>"#);
+    for mark in marks(regex, haystack) {
+        match mark {
+            Mark::Text(range) => highlighted.push_str(&html_escape(&haystack[range])),
+            Mark::Open(0) => highlighted.push_str(r#"<mark class="match">"#),
+            Mark::Open(group) => highlighted.push_str(&format!(
+                r#"<mark class="group group-{}" style="background-color: {}" title="{}">"#,
+                group,
+                HTML_COLORS[(group - 1) % HTML_COLORS.len()],
+                html_escape(&names[group])
+            )),
+            Mark::Close => highlighted.push_str("</mark>"),
+        }
+    }
+    highlighted.push_str("</pre>");
+    if names.len() > 1 {
+        highlighted.push_str(r#"<ul class="legend">"#);
+        for (group, name) in names.iter().enumerate().skip(1) {
+            highlighted.push_str(&format!(
+                r#"<li><mark class="group group-{}" style="background-color: {}">{}</mark></li>"#,
+                group,
+                HTML_COLORS[(group - 1) % HTML_COLORS.len()],
+                html_escape(name)
+            ));
+        }
+        highlighted.push_str("</ul>");
+    }
+    highlighted.push_str("</div>");
+    highlighted
+}
+
+/// Split a haystack into text and the starts and ends of matches and capture groups, properly nested
+fn marks(regex: &regex::Regex, haystack: &str) -> Vec<Mark> {
+    let mut marks = Vec::new();
+    let mut cursor = 0;
+    for captures in regex.captures_iter(haystack) {
+        let mut groups: Vec<(usize, Range<usize>)> = captures
+            .iter()
+            .enumerate()
+            .filter_map(|(group, found)| found.map(|found| (group, found.range())))
+            .collect();
+        // Outer groups first, so that every group comes after the groups containing it
+        groups.sort_by_key(|(group, range)| (range.start, std::cmp::Reverse(range.end), *group));
+        let mut open: Vec<(usize, Range<usize>)> = Vec::new();
+        for (group, range) in groups {
+            while let Some((_, outer_range)) = open.last().cloned() {
+                if outer_range.start <= range.start && range.end <= outer_range.end {
+                    break;
+                }
+                text(&mut marks, &mut cursor, outer_range.end);
+                marks.push(Mark::Close);
+                open.pop();
+            }
+            text(&mut marks, &mut cursor, range.start);
+            marks.push(Mark::Open(group));
+            open.push((group, range));
+        }
+        while let Some((_, range)) = open.pop() {
+            text(&mut marks, &mut cursor, range.end);
+            marks.push(Mark::Close);
+        }
+    }
+    text(&mut marks, &mut cursor, haystack.len());
+    marks
+}
+
+/// Add the text from the cursor up to a position, if there is any, and move the cursor there
+fn text(marks: &mut Vec<Mark>, cursor: &mut usize, position: usize) {
+    if *cursor < position {
+        marks.push(Mark::Text(*cursor..position));
+        *cursor = position;
+    }
+}
+
+/// Escape text for use in HTML
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
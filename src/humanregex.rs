@@ -22,15 +22,120 @@ pub struct LiteralSymbolChain;
 /// Represents the state when [HumanRegex] is a wrapper for any arbitrary regular expression
 pub struct SymbolChain;
 
+/// Represents the state when [HumanRegex] is a wrapper for a single raw byte class, which can only be matched against `&[u8]`
+pub struct ByteClass;
+
+/// Represents the state when [HumanRegex] is a wrapper for an arbitrary regular expression containing raw bytes, which can only be matched against `&[u8]`
+pub struct ByteChain;
+
+/// Implemented by every state a [HumanRegex] can be in.
+///
+/// The associated `Chain` records what an expression collapses into once it is repeated, captured or concatenated:
+/// [SymbolChain] for anything that can be matched against `&str`, and [ByteChain] once raw bytes are involved.
+pub trait State {
+    /// The state of any arbitrary expression built from this one
+    type Chain;
+    /// Whether expressions in this state match raw bytes, so they may match invalid UTF-8
+    const BYTES: bool = false;
+}
+
+impl<T> State for SymbolClass<T> {
+    type Chain = SymbolChain;
+}
+
+impl State for LiteralSymbolChain {
+    type Chain = SymbolChain;
+}
+
+impl State for SymbolChain {
+    type Chain = SymbolChain;
+}
+
+impl State for ByteClass {
+    type Chain = ByteChain;
+    const BYTES: bool = true;
+}
+
+impl State for ByteChain {
+    type Chain = ByteChain;
+    const BYTES: bool = true;
+}
+
+/// Combines the chain states of two concatenated expressions, so that a single raw byte makes the whole expression byte-oriented
+pub trait Join<U> {
+    /// The state of the concatenated expression
+    type Output;
+}
+
+impl Join<SymbolChain> for SymbolChain {
+    type Output = SymbolChain;
+}
+
+impl Join<ByteChain> for SymbolChain {
+    type Output = ByteChain;
+}
+
+impl Join<SymbolChain> for ByteChain {
+    type Output = ByteChain;
+}
+
+impl Join<ByteChain> for ByteChain {
+    type Output = ByteChain;
+}
+
+/// Implemented by everything that [or](crate::or) can choose between, recording the state of the alternation.
+///
+/// Plain strings are taken to be regex strings that match `&str` haystacks, while a [HumanRegex] keeps its state,
+/// so an alternation of raw bytes can only be compiled with [HumanRegex::to_bytes_regex].
+pub trait Alternative: fmt::Display {
+    /// The state of an alternation between options of this type
+    type Chain;
+}
+
+impl<T: State> Alternative for HumanRegex<T> {
+    type Chain = T::Chain;
+}
+
+impl Alternative for String {
+    type Chain = SymbolChain;
+}
+
+impl Alternative for &String {
+    type Chain = SymbolChain;
+}
+
+impl Alternative for &str {
+    type Chain = SymbolChain;
+}
+
+impl Alternative for char {
+    type Chain = SymbolChain;
+}
+
 /// The HumanRegex struct which maintains and updates the regex string.
 /// For most use cases it will never be necessary to instantiate this directly.
 #[derive(Debug)]
 pub struct HumanRegex<T = SymbolChain>(pub String, pub std::marker::PhantomData<T>);
 
-impl<T> HumanRegex<T> {
+impl<T: State<Chain = SymbolChain>> HumanRegex<T> {
     /// Convert to a rust Regex
     pub fn to_regex(&self) -> Regex {
-        Regex::new(&*self.0).unwrap()
+        Regex::new(&self.0).unwrap()
+    }
+}
+
+impl<T> HumanRegex<T> {
+    /// Convert to a rust Regex that searches `&[u8]` haystacks, which need not be valid UTF-8
+    /// ```
+    /// use human_regex::{byte, byte_range, one_or_more, text};
+    /// // "café" encoded as latin-1
+    /// let latin1 = b"caf\xE9";
+    /// let regex_string = text("caf") + one_or_more(byte_range(0x80..=0xFF));
+    /// assert!(regex_string.to_bytes_regex().is_match(latin1));
+    /// assert!(!regex_string.to_bytes_regex().is_match(b"cafe"));
+    /// ```
+    pub fn to_bytes_regex(&self) -> regex::bytes::Regex {
+        regex::bytes::Regex::new(&self.0).unwrap()
     }
 
     /// Add a lazy modifier
@@ -46,14 +151,16 @@ impl<T> HumanRegex<T> {
 /// assert!(regex_string.to_regex().is_match("mccomb"));
 /// assert!(regex_string.to_regex().is_match("chrismccomb"));
 /// ```
-impl<T, U> Add<HumanRegex<U>> for HumanRegex<T> {
-    type Output = HumanRegex<SymbolChain>;
+impl<T, U> Add<HumanRegex<U>> for HumanRegex<T>
+where
+    T: State,
+    U: State,
+    T::Chain: Join<U::Chain>,
+{
+    type Output = HumanRegex<<T::Chain as Join<U::Chain>>::Output>;
 
     fn add(self, rhs: HumanRegex<U>) -> Self::Output {
-        HumanRegex(
-            format!("{}{}", self.to_string(), rhs.to_string()),
-            pd::<SymbolChain>,
-        )
+        HumanRegex(format!("{}{}", self, rhs), pd)
     }
 }
 
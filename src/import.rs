@@ -0,0 +1,893 @@
+//! Functions for importing patterns written for other regex engines
+//!
+//! These are the reverse of [HumanRegex::to_dialect]: they read a pattern copied from JavaScript,
+//! Python or a POSIX tool and return a [HumanRegex] that matches the same text. Escapes and classes
+//! are rewritten where the engines disagree (JavaScript's `\d`, `\w` and `\b` are ASCII-only, for
+//! example), and whole-pattern flags become the wrappers from the [flags](crate::flags) module, so
+//! `/cat/i` imports as `case_insensitive(..)`.
+//!
+//! Backreferences and lookaround have no equivalent in this crate, so they are reported as a
+//! [DialectError::CannotImport] naming the feature and its byte offset in the pattern.
+//! ```
+//! use human_regex::from_python;
+//! let error = from_python(r"(?P<word>\w+) (?P=word)").err().unwrap();
+//! assert_eq!(
+//!     error.to_string(),
+//!     "this crate cannot express the Python backreference `(?P=word)` at byte 14"
+//! );
+//! ```
+
+use super::dialect::{Dialect, DialectError};
+use super::flags::{case_insensitive, dot_matches_newline_too, multi_line_mode};
+use super::humanregex::*;
+use std::marker::PhantomData as pd;
+
+/// Import a JavaScript regex literal like `/^\d+$/iu`, or a bare pattern as passed to `new RegExp`.
+/// The `i`, `m` and `s` flags become [case_insensitive], [multi_line_mode] and [dot_matches_newline_too],
+/// `u` and `v` enable Unicode escapes and class set operations, and `g` and `d` are ignored since they only
+/// change how matches are reported.
+/// ```
+/// let regex_string = human_regex::from_javascript(r"/^(?<year>\d{4})-(?<month>\d{2})$/u").unwrap();
+/// let caps = regex_string.to_regex().captures("2024-02").unwrap();
+/// assert_eq!(&caps["month"], "02");
+/// // `\d` only matches ASCII digits in JavaScript
+/// assert!(!regex_string.to_regex().is_match("٢٠٢٤-٠٢"));
+/// ```
+/// ```
+/// let regex_string = human_regex::from_javascript("/hello world/i").unwrap();
+/// assert!(regex_string.to_regex().is_match("Hello World"));
+/// assert!(human_regex::from_javascript(r"/\d+(?= dollars)/").is_err());
+/// ```
+pub fn from_javascript(literal: &str) -> Result<HumanRegex<SymbolChain>, DialectError> {
+    let (pattern, flags) = match literal.strip_prefix('/') {
+        Some(rest) => match rest.rfind('/') {
+            Some(end) => (&rest[..end], &rest[end + 1..]),
+            None => {
+                return Err(DialectError::Invalid(
+                    "unterminated regex literal".to_string(),
+                ))
+            }
+        },
+        None => (literal, ""),
+    };
+    let offset = literal.len() - literal.trim_start_matches('/').len();
+    let flags_offset = literal.len() - flags.len();
+    let mut importer = Importer::new(Dialect::JavaScript, pattern, offset);
+    for (idx, flag) in flags.char_indices() {
+        match flag {
+            'i' => importer.global.case_insensitive = true,
+            'm' => importer.global.multi_line = true,
+            's' => importer.global.dot_matches_new_line = true,
+            'u' => importer.unicode = true,
+            'v' => {
+                importer.unicode = true;
+                importer.sets = true;
+            }
+            'g' | 'd' => {}
+            'y' => {
+                return Err(DialectError::CannotImport {
+                    dialect: Dialect::JavaScript,
+                    feature: "sticky flag `y`".to_string(),
+                    offset: flags_offset + idx,
+                })
+            }
+            flag => return Err(DialectError::Invalid(format!("unknown flag `{}`", flag))),
+        }
+    }
+    importer.run()
+}
+
+/// Import a pattern written for Python's `re` module, as it would be matched against a `str`.
+/// Inline flags at the start like `(?i)` become wrappers from the [flags](crate::flags) module, and
+/// verbose patterns (`(?x)`) have their whitespace and comments removed.
+/// ```
+/// let regex_string = human_regex::from_python(r"(?i)(?P<key>\w+)\s*=\s*(?P<value>[^#\n]*)").unwrap();
+/// let caps = regex_string.to_regex().captures("Name = Ada").unwrap();
+/// assert_eq!(&caps["key"], "Name");
+/// assert_eq!(&caps["value"], "Ada");
+/// ```
+/// Outside of `MULTILINE` mode, Python's `$` also matches before a newline at the very end of the text.
+/// That has no equivalent here, so it is reported, while `\Z` and `$` in `MULTILINE` mode are imported:
+/// ```
+/// assert!(human_regex::from_python(r"\d+$").is_err());
+/// let regex_string = human_regex::from_python(r"\d+\Z").unwrap();
+/// assert!(regex_string.to_regex().is_match("42"));
+/// assert!(!regex_string.to_regex().is_match("42\n"));
+/// ```
+pub fn from_python(pattern: &str) -> Result<HumanRegex<SymbolChain>, DialectError> {
+    Importer::new(Dialect::Python, pattern, 0).run()
+}
+
+/// Import a POSIX extended regular expression, as used by `grep -E`. The pattern is matched against
+/// the whole text rather than line by line, so `.` and bracket expressions also match newlines.
+/// ```
+/// let regex_string = human_regex::from_posix_extended("^[[:alpha:]]+(-[[:digit:]]{2,3})?$").unwrap();
+/// assert!(regex_string.to_regex().is_match("abc-123"));
+/// assert!(!regex_string.to_regex().is_match("abc-1"));
+/// ```
+pub fn from_posix_extended(pattern: &str) -> Result<HumanRegex<SymbolChain>, DialectError> {
+    Importer::new(Dialect::Posix, pattern, 0).run()
+}
+
+/// Import a POSIX basic regular expression, as used by `grep` and `sed`. Groups and counted repetitions
+/// are written `\(..\)` and `\{..\}`, while `+`, `?`, `|`, `(` and `{` match themselves. The GNU
+/// extensions `\+`, `\?` and `\|` are also understood.
+/// ```
+/// let regex_string = human_regex::from_posix_basic(r"^\(ab\)\{2\}+$").unwrap();
+/// assert!(regex_string.to_regex().is_match("abab+"));
+/// assert!(!regex_string.to_regex().is_match("ab+"));
+/// ```
+pub fn from_posix_basic(pattern: &str) -> Result<HumanRegex<SymbolChain>, DialectError> {
+    let mut importer = Importer::new(Dialect::Posix, pattern, 0);
+    importer.basic = true;
+    importer.run()
+}
+
+/// Flags that apply to a whole imported pattern
+#[derive(Clone, Copy, Debug, Default)]
+struct Global {
+    /// Becomes [case_insensitive]
+    case_insensitive: bool,
+    /// Becomes [multi_line_mode]
+    multi_line: bool,
+    /// Becomes [dot_matches_newline_too]
+    dot_matches_new_line: bool,
+}
+
+/// Python flags that change how the pattern itself is read, scoped to a group
+#[derive(Clone, Copy, Debug, Default)]
+struct Scope {
+    /// `x`: whitespace and comments are ignored outside of classes
+    verbose: bool,
+    /// `a`: `\d`, `\w`, `\s` and `\b` only consider ASCII
+    ascii: bool,
+    /// `m`: `$` matches before every newline, rather than only before a final one and at the end
+    multi_line: bool,
+}
+
+/// The inside of a class matching JavaScript's `\s`
+const JAVASCRIPT_SPACE: &str =
+    r"\t\n\x0B\f\r \xA0\x{1680}\x{2000}-\x{200A}\x{2028}\x{2029}\x{202F}\x{205F}\x{3000}\x{FEFF}";
+
+/// Reads a pattern written for another engine and writes the equivalent regex string
+struct Importer {
+    /// The dialect being imported from
+    dialect: Dialect,
+    /// Whether a POSIX pattern uses basic rather than extended syntax
+    basic: bool,
+    /// The pattern, with the byte offset of each character
+    chars: Vec<(usize, char)>,
+    /// The byte offset of the pattern in the text given by the caller
+    offset: usize,
+    /// The index of the next character to read
+    pos: usize,
+    /// The regex string written so far
+    out: String,
+    /// Flags for the whole pattern
+    global: Global,
+    /// Python flags in effect, with one entry per open group
+    scopes: Vec<Scope>,
+    /// JavaScript's `u` or `v` flag
+    unicode: bool,
+    /// JavaScript's `v` flag
+    sets: bool,
+    /// The length of the regex string just after the last repetition operator was written
+    repeated: Option<usize>,
+}
+
+impl Importer {
+    /// Prepare to import a pattern
+    fn new(dialect: Dialect, pattern: &str, offset: usize) -> Self {
+        Importer {
+            dialect,
+            basic: false,
+            chars: pattern.char_indices().collect(),
+            offset,
+            pos: 0,
+            out: String::new(),
+            global: Global::default(),
+            scopes: vec![Scope::default()],
+            unicode: false,
+            sets: false,
+            repeated: None,
+        }
+    }
+
+    /// Import the whole pattern
+    fn run(mut self) -> Result<HumanRegex<SymbolChain>, DialectError> {
+        if self.dialect == Dialect::Python {
+            self.python_global_flags()?;
+        }
+        let mut at_start = true;
+        while self.pos < self.chars.len() {
+            at_start = self.token(at_start)?;
+        }
+        if self.scopes.len() > 1 {
+            return Err(self.invalid("missing `)`", self.chars.len()));
+        }
+        regex_syntax::ast::parse::Parser::new()
+            .parse(&self.out)
+            .map_err(|error| DialectError::Invalid(error.kind().to_string()))?;
+        let mut regex = HumanRegex(self.out, pd::<SymbolChain>);
+        if self.global.dot_matches_new_line {
+            regex = dot_matches_newline_too(regex);
+        }
+        if self.global.multi_line {
+            regex = multi_line_mode(regex);
+        }
+        if self.global.case_insensitive {
+            regex = case_insensitive(regex);
+        }
+        Ok(regex)
+    }
+
+    /// Return the character at an index, if there is one
+    fn peek_at(&self, idx: usize) -> Option<char> {
+        self.chars.get(idx).map(|&(_, chr)| chr)
+    }
+
+    /// Return the next character without consuming it
+    fn peek(&self) -> Option<char> {
+        self.peek_at(self.pos)
+    }
+
+    /// Return whether the unread part of the pattern starts with some text
+    fn looking_at(&self, text: &str) -> bool {
+        text.chars()
+            .enumerate()
+            .all(|(idx, chr)| self.peek_at(self.pos + idx) == Some(chr))
+    }
+
+    /// Return the byte offset of a character index, as seen by the caller
+    fn offset_of(&self, idx: usize) -> usize {
+        self.offset
+            + self
+                .chars
+                .get(idx)
+                .map(|&(offset, _)| offset)
+                .unwrap_or_else(|| {
+                    self.chars
+                        .last()
+                        .map(|&(o, c)| o + c.len_utf8())
+                        .unwrap_or(0)
+                })
+    }
+
+    /// Return the text between two character indices
+    fn snippet(&self, start: usize, end: usize) -> String {
+        self.chars[start..end.min(self.chars.len())]
+            .iter()
+            .map(|&(_, chr)| chr)
+            .collect()
+    }
+
+    /// Build an error for a feature this crate cannot express, spanning some characters
+    fn unsupported(&self, feature: &str, start: usize, end: usize) -> DialectError {
+        DialectError::CannotImport {
+            dialect: self.dialect,
+            feature: format!("{} `{}`", feature, self.snippet(start, end)),
+            offset: self.offset_of(start),
+        }
+    }
+
+    /// Build an error for a pattern that is not valid in its dialect
+    fn invalid(&self, message: &str, idx: usize) -> DialectError {
+        DialectError::Invalid(format!("{} at byte {}", message, self.offset_of(idx)))
+    }
+
+    /// Return the Python flags in effect
+    fn scope(&self) -> Scope {
+        *self.scopes.last().unwrap_or(&Scope::default())
+    }
+
+    /// Read Python's global inline flags like `(?ix)`, which must come first
+    fn python_global_flags(&mut self) -> Result<(), DialectError> {
+        while self.looking_at("(?") {
+            let start = self.pos;
+            let mut idx = self.pos + 2;
+            let mut flags = Vec::new();
+            while let Some(flag) = self.peek_at(idx).filter(|chr| "aiLmsux".contains(*chr)) {
+                flags.push(flag);
+                idx += 1;
+            }
+            if flags.is_empty() || self.peek_at(idx) != Some(')') {
+                return Ok(());
+            }
+            for flag in flags {
+                match flag {
+                    'i' => self.global.case_insensitive = true,
+                    'm' => {
+                        self.global.multi_line = true;
+                        self.scopes[0].multi_line = true;
+                    }
+                    's' => self.global.dot_matches_new_line = true,
+                    'x' => self.scopes[0].verbose = true,
+                    'a' => self.scopes[0].ascii = true,
+                    'u' => self.scopes[0].ascii = false,
+                    _ => return Err(self.unsupported("the locale flag", start, idx + 1)),
+                }
+            }
+            self.pos = idx + 1;
+        }
+        Ok(())
+    }
+
+    /// Read one piece of the pattern outside of a class, returning whether the next piece counts as the
+    /// start of an expression for POSIX basic syntax
+    fn token(&mut self, at_start: bool) -> Result<bool, DialectError> {
+        let start = self.pos;
+        let chr = self.chars[self.pos].1;
+        self.pos += 1;
+        if self.dialect == Dialect::Python && self.scope().verbose {
+            if chr.is_whitespace() {
+                return Ok(at_start);
+            }
+            if chr == '#' {
+                while self.peek().is_some_and(|chr| chr != '\n') {
+                    self.pos += 1;
+                }
+                return Ok(at_start);
+            }
+        }
+        match chr {
+            '\\' => return self.escape(start),
+            '[' => self.class(start)?,
+            '.' => match self.dialect {
+                Dialect::JavaScript if !self.global.dot_matches_new_line => {
+                    self.out.push_str(r"[^\n\r\x{2028}\x{2029}]")
+                }
+                Dialect::Posix => {
+                    self.global.dot_matches_new_line = true;
+                    self.out.push('.')
+                }
+                _ => self.out.push('.'),
+            },
+            '^' if self.basic && !at_start => self.out.push_str(r"\^"),
+            '^' => {
+                self.out.push('^');
+                return Ok(true);
+            }
+            '$' if self.basic && !(self.peek().is_none() || self.looking_at(r"\)")) => {
+                self.out.push_str(r"\$")
+            }
+            '$' if self.dialect == Dialect::Python && !self.scope().multi_line => {
+                return Err(DialectError::CannotImport {
+                    dialect: self.dialect,
+                    feature: "end of text `$`, which also matches before a final newline"
+                        .to_string(),
+                    offset: self.offset_of(start),
+                })
+            }
+            '$' => self.out.push('$'),
+            '*' if self.basic && at_start => self.out.push_str(r"\*"),
+            '*' | '+' | '?' if !self.basic || chr == '*' => {
+                self.quantifier(chr.to_string(), start)?
+            }
+            '{' if !self.basic => match self.brace(false) {
+                Some(bounds) => self.quantifier(bounds, start)?,
+                None if self.dialect == Dialect::JavaScript && self.unicode => {
+                    return Err(self.invalid("lone `{`", start))
+                }
+                None => self.out.push_str(r"\{"),
+            },
+            '(' if !self.basic => {
+                self.group(start)?;
+                return Ok(true);
+            }
+            ')' if !self.basic => self.close(start)?,
+            '|' if !self.basic => {
+                self.out.push('|');
+                return Ok(true);
+            }
+            chr => self.out.push_str(&literal(chr)),
+        }
+        Ok(false)
+    }
+
+    /// Write a repetition operator, along with the laziness marker that may follow it
+    fn quantifier(&mut self, operator: String, start: usize) -> Result<(), DialectError> {
+        if self.dialect == Dialect::Posix && self.repeated == Some(self.out.len()) {
+            return Err(self.invalid("repetition of a repetition", start));
+        }
+        self.out.push_str(&operator);
+        if self.dialect != Dialect::Posix && self.peek() == Some('?') {
+            self.pos += 1;
+            self.out.push('?');
+        } else if self.dialect == Dialect::Python && self.peek() == Some('+') {
+            return Err(self.unsupported("possessive repetition", start, self.pos + 1));
+        }
+        self.repeated = Some(self.out.len());
+        Ok(())
+    }
+
+    /// Read a counted repetition like `{2,5}` starting at the current `{`, returning it in this crate's syntax
+    fn brace(&mut self, escaped: bool) -> Option<String> {
+        let mut idx = self.pos;
+        let read_number = |idx: &mut usize| {
+            let mut number = String::new();
+            while let Some(digit) = self.peek_at(*idx).filter(char::is_ascii_digit) {
+                number.push(digit);
+                *idx += 1;
+            }
+            number
+        };
+        let min = read_number(&mut idx);
+        let max = if self.peek_at(idx) == Some(',') {
+            idx += 1;
+            Some(read_number(&mut idx))
+        } else {
+            None
+        };
+        if escaped {
+            if self.peek_at(idx) != Some('\\') {
+                return None;
+            }
+            idx += 1;
+        }
+        if self.peek_at(idx) != Some('}') {
+            return None;
+        }
+        let min = match (min.is_empty(), self.dialect) {
+            (false, _) => min,
+            (true, Dialect::Python) if max.as_ref().is_some_and(|max| !max.is_empty()) => {
+                "0".to_string()
+            }
+            (true, _) => return None,
+        };
+        self.pos = idx + 1;
+        Some(match max {
+            None => format!("{{{}}}", min),
+            Some(max) => format!("{{{},{}}}", min, max),
+        })
+    }
+
+    /// Read a group after its `(`
+    fn group(&mut self, start: usize) -> Result<(), DialectError> {
+        let mut scope = self.scope();
+        if self.peek() != Some('?') {
+            self.out.push('(');
+            self.scopes.push(scope);
+            return Ok(());
+        }
+        for (prefix, construct) in [
+            ("?=", "lookahead"),
+            ("?!", "negative lookahead"),
+            ("?<=", "lookbehind"),
+            ("?<!", "negative lookbehind"),
+        ] {
+            if self.looking_at(prefix) {
+                return Err(self.unsupported(construct, start, self.pos + prefix.len()));
+            }
+        }
+        if self.looking_at("?:") {
+            self.pos += 2;
+            self.out.push_str("(?:");
+        } else if self.looking_at("?<") && self.dialect == Dialect::JavaScript
+            || self.looking_at("?P<") && self.dialect == Dialect::Python
+        {
+            self.pos += if self.dialect == Dialect::Python {
+                3
+            } else {
+                2
+            };
+            let name = self.name('>', start)?;
+            self.out.push_str(&format!("(?P<{}>", name));
+        } else if self.looking_at("?P=") && self.dialect == Dialect::Python {
+            self.pos += 3;
+            self.name(')', start)?;
+            return Err(self.unsupported("backreference", start, self.pos));
+        } else if self.looking_at("?#") && self.dialect == Dialect::Python {
+            while self.peek().is_some_and(|chr| chr != ')') {
+                self.pos += 1;
+            }
+            if self.peek().is_none() {
+                return Err(self.invalid("missing `)`, unterminated comment", start));
+            }
+            self.pos += 1;
+            return Ok(());
+        } else if self.looking_at("?>") && self.dialect == Dialect::Python {
+            return Err(self.unsupported("atomic group", start, self.pos + 2));
+        } else if self.looking_at("?(") && self.dialect == Dialect::Python {
+            return Err(self.unsupported("conditional group", start, self.pos + 2));
+        } else {
+            self.pos += 1;
+            let flags = self.scoped_flags(start, &mut scope)?;
+            self.out.push_str(&format!("(?{}:", flags));
+        }
+        self.scopes.push(scope);
+        Ok(())
+    }
+
+    /// Read a group name up to its terminator
+    fn name(&mut self, terminator: char, start: usize) -> Result<String, DialectError> {
+        let mut name = String::new();
+        while let Some(chr) = self.peek() {
+            self.pos += 1;
+            if chr == terminator {
+                return Ok(name);
+            }
+            name.push(chr);
+        }
+        Err(self.invalid("unterminated group name", start))
+    }
+
+    /// Read flags scoped to a group like `(?i-m:`, returning them in this crate's syntax
+    fn scoped_flags(&mut self, start: usize, scope: &mut Scope) -> Result<String, DialectError> {
+        let mut flags = String::new();
+        let mut enable = true;
+        loop {
+            let Some(chr) = self.peek() else {
+                return Err(self.invalid("missing `)`", start));
+            };
+            self.pos += 1;
+            match (chr, self.dialect) {
+                (':', _) if flags != "-" => return Ok(flags),
+                ('-', _) if enable => {
+                    enable = false;
+                    flags.push('-');
+                }
+                ('m', Dialect::Python) => {
+                    scope.multi_line = enable;
+                    flags.push(chr);
+                }
+                ('i' | 'm' | 's', _) => flags.push(chr),
+                ('x', Dialect::Python) => scope.verbose = enable,
+                ('a', Dialect::Python) if enable => scope.ascii = true,
+                ('u', Dialect::Python) if enable => scope.ascii = false,
+                ('L', Dialect::Python) => {
+                    return Err(self.unsupported("the locale flag", start, self.pos))
+                }
+                (')', Dialect::Python) => {
+                    return Err(
+                        self.invalid("global flags not at the start of the expression", start)
+                    )
+                }
+                _ => return Err(self.invalid("unknown group type", start)),
+            }
+        }
+    }
+
+    /// Close a group
+    fn close(&mut self, start: usize) -> Result<(), DialectError> {
+        if self.scopes.len() == 1 {
+            return Err(self.invalid("unbalanced `)`", start));
+        }
+        self.scopes.pop();
+        self.out.push(')');
+        Ok(())
+    }
+
+    /// Read an escape outside of a class, returning whether the next piece counts as the start of an
+    /// expression for POSIX basic syntax
+    fn escape(&mut self, start: usize) -> Result<bool, DialectError> {
+        let Some(chr) = self.peek() else {
+            return Err(self.invalid("trailing backslash", start));
+        };
+        self.pos += 1;
+        if self.dialect == Dialect::Posix {
+            match chr {
+                '(' if self.basic => {
+                    self.out.push('(');
+                    self.scopes.push(Scope::default());
+                    return Ok(true);
+                }
+                ')' if self.basic => self.close(start)?,
+                '{' if self.basic => match self.brace(true) {
+                    Some(bounds) => self.quantifier(bounds, start)?,
+                    None => return Err(self.invalid(r"invalid `\{`", start)),
+                },
+                '|' if self.basic => {
+                    self.out.push('|');
+                    return Ok(true);
+                }
+                '+' | '?' if self.basic => self.quantifier(chr.to_string(), start)?,
+                '1'..='9' => return Err(self.unsupported("backreference", start, self.pos)),
+                chr => self.out.push_str(&literal(chr)),
+            }
+            return Ok(false);
+        }
+        let ascii = self.dialect == Dialect::JavaScript || self.scope().ascii;
+        match chr {
+            'b' | 'B' if ascii => self.out.push_str(&format!(r"(?-u:\{})", chr)),
+            'b' | 'B' => self.out.push_str(&format!(r"\{}", chr)),
+            'A' if self.dialect == Dialect::Python => self.out.push_str(r"\A"),
+            'Z' if self.dialect == Dialect::Python => self.out.push_str(r"\z"),
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' => {
+                let class = self.perl_class(chr);
+                self.out.push_str(&class);
+            }
+            '1'..='9' if !self.python_octal() => {
+                while self.peek().is_some_and(|chr| chr.is_ascii_digit()) {
+                    self.pos += 1;
+                }
+                return Err(self.unsupported("backreference", start, self.pos));
+            }
+            'p' | 'P' if self.dialect == Dialect::JavaScript && self.unicode => {
+                let property = self.property(chr, start)?;
+                self.out.push_str(&property);
+            }
+            'k' if self.dialect == Dialect::JavaScript && self.peek() == Some('<') => {
+                self.pos += 1;
+                self.name('>', start)?;
+                return Err(self.unsupported("backreference", start, self.pos));
+            }
+            _ => {
+                let chr = self.escaped_char(chr, start)?;
+                self.out.push_str(&literal(chr));
+            }
+        }
+        Ok(false)
+    }
+
+    /// Read a JavaScript Unicode property like `\p{Script=Greek}` after its `\p`
+    fn property(&mut self, chr: char, start: usize) -> Result<String, DialectError> {
+        if self.peek() != Some('{') {
+            return Err(self.invalid("invalid property name", start));
+        }
+        self.pos += 1;
+        let name = self.name('}', start)?;
+        Ok(format!(r"\{}{{{}}}", chr, name))
+    }
+
+    /// Read a class after its `[`
+    fn class(&mut self, start: usize) -> Result<(), DialectError> {
+        let class = self.class_body(start)?;
+        self.out.push_str(&class);
+        Ok(())
+    }
+
+    /// Read a class after its `[`, returning it in this crate's syntax
+    fn class_body(&mut self, start: usize) -> Result<String, DialectError> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.pos += 1;
+        }
+        if self.dialect == Dialect::JavaScript && self.peek() == Some(']') {
+            self.pos += 1;
+            return Ok(format!(
+                r"[{}\x{{0}}-\x{{10FFFF}}]",
+                if negated { "" } else { "^" }
+            ));
+        }
+        let mut inner = String::new();
+        let mut first = true;
+        loop {
+            let item_start = self.pos;
+            let Some(chr) = self.peek() else {
+                return Err(self.invalid("unterminated class", start));
+            };
+            self.pos += 1;
+            let single = match chr {
+                ']' if !first || self.dialect == Dialect::JavaScript => break,
+                '[' if self.dialect == Dialect::Posix && self.peek() == Some(':') => {
+                    let name = self.name(']', item_start)?;
+                    inner.push_str(&format!("[{}]", name));
+                    None
+                }
+                '[' if self.dialect == Dialect::Posix && self.peek() == Some('.') => {
+                    return Err(self.unsupported("collating element", item_start, self.pos + 1))
+                }
+                '[' if self.dialect == Dialect::Posix && self.peek() == Some('=') => {
+                    return Err(self.unsupported("equivalence class", item_start, self.pos + 1))
+                }
+                '[' if self.sets => {
+                    let nested = self.class_body(item_start)?;
+                    inner.push_str(&nested);
+                    None
+                }
+                '&' | '-' if self.sets && self.peek() == Some(chr) => {
+                    self.pos += 1;
+                    inner.push_str(&format!("{}{}", chr, chr));
+                    None
+                }
+                '\\' if self.dialect != Dialect::Posix => {
+                    self.class_escape(item_start, &mut inner)?
+                }
+                chr => Some(chr),
+            };
+            first = false;
+            let Some(single) = single else {
+                continue;
+            };
+            let is_range = self.peek() == Some('-')
+                && self.peek_at(self.pos + 1).is_some_and(|chr| chr != ']')
+                && !(self.sets && self.peek_at(self.pos + 1) == Some('-'));
+            if !is_range {
+                inner.push_str(&class_literal(single));
+                continue;
+            }
+            self.pos += 1;
+            let end_start = self.pos;
+            let end = match self.peek() {
+                Some('\\') if self.dialect != Dialect::Posix => {
+                    self.pos += 1;
+                    let mut ignored = String::new();
+                    self.class_escape(end_start, &mut ignored)?
+                }
+                Some('[') if self.dialect == Dialect::Posix => None,
+                chr => {
+                    self.pos += 1;
+                    chr
+                }
+            };
+            match end {
+                Some(end) if single <= end => {
+                    inner.push_str(&format!("{}-{}", class_literal(single), class_literal(end)))
+                }
+                _ => return Err(self.invalid("bad character range", item_start)),
+            }
+        }
+        Ok(format!("[{}{}]", if negated { "^" } else { "" }, inner))
+    }
+
+    /// Read an escape inside of a class, returning the character it stands for or writing the class it
+    /// stands for
+    fn class_escape(
+        &mut self,
+        start: usize,
+        inner: &mut String,
+    ) -> Result<Option<char>, DialectError> {
+        let Some(chr) = self.peek() else {
+            return Err(self.invalid("unterminated class", start));
+        };
+        self.pos += 1;
+        match chr {
+            'd' | 'D' | 'w' | 'W' | 's' | 'S' => inner.push_str(&self.perl_class(chr)),
+            'p' | 'P' if self.dialect == Dialect::JavaScript && self.unicode => {
+                let property = self.property(chr, start)?;
+                inner.push_str(&property);
+            }
+            'q' if self.sets => {
+                return Err(self.unsupported("string literal in a class", start, self.pos + 1))
+            }
+            'b' => return Ok(Some('\x08')),
+            '-' => return Ok(Some('-')),
+            chr => return self.escaped_char(chr, start).map(Some),
+        }
+        Ok(None)
+    }
+
+    /// Return whether an escape starting with a nonzero digit is a Python octal escape like `\101`
+    fn python_octal(&self) -> bool {
+        self.dialect == Dialect::Python
+            && (0..2).all(|idx| {
+                self.peek_at(self.pos + idx)
+                    .is_some_and(|chr| ('0'..='7').contains(&chr))
+            })
+            && ('0'..='7').contains(&self.chars[self.pos - 1].1)
+    }
+
+    /// Return the class for `\d`, `\w`, `\s` or their negations
+    fn perl_class(&self, chr: char) -> String {
+        let negated = chr.is_ascii_uppercase();
+        let inner = match (chr.to_ascii_lowercase(), self.dialect, self.scope().ascii) {
+            ('d', Dialect::JavaScript, _) | ('d', _, true) => "0-9",
+            ('w', Dialect::JavaScript, _) | ('w', _, true) => "0-9A-Za-z_",
+            ('s', Dialect::JavaScript, _) => JAVASCRIPT_SPACE,
+            ('s', _, true) => r"\t\n\x0B\f\r ",
+            _ => return format!(r"\{}", chr),
+        };
+        format!("[{}{}]", if negated { "^" } else { "" }, inner)
+    }
+
+    /// Read the rest of an escape that stands for a single character
+    fn escaped_char(&mut self, chr: char, start: usize) -> Result<char, DialectError> {
+        let control = |code: u32| char::from_u32(code).unwrap_or('\0');
+        Ok(match chr {
+            'n' => '\n',
+            't' => '\t',
+            'r' => '\r',
+            'f' => '\x0C',
+            'v' => '\x0B',
+            'a' if self.dialect == Dialect::Python => '\x07',
+            '0'..='7' if self.dialect == Dialect::Python => {
+                let mut code = chr.to_digit(8).unwrap_or(0);
+                for _ in 0..2 {
+                    match self.peek().and_then(|chr| chr.to_digit(8)) {
+                        Some(digit) => {
+                            code = code * 8 + digit;
+                            self.pos += 1;
+                        }
+                        None => break,
+                    }
+                }
+                char::from_u32(code)
+                    .ok_or_else(|| self.invalid("octal escape out of range", start))?
+            }
+            '0' => {
+                if self.peek().is_some_and(|chr| chr.is_ascii_digit()) {
+                    return Err(self.invalid("octal escapes are not supported", start));
+                }
+                '\0'
+            }
+            'x' => self.hex(2, start)?,
+            'u' if self.dialect == Dialect::JavaScript
+                && self.unicode
+                && self.peek() == Some('{') =>
+            {
+                self.pos += 1;
+                let mut code = String::new();
+                while let Some(digit) = self.peek().filter(char::is_ascii_hexdigit) {
+                    code.push(digit);
+                    self.pos += 1;
+                }
+                if self.peek() != Some('}') {
+                    return Err(self.invalid(r"invalid `\u{..}` escape", start));
+                }
+                self.pos += 1;
+                u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or_else(|| self.invalid("invalid code point", start))?
+            }
+            'u' if self.dialect == Dialect::JavaScript => {
+                let high = self.hex_code(4, start)?;
+                if (0xD800..0xDC00).contains(&high) && self.looking_at(r"\u") {
+                    let before = self.pos;
+                    self.pos += 2;
+                    match self.hex_code(4, start) {
+                        Ok(low) if (0xDC00..0xE000).contains(&low) => {
+                            return Ok(control(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)))
+                        }
+                        _ => self.pos = before,
+                    }
+                }
+                char::from_u32(high).ok_or_else(|| self.invalid("lone surrogate", start))?
+            }
+            'u' if self.dialect == Dialect::Python => self.hex(4, start)?,
+            'U' if self.dialect == Dialect::Python => self.hex(8, start)?,
+            'N' if self.dialect == Dialect::Python => {
+                return Err(self.unsupported("named character escape", start, self.pos + 1))
+            }
+            'c' if self.dialect == Dialect::JavaScript => match self.peek() {
+                Some(letter) if letter.is_ascii_alphabetic() => {
+                    self.pos += 1;
+                    control(letter as u32 % 32)
+                }
+                _ => return Err(self.invalid(r"invalid `\c` escape", start)),
+            },
+            chr if chr.is_ascii_alphanumeric()
+                && (self.dialect == Dialect::Python || self.unicode) =>
+            {
+                return Err(self.invalid(&format!(r"bad escape `\{}`", chr), start))
+            }
+            chr => chr,
+        })
+    }
+
+    /// Read a fixed number of hex digits as a character
+    fn hex(&mut self, digits: usize, start: usize) -> Result<char, DialectError> {
+        let code = self.hex_code(digits, start)?;
+        char::from_u32(code).ok_or_else(|| self.invalid("invalid code point", start))
+    }
+
+    /// Read a fixed number of hex digits
+    fn hex_code(&mut self, digits: usize, start: usize) -> Result<u32, DialectError> {
+        let text = self.snippet(self.pos, self.pos + digits);
+        match u32::from_str_radix(&text, 16) {
+            Ok(code) if text.len() == digits && text.chars().all(|chr| chr.is_ascii_hexdigit()) => {
+                self.pos += digits;
+                Ok(code)
+            }
+            _ => Err(self.invalid("incomplete hex escape", start)),
+        }
+    }
+}
+
+/// Escape a character for use inside of a class
+fn class_literal(chr: char) -> String {
+    match chr {
+        '\\' | '[' | ']' | '^' | '-' | '&' | '~' => format!(r"\{}", chr),
+        chr if chr.is_control() => format!(r"\x{{{:X}}}", chr as u32),
+        chr => chr.to_string(),
+    }
+}
+
+/// Escape a character for use outside of a class
+fn literal(chr: char) -> String {
+    if chr.is_control() {
+        format!(r"\x{{{:X}}}", chr as u32)
+    } else {
+        regex_syntax::escape(&chr.to_string())
+    }
+}
@@ -6,6 +6,7 @@
 
 pub mod cookbook;
 
+/// The core [HumanRegex] type and its state markers
 mod humanregex;
 #[doc(inline)]
 pub use humanregex::HumanRegex;
@@ -41,3 +42,80 @@ pub use ascii::*;
 pub mod flags;
 #[doc(inline)]
 pub use flags::*;
+
+pub mod bytes;
+#[doc(inline)]
+pub use bytes::*;
+
+pub mod set;
+#[doc(inline)]
+pub use set::*;
+
+pub mod dfa;
+
+pub mod options;
+#[doc(inline)]
+pub use options::*;
+
+/// Helpers for inspecting the structure of a regex string
+mod syntax;
+
+pub mod dialect;
+#[doc(inline)]
+pub use dialect::*;
+
+pub mod import;
+#[doc(inline)]
+pub use import::*;
+
+pub mod verbose;
+
+pub mod railroad;
+
+pub mod dot;
+#[doc(inline)]
+pub use dot::*;
+
+pub mod trace;
+#[doc(inline)]
+pub use trace::*;
+
+pub mod highlight;
+#[doc(inline)]
+pub use highlight::*;
+
+pub mod dsl;
+#[doc(inline)]
+pub use dsl::*;
+
+pub mod lint;
+#[doc(inline)]
+pub use lint::*;
+
+pub mod backtracking;
+#[doc(inline)]
+pub use backtracking::*;
+
+pub mod numbers;
+#[doc(inline)]
+pub use numbers::*;
+
+pub mod datetime;
+#[doc(inline)]
+pub use datetime::*;
+
+pub mod patterns;
+#[doc(inline)]
+pub use patterns::*;
+
+pub mod words;
+#[doc(inline)]
+pub use words::*;
+
+pub mod charset;
+#[doc(inline)]
+pub use charset::*;
+
+pub mod blocks;
+#[doc(inline)]
+pub use blocks::*;
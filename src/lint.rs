@@ -0,0 +1,489 @@
+//! Functions for finding suspicious compositions in a pattern
+//!
+//! [HumanRegex::lint] looks for mistakes that still build a pattern, or build one that cannot be
+//! compiled, such as [lazy](HumanRegex::lazy) applied to something that is not a repetition, which makes
+//! it optional instead. [HumanRegex::lint_for_validation] also checks that a pattern meant to validate
+//! whole inputs is anchored at both ends. Both return an empty list for a clean pattern, so they can be
+//! asserted on in tests over a whole library of patterns.
+
+use super::humanregex::*;
+use super::syntax::{self, FlagState};
+use regex_syntax::ast::{self, Ast};
+use regex_syntax::hir::{Class, Hir, HirKind};
+use std::ops::Range;
+
+/// The largest number of strings a branch of an alternation is expanded into when looking for shadowed branches
+const MAX_BRANCH_STRINGS: usize = 64;
+
+/// The kind of problem found by [HumanRegex::lint]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LintKind {
+    /// [lazy](HumanRegex::lazy) applied to something that is not a repetition, which makes it optional
+    LazyWithoutRepetition,
+    /// A repetition of something that matches no characters, such as [word_boundary](crate::word_boundary)
+    RepeatedAssertion,
+    /// A branch of [or](crate::or) that can never be chosen, because an earlier branch matches everything it does
+    ShadowedBranch,
+    /// [within_set](crate::within_set) or [without_set](crate::without_set) with no characters
+    EmptySet,
+    /// [between](crate::between) with a minimum larger than its maximum
+    ReversedBounds,
+    /// A repetition directly inside another, which a single repetition can replace
+    NestedRepetition,
+    /// A pattern meant for validation that is not anchored at its beginning or end
+    Unanchored,
+    /// A regex string that cannot be compiled, for another reason
+    InvalidPattern,
+}
+
+/// A problem found by [HumanRegex::lint]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Lint {
+    /// The kind of problem
+    pub kind: LintKind,
+    /// A description of the problem and how to fix it
+    pub message: String,
+    /// The part of the regex string with the problem, as a byte range
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} (at {}..{})",
+            self.message, self.span.start, self.span.end
+        )
+    }
+}
+
+impl<T> HumanRegex<T> {
+    /// Look for suspicious compositions in the pattern, in order of their position in the regex string
+    /// ```
+    /// use human_regex::{digit, nonescaped_text, one_or_more, or, text, word_boundary, LintKind};
+    /// let regex_string = text("a").lazy() + one_or_more(word_boundary()) + or(&["cat", "cat"]);
+    /// let kinds: Vec<LintKind> = regex_string.lint().iter().map(|lint| lint.kind).collect();
+    /// assert_eq!(
+    ///     kinds,
+    ///     [LintKind::LazyWithoutRepetition, LintKind::RepeatedAssertion, LintKind::ShadowedBranch]
+    /// );
+    /// assert_eq!(
+    ///     nonescaped_text(r"\d{5,2}").lint()[0].message,
+    ///     "`{5,2}` asks for at least 5 but at most 2 repetitions, swap the bounds"
+    /// );
+    /// assert!(one_or_more(digit()).lint().is_empty());
+    /// ```
+    pub fn lint(&self) -> Vec<Lint> {
+        let ast = match syntax::parse(&self.0) {
+            Ok(ast) => ast,
+            Err(_) => return vec![self.invalid()],
+        };
+        let mut linter = Linter {
+            pattern: &self.0,
+            lints: Vec::new(),
+        };
+        linter.visit(&ast, FlagState::default());
+        if let Err(regex_syntax::Error::Translate(error)) = regex_syntax::parse(&self.0) {
+            linter.lints.push(Lint {
+                kind: LintKind::InvalidPattern,
+                message: format!("the regex string cannot be compiled: {}", error.kind()),
+                span: error.span().start.offset..error.span().end.offset,
+            });
+        }
+        linter
+            .lints
+            .sort_by_key(|lint| (lint.span.start, lint.span.end));
+        linter.lints
+    }
+
+    /// Look for suspicious compositions like [lint](HumanRegex::lint), and also check that the pattern is
+    /// anchored at both ends, as a pattern validating whole inputs must be
+    /// ```
+    /// use human_regex::{beginning, digit, end, exactly, multi_line_mode, LintKind};
+    /// let zip_code = exactly(5, digit());
+    /// assert_eq!(zip_code.lint_for_validation().len(), 2);
+    /// assert_eq!(zip_code.lint_for_validation()[0].kind, LintKind::Unanchored);
+    /// assert!((beginning() + exactly(5, digit()) + end()).lint_for_validation().is_empty());
+    /// // In multi-line mode, `beginning()` and `end()` match around every line
+    /// assert_eq!(
+    ///     multi_line_mode(beginning() + exactly(5, digit()) + end()).lint_for_validation().len(),
+    ///     2
+    /// );
+    /// ```
+    pub fn lint_for_validation(&self) -> Vec<Lint> {
+        let mut lints = self.lint();
+        let Ok(ast) = syntax::parse(&self.0) else {
+            return lints;
+        };
+        let state = FlagState::default();
+        if !anchored(&ast, state, Side::Start) {
+            lints.push(Lint {
+                kind: LintKind::Unanchored,
+                message: "the pattern does not start with beginning() or beginning_of_text(), so \
+                          it accepts anything before a valid input"
+                    .to_string(),
+                span: 0..0,
+            });
+        }
+        if !anchored(&ast, state, Side::End) {
+            lints.push(Lint {
+                kind: LintKind::Unanchored,
+                message: "the pattern does not end with end() or end_of_text(), so it accepts \
+                          anything after a valid input"
+                    .to_string(),
+                span: self.0.len()..self.0.len(),
+            });
+        }
+        lints
+    }
+
+    /// Describe why the regex string cannot be parsed
+    fn invalid(&self) -> Lint {
+        let error = match ast::parse::Parser::new().parse(&self.0) {
+            Err(error) => error,
+            Ok(_) => unreachable!("only called for regex strings that cannot be parsed"),
+        };
+        let span = error.span().start.offset..error.span().end.offset;
+        let source = &self.0[span.clone()];
+        match error.kind() {
+            ast::ErrorKind::ClassUnclosed if source == "[]" || source == "[^]" => Lint {
+                kind: LintKind::EmptySet,
+                message: "a set of characters is empty, give within_set() or without_set() at \
+                          least one character"
+                    .to_string(),
+                span,
+            },
+            ast::ErrorKind::RepetitionCountInvalid => {
+                let bounds: Vec<&str> = source
+                    .trim_matches(|chr| chr == '{' || chr == '}')
+                    .split(',')
+                    .collect();
+                Lint {
+                    kind: LintKind::ReversedBounds,
+                    message: format!(
+                        "`{}` asks for at least {} but at most {} repetitions, swap the bounds",
+                        source,
+                        bounds[0],
+                        bounds.get(1).unwrap_or(&"")
+                    ),
+                    span,
+                }
+            }
+            kind => Lint {
+                kind: LintKind::InvalidPattern,
+                message: format!("the regex string cannot be compiled: {}", kind),
+                span,
+            },
+        }
+    }
+}
+
+/// Walks the syntax tree of a regex string, collecting lints
+struct Linter<'p> {
+    /// The regex string
+    pattern: &'p str,
+    /// The lints found so far
+    lints: Vec<Lint>,
+}
+
+impl Linter<'_> {
+    /// Record a lint about a part of the regex string
+    fn push(&mut self, kind: LintKind, message: String, span: &ast::Span) {
+        self.lints.push(Lint {
+            kind,
+            message,
+            span: span.start.offset..span.end.offset,
+        });
+    }
+
+    /// Check a node and everything inside of it
+    fn visit(&mut self, node: &Ast, state: FlagState) {
+        match node {
+            Ast::Repetition(repetition) => {
+                self.repetition(repetition);
+                self.visit(&repetition.ast, state);
+            }
+            Ast::Group(group) => {
+                let inner = match &group.kind {
+                    ast::GroupKind::NonCapturing(flags) => state.apply(flags),
+                    _ => state,
+                };
+                self.visit(&group.ast, inner);
+            }
+            Ast::Alternation(alternation) => {
+                self.alternation(alternation, state);
+                for branch in &alternation.asts {
+                    self.visit(branch, state);
+                }
+            }
+            Ast::Concat(concat) => {
+                let mut state = state;
+                for node in &concat.asts {
+                    self.visit(node, state);
+                    if let Ast::Flags(flags) = node {
+                        state = state.apply(&flags.flags);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Check a repetition for zero-width operands, misplaced laziness and nesting
+    fn repetition(&mut self, repetition: &ast::Repetition) {
+        let source = syntax::source(self.pattern, &repetition.span);
+        let operand = syntax::source(self.pattern, repetition.ast.span());
+        if zero_width(&repetition.ast) {
+            let message = format!(
+                "`{}` repeats `{}`, which matches no characters, so the repetition has no effect",
+                source, operand
+            );
+            self.push(LintKind::RepeatedAssertion, message, &repetition.span);
+            return;
+        }
+        let optional = repetition.op.kind == ast::RepetitionKind::ZeroOrOne && repetition.greedy;
+        if optional && !built_by_repetition(&repetition.ast) {
+            let message = format!(
+                "`{}` makes `{}` optional, because lazy() only changes repetitions; use zero_or_one() \
+                 if it is meant to be optional",
+                source, operand
+            );
+            self.push(LintKind::LazyWithoutRepetition, message, &repetition.span);
+            return;
+        }
+        let Ast::Group(group) = &*repetition.ast else {
+            return;
+        };
+        let Ast::Repetition(inner) = strip(&group.ast) else {
+            return;
+        };
+        if inner.greedy != repetition.greedy {
+            return;
+        }
+        if let Some(single) = combine(&inner.op.kind, &repetition.op.kind) {
+            let message = format!(
+                "`{}` nests one repetition in another, a single {} matches the same",
+                source, single
+            );
+            self.push(LintKind::NestedRepetition, message, &repetition.span);
+        }
+    }
+
+    /// Check the branches of an alternation for branches that earlier ones shadow
+    fn alternation(&mut self, alternation: &ast::Alternation, state: FlagState) {
+        let sources: Vec<&str> = alternation
+            .asts
+            .iter()
+            .map(|branch| syntax::source(self.pattern, branch.span()))
+            .collect();
+        let hirs: Vec<Option<Hir>> = sources
+            .iter()
+            .map(|source| state.translate(source))
+            .collect();
+        let matchers: Vec<Option<regex::Regex>> = hirs
+            .iter()
+            .map(|hir| match hir {
+                Some(hir) if hir.properties().look_set().is_empty() => {
+                    regex::Regex::new(&format!("^(?:{})$", hir)).ok()
+                }
+                _ => None,
+            })
+            .collect();
+        for (later, branch) in alternation.asts.iter().enumerate() {
+            let Some(hir) = &hirs[later] else {
+                continue;
+            };
+            let strings = finite_strings(hir);
+            let shadowing = (0..later).find(|&earlier| {
+                hirs[earlier].as_ref() == Some(hir)
+                    || subclass(hir, hirs[earlier].as_ref())
+                    || match (&strings, &matchers[earlier]) {
+                        (Some(strings), Some(matcher)) => {
+                            strings.iter().all(|string| matcher.is_match(string))
+                        }
+                        _ => false,
+                    }
+            });
+            if let Some(earlier) = shadowing {
+                let message = format!(
+                    "the branch `{}` can never be chosen, because the earlier branch `{}` already \
+                     matches everything it does",
+                    sources[later], sources[earlier]
+                );
+                self.push(LintKind::ShadowedBranch, message, branch.span());
+            }
+        }
+    }
+}
+
+/// Return a node without the non-capturing groups without flags around it
+fn strip(node: &Ast) -> &Ast {
+    match node {
+        Ast::Group(group) => match &group.kind {
+            ast::GroupKind::NonCapturing(flags) if flags.items.is_empty() => strip(&group.ast),
+            _ => node,
+        },
+        node => node,
+    }
+}
+
+/// Return whether a node always matches the empty string and nothing else
+fn zero_width(node: &Ast) -> bool {
+    match node {
+        Ast::Empty(_) | Ast::Assertion(_) | Ast::Flags(_) => true,
+        Ast::Group(group) => zero_width(&group.ast),
+        Ast::Repetition(repetition) => zero_width(&repetition.ast),
+        Ast::Concat(concat) => concat.asts.iter().all(zero_width),
+        Ast::Alternation(alternation) => alternation.asts.iter().all(zero_width),
+        _ => false,
+    }
+}
+
+/// Return whether the operand of a `?` looks like the group that [zero_or_one](crate::zero_or_one) wraps
+/// around its target, rather than something [lazy](HumanRegex::lazy) was applied to
+fn built_by_repetition(operand: &Ast) -> bool {
+    match operand {
+        Ast::Group(group) => match (&group.kind, &*group.ast) {
+            (ast::GroupKind::NonCapturing(flags), _) if !flags.items.is_empty() => false,
+            // The group around text() is not the one zero_or_one() adds, which would surround it
+            (ast::GroupKind::NonCapturing(_), Ast::Literal(_)) => false,
+            (ast::GroupKind::NonCapturing(_), Ast::Concat(concat)) => !concat
+                .asts
+                .iter()
+                .all(|node| matches!(node, Ast::Literal(_))),
+            (ast::GroupKind::NonCapturing(_), _) => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Return the single repetition function equivalent to nesting one repetition in another, if there is one
+fn combine(inner: &ast::RepetitionKind, outer: &ast::RepetitionKind) -> Option<String> {
+    use ast::RepetitionKind::*;
+    use ast::RepetitionRange::Exactly;
+    let bounds = |kind: &ast::RepetitionKind| match kind {
+        ZeroOrOne => Some((0, Some(1))),
+        ZeroOrMore => Some((0, None)),
+        OneOrMore => Some((1, None)),
+        Range(_) => None,
+    };
+    match (inner, outer) {
+        (Range(Exactly(n)), Range(Exactly(m))) => Some(format!("exactly({})", n * m)),
+        (inner, outer) => {
+            let ((inner_min, inner_max), (outer_min, outer_max)) = (bounds(inner)?, bounds(outer)?);
+            let single = match (inner_min * outer_min, inner_max.zip(outer_max)) {
+                (0, Some(_)) => "zero_or_one()",
+                (0, None) => "zero_or_more()",
+                _ => "one_or_more()",
+            };
+            Some(single.to_string())
+        }
+    }
+}
+
+/// Return whether a branch is a character class contained in an earlier branch that is also a class
+pub(crate) fn subclass(hir: &Hir, earlier: Option<&Hir>) -> bool {
+    match (hir.kind(), earlier.map(Hir::kind)) {
+        (HirKind::Class(Class::Unicode(class)), Some(HirKind::Class(Class::Unicode(earlier)))) => {
+            let mut outside = class.clone();
+            outside.difference(earlier);
+            outside.ranges().is_empty()
+        }
+        _ => false,
+    }
+}
+
+/// Return every string a branch can match, if it can match only a few
+pub(crate) fn finite_strings(hir: &Hir) -> Option<Vec<String>> {
+    let strings = match hir.kind() {
+        HirKind::Empty => vec![String::new()],
+        HirKind::Literal(literal) => vec![String::from_utf8(literal.0.to_vec()).ok()?],
+        HirKind::Class(Class::Unicode(class)) => {
+            let mut chars = Vec::new();
+            for range in class.ranges() {
+                for chr in range.start()..=range.end() {
+                    if chars.len() == MAX_BRANCH_STRINGS {
+                        return None;
+                    }
+                    chars.push(chr.to_string());
+                }
+            }
+            chars
+        }
+        HirKind::Capture(capture) => finite_strings(&capture.sub)?,
+        HirKind::Alternation(branches) => {
+            let mut strings = Vec::new();
+            for branch in branches {
+                strings.extend(finite_strings(branch)?);
+            }
+            strings
+        }
+        HirKind::Concat(parts) => {
+            let mut strings = vec![String::new()];
+            for part in parts {
+                let endings = finite_strings(part)?;
+                if strings.len() * endings.len() > MAX_BRANCH_STRINGS {
+                    return None;
+                }
+                strings = strings
+                    .iter()
+                    .flat_map(|start| endings.iter().map(move |end| format!("{}{}", start, end)))
+                    .collect();
+            }
+            strings
+        }
+        _ => return None,
+    };
+    (strings.len() <= MAX_BRANCH_STRINGS).then_some(strings)
+}
+
+/// An end of a pattern
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Side {
+    /// Where matches start
+    Start,
+    /// Where matches end
+    End,
+}
+
+/// Return whether every match of a node is anchored to the start or end of the haystack
+fn anchored(node: &Ast, state: FlagState, side: Side) -> bool {
+    match node {
+        Ast::Assertion(assertion) => matches!(
+            (side, &assertion.kind, state.multi_line),
+            (Side::Start, ast::AssertionKind::StartText, _)
+                | (Side::Start, ast::AssertionKind::StartLine, false)
+                | (Side::End, ast::AssertionKind::EndText, _)
+                | (Side::End, ast::AssertionKind::EndLine, false)
+        ),
+        Ast::Group(group) => match &group.kind {
+            ast::GroupKind::NonCapturing(flags) => anchored(&group.ast, state.apply(flags), side),
+            _ => anchored(&group.ast, state, side),
+        },
+        Ast::Alternation(alternation) => alternation
+            .asts
+            .iter()
+            .all(|branch| anchored(branch, state, side)),
+        Ast::Concat(concat) => {
+            // Flags set partway through apply to everything after them
+            let mut state = state;
+            let mut states = Vec::new();
+            for node in &concat.asts {
+                states.push(state);
+                if let Ast::Flags(flags) = node {
+                    state = state.apply(&flags.flags);
+                }
+            }
+            let mut nodes: Vec<(&Ast, FlagState)> = concat.asts.iter().zip(states).collect();
+            if side == Side::End {
+                nodes.reverse();
+            }
+            nodes
+                .into_iter()
+                .find(|(node, _)| !matches!(node, Ast::Flags(_) | Ast::Empty(_)))
+                .is_some_and(|(node, state)| anchored(node, state, side))
+        }
+        _ => false,
+    }
+}
@@ -11,16 +11,26 @@ use std::marker::PhantomData as pd;
 /// assert!(regex_string.to_regex().is_match("grey"));
 /// assert!(regex_string.to_regex().is_match("gray"));
 /// assert!(!regex_string.to_regex().is_match("graey"));
+/// assert!(!regex_string.to_regex().is_match("gr:ay"));
+/// // The alternation does not capture, so it doesn't shift the numbers of other groups
+/// assert_eq!(regex_string.to_regex().captures_len(), 1);
 /// ```
-pub fn or<T>(options: &[T]) -> HumanRegex<SymbolChain>
-where
-    T: Into<String> + fmt::Display,
-{
-    let mut regex_string = format!("{}", options[0].to_string());
-    for idx in 1..options.len() {
-        regex_string = format!("{}|{}", regex_string, options[idx].to_string())
+/// Choosing between raw bytes gives an expression that can only be matched against `&[u8]`:
+/// ```
+/// use human_regex::{byte, or};
+/// let regex_string = or(&[byte(0xFF), byte(0xFE)]);
+/// assert!(regex_string.to_bytes_regex().is_match(b"\xFE"));
+/// ```
+/// ```compile_fail
+/// use human_regex::{byte, or};
+/// or(&[byte(0xFF), byte(0xFE)]).to_regex();
+/// ```
+pub fn or<T: Alternative>(options: &[T]) -> HumanRegex<T::Chain> {
+    let mut regex_string = options[0].to_string();
+    for option in &options[1..] {
+        regex_string = format!("{}|{}", regex_string, option)
     }
-    HumanRegex(format!("(:?{})", regex_string), pd::<SymbolChain>)
+    HumanRegex(format!("(?:{})", regex_string), pd::<T::Chain>)
 }
 
 /// Xor on two [SymbolClass]es, also known as symmetric difference.
@@ -37,10 +47,7 @@ pub fn xor<T, U>(
     lhs: HumanRegex<SymbolClass<T>>,
     rhs: HumanRegex<SymbolClass<U>>,
 ) -> HumanRegex<SymbolClass<Custom>> {
-    HumanRegex(
-        format!("[{}~~{}]", lhs.to_string(), rhs.to_string()),
-        pd::<SymbolClass<Custom>>,
-    )
+    HumanRegex(format!("[{}~~{}]", lhs, rhs), pd::<SymbolClass<Custom>>)
 }
 
 /// A function for establishing an AND relationship between two or more possible matches
@@ -72,10 +79,7 @@ impl<T, U> std::ops::BitAnd<HumanRegex<SymbolClass<U>>> for HumanRegex<SymbolCla
     type Output = HumanRegex<SymbolClass<Custom>>;
 
     fn bitand(self, rhs: HumanRegex<SymbolClass<U>>) -> Self::Output {
-        HumanRegex(
-            format!("[{}&&{}]", self.to_string(), rhs.to_string()),
-            pd::<SymbolClass<Custom>>,
-        )
+        HumanRegex(format!("[{}&&{}]", self, rhs), pd::<SymbolClass<Custom>>)
     }
 }
 
@@ -93,7 +97,7 @@ pub fn subtract<T, U>(
     subtract: HumanRegex<SymbolClass<U>>,
 ) -> HumanRegex<SymbolClass<Custom>> {
     HumanRegex(
-        format!("[{}--{}]", from.to_string(), subtract.to_string()),
+        format!("[{}--{}]", from, subtract),
         pd::<SymbolClass<Custom>>,
     )
 }
@@ -140,27 +144,27 @@ impl std::ops::Not for HumanRegex<SymbolClass<Standard>> {
     }
 }
 
+/// Negation for custom symbol classes, including the results of [and], [xor] and [subtract]
+/// ```
+/// use human_regex::{within_range, within_set};
+/// let regex_string = !(within_range('a'..='g') & within_range('c'..='z'));
+/// assert!(regex_string.to_regex().is_match("a"));
+/// assert!(!regex_string.to_regex().is_match("d"));
+/// assert!(!within_set(&['^']).to_regex().is_match("a"));
+/// ```
 impl std::ops::Not for HumanRegex<SymbolClass<Custom>> {
     type Output = Self;
 
     fn not(self) -> Self::Output {
-        if self
-            .to_string()
-            .chars()
-            .nth(1)
-            .expect("Should always be at least 2 characters in SymbolClass<Custom>")
-            != '^'
-        {
-            HumanRegex(
-                self.to_string().replace("[", "[^"),
-                pd::<SymbolClass<Custom>>,
-            )
+        // Only the outermost class is negated, nested classes in set operations keep their meaning
+        let negated = if let Some(inner) = self.0.strip_prefix("[^") {
+            format!("[{}", inner)
+        } else if let Some(inner) = self.0.strip_prefix('[') {
+            format!("[^{}", inner)
         } else {
-            HumanRegex(
-                self.to_string().replace("[^", "["),
-                pd::<SymbolClass<Custom>>,
-            )
-        }
+            format!("[^{}]", self.0)
+        };
+        HumanRegex(negated, pd::<SymbolClass<Custom>>)
     }
 }
 
@@ -188,6 +192,25 @@ impl std::ops::Not for HumanRegex<SymbolClass<Ascii>> {
     }
 }
 
+/// Negation for raw byte classes.
+/// ```
+/// use human_regex::byte;
+/// let regex_string = !byte(0xFF);
+/// assert!(regex_string.to_bytes_regex().is_match(b"\xFE"));
+/// assert!(!regex_string.to_bytes_regex().is_match(b"\xFF"));
+/// ```
+impl std::ops::Not for HumanRegex<ByteClass> {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        if self.0.starts_with("(?-u:[^") {
+            HumanRegex(self.0.replacen("(?-u:[^", "(?-u:[", 1), pd::<ByteClass>)
+        } else {
+            HumanRegex(self.0.replacen("(?-u:[", "(?-u:[^", 1), pd::<ByteClass>)
+        }
+    }
+}
+
 impl std::ops::Not for HumanRegex<LiteralSymbolChain> {
     type Output = HumanRegex<SymbolChain>;
 
@@ -195,7 +218,6 @@ impl std::ops::Not for HumanRegex<LiteralSymbolChain> {
         HumanRegex(
             self.to_string()
                 .chars()
-                .into_iter()
                 .map(|chr| format!("[^{}]", chr))
                 .collect::<String>(),
             pd::<SymbolChain>,
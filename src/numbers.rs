@@ -0,0 +1,381 @@
+//! Functions for matching numbers within a range
+//!
+//! A regex cannot compare numbers, so a range like 0 to 255 has to be spelled out digit by digit,
+//! as in `25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9][0-9]|[0-9]`, which is easy to get wrong by hand.
+//! [number_in_range] and [decimal_in_range] generate these exactly. Longer numbers come first, so
+//! that a search finds `255` rather than `25`, but a number in range can still be found inside a
+//! larger one, so anchor the pattern or put it between separators.
+
+use super::humanregex::*;
+use std::marker::PhantomData as pd;
+use std::ops::RangeInclusive;
+
+/// The most digits after the decimal point that [decimal_in_range] accepts, as an `f64` holds about 15
+/// significant digits
+pub const MAX_PRECISION: u32 = 15;
+
+/// How the numbers matched by [number_in_range_with] and [decimal_in_range_with] may be written
+/// ```
+/// use human_regex::{beginning, end, number_in_range_with, NumberOptions};
+/// let options = NumberOptions {
+///     thousands_separator: Some(','),
+///     ..Default::default()
+/// };
+/// let regex = (beginning() + number_in_range_with(0..=1_000_000, &options) + end()).to_regex();
+/// assert!(regex.is_match("65,535"));
+/// assert!(!regex.is_match("65535"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct NumberOptions {
+    /// Whether any number of extra leading zeros are allowed, as in `007`
+    pub leading_zeros: bool,
+    /// The number of digits shorter numbers are padded to with zeros, as in `07` for a width of 2
+    pub zero_pad: usize,
+    /// Whether numbers that are not negative may start with `+`
+    pub plus_sign: bool,
+    /// The character separating groups of three digits, as in `1,000`, which is then required
+    pub thousands_separator: Option<char>,
+}
+
+/// Match an integer within a range, written without leading zeros
+/// ```
+/// use human_regex::{beginning, end, number_in_range};
+/// let octet = number_in_range(0..=255);
+/// assert_eq!(
+///     octet.to_string(),
+///     "(?:25[0-5]|2[0-4][0-9]|1[0-9]{2}|[1-9][0-9]|[0-9])"
+/// );
+/// let regex = (beginning() + number_in_range(-40..=85) + end()).to_regex();
+/// assert!(regex.is_match("-40"));
+/// assert!(regex.is_match("85"));
+/// assert!(!regex.is_match("86"));
+/// assert!(!regex.is_match("085"));
+/// ```
+/// # Panics
+/// If the range is empty
+pub fn number_in_range(range: RangeInclusive<i64>) -> HumanRegex<SymbolChain> {
+    number_in_range_with(range, &NumberOptions::default())
+}
+
+/// Match an integer within a range, written as the options allow
+/// ```
+/// use human_regex::{beginning, end, number_in_range_with, NumberOptions};
+/// let month = number_in_range_with(1..=12, &NumberOptions { zero_pad: 2, ..Default::default() });
+/// let regex = (beginning() + month + end()).to_regex();
+/// assert!(regex.is_match("07"));
+/// assert!(!regex.is_match("7"));
+/// let options = NumberOptions { leading_zeros: true, plus_sign: true, ..Default::default() };
+/// let regex = (beginning() + number_in_range_with(0..=65535, &options) + end()).to_regex();
+/// assert!(regex.is_match("+008080"));
+/// ```
+/// # Panics
+/// If the range is empty
+pub fn number_in_range_with(
+    range: RangeInclusive<i64>,
+    options: &NumberOptions,
+) -> HumanRegex<SymbolChain> {
+    let (lo, hi) = (*range.start(), *range.end());
+    assert!(lo <= hi, "the range of numbers {}..={} is empty", lo, hi);
+    let mut branches = Vec::new();
+    if lo < 0 {
+        let smallest = if hi < 0 { hi.unsigned_abs() } else { 1 };
+        let digits = integer(smallest.into(), lo.unsigned_abs().into(), options);
+        branches.extend(signed("-", digits));
+    }
+    if hi >= 0 {
+        let digits = integer(lo.max(0) as u128, hi as u128, options);
+        branches.extend(signed(plus(options), digits));
+    }
+    HumanRegex(format!("(?:{})", branches.join("|")), pd::<SymbolChain>)
+}
+
+/// Match a decimal number within a range, with at most `precision` digits after the decimal point,
+/// which is left out along with trailing zeros as needed
+/// ```
+/// use human_regex::{beginning, decimal_in_range, end};
+/// let latitude = (beginning() + decimal_in_range(-90.0..=90.0, 6) + end()).to_regex();
+/// assert!(latitude.is_match("-45.123456"));
+/// assert!(latitude.is_match("90"));
+/// assert!(latitude.is_match("90.000"));
+/// assert!(!latitude.is_match("90.000001"));
+/// assert!(!latitude.is_match("12.3456789"));
+/// assert!(!latitude.is_match("-0"));
+/// ```
+/// # Panics
+/// If the range is empty or the precision is larger than [MAX_PRECISION]
+pub fn decimal_in_range(range: RangeInclusive<f64>, precision: u32) -> HumanRegex<SymbolChain> {
+    decimal_in_range_with(range, precision, &NumberOptions::default())
+}
+
+/// Match a decimal number within a range like [decimal_in_range], with the digits before the decimal
+/// point written as the options allow
+/// ```
+/// use human_regex::{beginning, decimal_in_range_with, end, NumberOptions};
+/// let options = NumberOptions { thousands_separator: Some(','), ..Default::default() };
+/// let price = (beginning() + decimal_in_range_with(0.0..=9999.99, 2, &options) + end()).to_regex();
+/// assert!(price.is_match("1,250.50"));
+/// assert!(price.is_match("0.5"));
+/// assert!(!price.is_match("1250.50"));
+/// ```
+/// # Panics
+/// If the range is empty or the precision is larger than [MAX_PRECISION]
+pub fn decimal_in_range_with(
+    range: RangeInclusive<f64>,
+    precision: u32,
+    options: &NumberOptions,
+) -> HumanRegex<SymbolChain> {
+    assert!(
+        precision <= MAX_PRECISION,
+        "a precision of {} is larger than the limit of {}",
+        precision,
+        MAX_PRECISION
+    );
+    let scale = 10f64.powi(precision as i32);
+    let lo = scaled(*range.start() * scale, f64::ceil);
+    let hi = scaled(*range.end() * scale, f64::floor);
+    assert!(
+        lo <= hi,
+        "the range of numbers {}..={} has no number with {} digits after the point",
+        range.start(),
+        range.end(),
+        precision
+    );
+    let scale = 10u128.pow(precision);
+    let mut branches = Vec::new();
+    if lo < 0 {
+        let smallest = if hi < 0 { hi.unsigned_abs() } else { 1 };
+        let digits = decimal(smallest, lo.unsigned_abs(), scale, options);
+        branches.extend(signed("-", digits));
+    }
+    if hi >= 0 {
+        let digits = decimal(lo.max(0) as u128, hi as u128, scale, options);
+        branches.extend(signed(plus(options), digits));
+    }
+    HumanRegex(format!("(?:{})", branches.join("|")), pd::<SymbolChain>)
+}
+
+/// Round a bound scaled to an integer in the direction given, unless it is an integer already but for
+/// floating point error
+fn scaled(bound: f64, round: fn(f64) -> f64) -> i128 {
+    let nearest = bound.round();
+    match (bound - nearest).abs() < 1e-6 {
+        true => nearest as i128,
+        false => round(bound) as i128,
+    }
+}
+
+/// Return the optional `+` allowed before numbers that are not negative
+fn plus(options: &NumberOptions) -> &'static str {
+    match options.plus_sign {
+        true => r"\+?",
+        false => "",
+    }
+}
+
+/// Return the branches matching numbers after a sign, which need a group unless the sign is empty
+fn signed(sign: &str, branches: Vec<String>) -> Vec<String> {
+    match sign {
+        "" => branches,
+        sign => vec![format!("{}{}", sign, group(&branches))],
+    }
+}
+
+/// Join branches into a non-capturing group, unless there is only one
+fn group(branches: &[String]) -> String {
+    match branches {
+        [branch] => branch.clone(),
+        branches => format!("(?:{})", branches.join("|")),
+    }
+}
+
+/// Return the branches of the regex for the non-negative integers from `lo` to `hi`, without sign
+fn integer(lo: u128, hi: u128, options: &NumberOptions) -> Vec<String> {
+    let mut branches = Vec::new();
+    for length in (digits(lo).len()..=digits(hi).len()).rev() {
+        let shortest = match length {
+            1 => 0,
+            length => 10u128.pow(length as u32 - 1),
+        };
+        let longest = 10u128.pow(length as u32) - 1;
+        let sequences = split(
+            &padded(lo.max(shortest), length),
+            &padded(hi.min(longest), length),
+        );
+        for mut sequence in sequences {
+            while sequence.len() < options.zero_pad {
+                sequence.insert(0, (0, 0));
+            }
+            branches.push(render(&sequence, options.thousands_separator));
+        }
+    }
+    match options.leading_zeros {
+        true => vec![format!("0*{}", group(&branches))],
+        false => branches,
+    }
+}
+
+/// Return the branches of the regex for the non-negative decimals from `lo / scale` to `hi / scale`,
+/// without sign
+fn decimal(lo: u128, hi: u128, scale: u128, options: &NumberOptions) -> Vec<String> {
+    if scale == 1 {
+        return integer(lo, hi, options);
+    }
+    let (whole_lo, fraction_lo) = (lo / scale, lo % scale);
+    let (whole_hi, fraction_hi) = (hi / scale, hi % scale);
+    let mut pieces = Vec::new();
+    if whole_lo == whole_hi {
+        pieces.push((whole_lo, whole_hi, fraction_lo, fraction_hi));
+    } else {
+        let mut first = whole_lo;
+        let mut last = whole_hi;
+        if fraction_lo > 0 {
+            pieces.push((whole_lo, whole_lo, fraction_lo, scale - 1));
+            first += 1;
+        }
+        if fraction_hi < scale - 1 {
+            last -= 1;
+        }
+        if first <= last {
+            pieces.push((first, last, 0, scale - 1));
+        }
+        if fraction_hi < scale - 1 {
+            pieces.push((whole_hi, whole_hi, 0, fraction_hi));
+        }
+    }
+    // Larger whole parts are usually longer, and must be tried first
+    pieces.reverse();
+    let width = digits(scale).len() - 1;
+    pieces
+        .into_iter()
+        .map(|(first, last, fraction_lo, fraction_hi)| {
+            let whole = group(&integer(first, last, options));
+            let fractions = split(&padded(fraction_lo, width), &padded(fraction_hi, width));
+            format!("{}{}", whole, fraction(&fractions))
+        })
+        .collect()
+}
+
+/// Return the regex for a decimal point and the digits after it, given as sequences of digit ranges,
+/// where trailing zeros, and the point when every digit is zero, may be left out
+fn fraction(sequences: &[Vec<(u8, u8)>]) -> String {
+    let branches: Vec<String> = sequences
+        .iter()
+        .map(|sequence| shortened(sequence))
+        .collect();
+    let optional = sequences
+        .iter()
+        .any(|sequence| sequence.iter().all(|&(lo, _)| lo == 0));
+    let digits = group(&branches);
+    match optional {
+        true => format!(r"(?:\.{})?", digits),
+        false => format!(r"\.{}", digits),
+    }
+}
+
+/// Render a sequence of digit ranges where the digits after the first may be left out when every one
+/// left out could be zero
+fn shortened(sequence: &[(u8, u8)]) -> String {
+    let Some((&first, rest)) = sequence.split_first() else {
+        return String::new();
+    };
+    let first = digit_class(first);
+    if rest.is_empty() {
+        first
+    } else if rest.iter().all(|&range| range == (0, 9)) {
+        format!("{}[0-9]{{0,{}}}", first, rest.len())
+    } else if rest.iter().all(|&(lo, _)| lo == 0) {
+        format!("{}(?:{})?", first, shortened(rest))
+    } else {
+        format!("{}{}", first, shortened(rest))
+    }
+}
+
+/// Return the decimal digits of a number
+fn digits(number: u128) -> Vec<u8> {
+    number.to_string().bytes().map(|byte| byte - b'0').collect()
+}
+
+/// Return the decimal digits of a number, with leading zeros up to a length
+fn padded(number: u128, length: usize) -> Vec<u8> {
+    let mut digits = digits(number);
+    while digits.len() < length {
+        digits.insert(0, 0);
+    }
+    digits
+}
+
+/// Split the numbers between two with the same number of digits into sequences of digit ranges, each
+/// matching every combination of its digits
+fn split(lo: &[u8], hi: &[u8]) -> Vec<Vec<(u8, u8)>> {
+    let (Some((&first, lo_rest)), Some((&last, hi_rest))) = (lo.split_first(), hi.split_first())
+    else {
+        return vec![Vec::new()];
+    };
+    let prefixed = |digit: u8, sequences: Vec<Vec<(u8, u8)>>| {
+        sequences.into_iter().map(move |mut sequence| {
+            sequence.insert(0, (digit, digit));
+            sequence
+        })
+    };
+    if first == last {
+        return prefixed(first, split(lo_rest, hi_rest)).collect();
+    }
+    let rest = lo_rest.len();
+    let (lo_whole, hi_whole) = (
+        lo_rest.iter().all(|&digit| digit == 0),
+        hi_rest.iter().all(|&digit| digit == 9),
+    );
+    // Higher numbers first, so that the order is the same at every length
+    let mut sequences = Vec::new();
+    let (mut middle_lo, mut middle_hi) = (first, last);
+    if !hi_whole {
+        sequences.extend(prefixed(last, split(&vec![0; rest], hi_rest)));
+        middle_hi -= 1;
+    }
+    if !lo_whole {
+        middle_lo += 1;
+    }
+    if middle_lo <= middle_hi {
+        let mut sequence = vec![(middle_lo, middle_hi)];
+        sequence.extend(vec![(0, 9); rest]);
+        sequences.push(sequence);
+    }
+    if !lo_whole {
+        sequences.extend(prefixed(first, split(lo_rest, &vec![9; rest])));
+    }
+    sequences
+}
+
+/// Render a sequence of digit ranges, grouping digits by three with a separator when there is one
+fn render(sequence: &[(u8, u8)], separator: Option<char>) -> String {
+    let mut rendered = String::new();
+    let mut idx = 0;
+    while idx < sequence.len() {
+        let group_end = match separator {
+            Some(_) => idx + (sequence.len() - idx - 1) % 3 + 1,
+            None => sequence.len(),
+        };
+        let mut run = idx + 1;
+        while run < group_end && sequence[run] == sequence[idx] {
+            run += 1;
+        }
+        rendered.push_str(&digit_class(sequence[idx]));
+        if run - idx > 1 {
+            rendered.push_str(&format!("{{{}}}", run - idx));
+        }
+        idx = run;
+        if let Some(separator) = separator.filter(|_| idx == group_end && idx < sequence.len()) {
+            rendered.push_str(&regex_syntax::escape(&separator.to_string()));
+        }
+    }
+    rendered
+}
+
+/// Render a range of digits as a literal or a class of ASCII digits
+fn digit_class((lo, hi): (u8, u8)) -> String {
+    match hi - lo {
+        0 => lo.to_string(),
+        1 => format!("[{}{}]", lo, hi),
+        _ => format!("[{}-{}]", lo, hi),
+    }
+}
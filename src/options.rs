@@ -0,0 +1,310 @@
+//! Functions for compiling with custom engine options and match semantics
+
+use super::humanregex::*;
+use regex_automata::meta;
+use regex_automata::util::syntax;
+use regex_automata::{Anchored, Input};
+use std::ops::Range;
+
+/// The error returned when a pattern cannot be compiled with the given [Options], for instance because it
+/// goes over one of their limits
+#[doc(no_inline)]
+pub use regex_automata::meta::BuildError as RegexBuildError;
+#[doc(no_inline)]
+pub use regex_automata::util::captures::Captures;
+#[doc(no_inline)]
+pub use regex_automata::Match;
+
+/// The match semantics used when searching
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatchKind {
+    /// Report the leftmost match, preferring the earlier branch of an [or](crate::or) and respecting
+    /// greedy and [lazy](HumanRegex::lazy) repetitions. This is what [HumanRegex::to_regex] does.
+    #[default]
+    LeftmostFirst,
+    /// Report the leftmost match, preferring the longest one that starts there, as lexers usually want
+    LeftmostLongest,
+    /// Ignore match priority entirely, as described in
+    /// [regex-automata](https://docs.rs/regex-automata/latest/regex_automata/enum.MatchKind.html#variant.All).
+    /// Searches still report the leftmost match, and without priorities the one that ends last is
+    /// chosen among those starting there, just like with [LeftmostLongest](MatchKind::LeftmostLongest).
+    All,
+}
+
+/// Options for compiling a [HumanRegex], mirroring those of [regex::RegexBuilder].
+///
+/// ```
+/// use human_regex::{text, Options};
+/// let options = Options {
+///     case_insensitive: true,
+///     size_limit: 100 * (1 << 20),
+///     ..Default::default()
+/// };
+/// assert!(text("abc").to_regex_with(&options).unwrap().is_match("ABC"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Options {
+    /// The approximate size limit, in bytes, of the compiled regex
+    pub size_limit: usize,
+    /// The approximate capacity, in bytes, of the cache used by the lazy DFA
+    pub dfa_size_limit: usize,
+    /// The maximum nesting depth of the pattern
+    pub nest_limit: u32,
+    /// Whether octal escapes like `\141` are allowed
+    pub octal: bool,
+    /// Whether `\r\n` is treated as a line terminator by `^` and `$` in multi-line mode, see [crlf_mode](crate::crlf_mode)
+    pub crlf: bool,
+    /// The default for the `i` flag, see [case_insensitive](crate::case_insensitive)
+    pub case_insensitive: bool,
+    /// The default for the `m` flag, see [multi_line_mode](crate::multi_line_mode)
+    pub multi_line: bool,
+    /// The default for the `s` flag, see [dot_matches_newline_too](crate::dot_matches_newline_too)
+    pub dot_matches_new_line: bool,
+    /// The default for the `U` flag, see [swap_greed](crate::swap_greed)
+    pub swap_greed: bool,
+    /// The default for the `x` flag, see [ignore_whitespace](crate::ignore_whitespace)
+    pub ignore_whitespace: bool,
+    /// The default for the `u` flag, see [disable_unicode](crate::disable_unicode)
+    pub unicode: bool,
+    /// The match semantics used when searching
+    pub match_kind: MatchKind,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            size_limit: 10 * (1 << 20),
+            dfa_size_limit: 2 * (1 << 20),
+            nest_limit: 250,
+            octal: false,
+            crlf: false,
+            case_insensitive: false,
+            multi_line: false,
+            dot_matches_new_line: false,
+            swap_greed: false,
+            ignore_whitespace: false,
+            unicode: true,
+            match_kind: MatchKind::LeftmostFirst,
+        }
+    }
+}
+
+impl Options {
+    /// Build a meta regex from a pattern with these options and a given match kind
+    fn build(
+        &self,
+        pattern: &str,
+        kind: regex_automata::MatchKind,
+    ) -> Result<meta::Regex, Box<RegexBuildError>> {
+        meta::Builder::new()
+            .configure(
+                meta::Config::new()
+                    .match_kind(kind)
+                    .nfa_size_limit(Some(self.size_limit))
+                    .hybrid_cache_capacity(self.dfa_size_limit),
+            )
+            .syntax(
+                syntax::Config::new()
+                    .nest_limit(self.nest_limit)
+                    .octal(self.octal)
+                    .crlf(self.crlf)
+                    .case_insensitive(self.case_insensitive)
+                    .multi_line(self.multi_line)
+                    .dot_matches_new_line(self.dot_matches_new_line)
+                    .swap_greed(self.swap_greed)
+                    .ignore_whitespace(self.ignore_whitespace)
+                    .unicode(self.unicode),
+            )
+            .build(pattern)
+            .map_err(Box::new)
+    }
+}
+
+/// A regex compiled with custom [Options], see [HumanRegex::to_regex_with]
+#[derive(Clone, Debug)]
+pub struct ConfiguredRegex {
+    /// The match semantics requested
+    kind: MatchKind,
+    /// The regex used to find where matches start (and end, for leftmost-first semantics)
+    regex: meta::Regex,
+    /// For leftmost-longest and all semantics, a regex with all semantics used to extend a match as far as
+    /// possible and to resolve its captures
+    longest: Option<meta::Regex>,
+}
+
+impl<T: State<Chain = SymbolChain>> HumanRegex<T> {
+    /// Convert to a regex using custom engine [Options] and match semantics, or return why the pattern
+    /// cannot be compiled with them
+    /// ```
+    /// use human_regex::{or, text, MatchKind, Options};
+    /// let keyword = or(&[text("in"), text("int"), text("integer")]);
+    /// let first = keyword.to_regex_with(&Options::default()).unwrap();
+    /// let longest = keyword
+    ///     .to_regex_with(&Options {
+    ///         match_kind: MatchKind::LeftmostLongest,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(first.find("an integer").unwrap().range(), 3..5);
+    /// assert_eq!(longest.find("an integer").unwrap().range(), 3..10);
+    /// ```
+    pub fn to_regex_with(
+        &self,
+        options: &Options,
+    ) -> Result<ConfiguredRegex, Box<RegexBuildError>> {
+        let regex = options.build(&self.0, regex_automata::MatchKind::LeftmostFirst)?;
+        let longest = match options.match_kind {
+            MatchKind::LeftmostFirst => None,
+            MatchKind::LeftmostLongest | MatchKind::All => {
+                Some(options.build(&self.0, regex_automata::MatchKind::All)?)
+            }
+        };
+        Ok(ConfiguredRegex {
+            kind: options.match_kind,
+            regex,
+            longest,
+        })
+    }
+}
+
+impl ConfiguredRegex {
+    /// Return whether the regex matches anywhere in the haystack
+    /// ```
+    /// use human_regex::{text, Options};
+    /// let regex = text("a").to_regex_with(&Options::default()).unwrap();
+    /// assert!(regex.is_match("cat"));
+    /// assert!(!regex.is_match("dog"));
+    /// ```
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.regex.is_match(haystack)
+    }
+
+    /// Return the first match in the haystack
+    pub fn find(&self, haystack: &str) -> Option<Match> {
+        self.find_at(haystack, 0)
+    }
+
+    /// Return the first match in the haystack that starts at or after `start`
+    pub fn find_at(&self, haystack: &str, start: usize) -> Option<Match> {
+        let found = self.regex.search(&Input::new(haystack).range(start..))?;
+        match &self.longest {
+            Some(longest) => {
+                let extended = longest.search(
+                    &Input::new(haystack)
+                        .range(found.start()..)
+                        .anchored(Anchored::Yes),
+                )?;
+                Some(Match::must(0, found.start()..extended.end()))
+            }
+            None => Some(found),
+        }
+    }
+
+    /// Return every successive non-overlapping match in the haystack
+    /// ```
+    /// use human_regex::{digit, one_or_more, or, text, MatchKind, Options};
+    /// let token = or(&[text("=").to_string(), text("==").to_string(), one_or_more(digit()).to_string()]);
+    /// let lexer = token
+    ///     .to_regex_with(&Options {
+    ///         match_kind: MatchKind::LeftmostLongest,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// let tokens: Vec<&str> = lexer.find_iter("1==23=4").map(|m| &"1==23=4"[m.range()]).collect();
+    /// assert_eq!(tokens, vec!["1", "==", "23", "=", "4"]);
+    /// ```
+    pub fn find_iter<'r, 'h>(&'r self, haystack: &'h str) -> impl Iterator<Item = Match> + 'r
+    where
+        'h: 'r,
+    {
+        successive_matches(haystack, move |start| {
+            Some(self.find_at(haystack, start)?.range())
+        })
+        .map(|range| Match::must(0, range))
+    }
+
+    /// Return the captures of the first match in the haystack
+    /// ```
+    /// use human_regex::{named_capture, one_or_more, or, text, word, MatchKind, Options};
+    /// let regex = named_capture(or(&[text("a").to_string(), one_or_more(word()).to_string()]), "token");
+    /// let longest = regex
+    ///     .to_regex_with(&Options {
+    ///         match_kind: MatchKind::LeftmostLongest,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// let caps = longest.captures("abc").unwrap();
+    /// assert_eq!(caps.get_group_by_name("token").unwrap().range(), 0..3);
+    /// ```
+    /// Assertions see the whole haystack, including the text after the match:
+    /// ```
+    /// use human_regex::{end, named_capture, or, text, Captures, MatchKind, Options};
+    /// let regex = or(&[named_capture(text("ab"), "last") + end(), named_capture(text("ab"), "any")]);
+    /// let longest = regex
+    ///     .to_regex_with(&Options {
+    ///         match_kind: MatchKind::LeftmostLongest,
+    ///         ..Default::default()
+    ///     })
+    ///     .unwrap();
+    /// let caps: Captures = longest.captures("abc").unwrap();
+    /// assert!(caps.get_group_by_name("last").is_none());
+    /// assert_eq!(caps.get_group_by_name("any").unwrap().range(), 0..2);
+    /// ```
+    pub fn captures(&self, haystack: &str) -> Option<Captures> {
+        match &self.longest {
+            Some(longest) => {
+                let found = self.find(haystack)?;
+                let mut caps = longest.create_captures();
+                // Search the whole haystack so assertions like `$` and `\b` see what follows the match
+                longest.search_captures(
+                    &Input::new(haystack)
+                        .range(found.range())
+                        .anchored(Anchored::Yes),
+                    &mut caps,
+                );
+                Some(caps).filter(|caps| caps.get_match() == Some(found))
+            }
+            None => {
+                let mut caps = self.regex.create_captures();
+                self.regex.captures(haystack, &mut caps);
+                Some(caps).filter(Captures::is_match)
+            }
+        }
+    }
+
+    /// Return the match semantics this regex was compiled with
+    pub fn match_kind(&self) -> MatchKind {
+        self.kind
+    }
+}
+
+/// Return every successive non-overlapping match in the haystack, given a search for the first match
+/// starting at or after a position. Like [regex::Regex::find_iter], an empty match right where the
+/// previous match ended is skipped, and the search moves on by one character after an empty match.
+pub(crate) fn successive_matches<'a, F>(
+    haystack: &'a str,
+    mut find_at: F,
+) -> impl Iterator<Item = Range<usize>> + 'a
+where
+    F: FnMut(usize) -> Option<Range<usize>> + 'a,
+{
+    let mut start = 0;
+    let mut last_end = None;
+    std::iter::from_fn(move || loop {
+        if start > haystack.len() {
+            return None;
+        }
+        let found = find_at(start)?;
+        if found.is_empty() && last_end == Some(found.end) {
+            start = found.end
+                + haystack[found.end..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+            continue;
+        }
+        start = found.end;
+        last_end = Some(found.end);
+        return Some(found);
+    })
+}
@@ -0,0 +1,800 @@
+//! Functions for matching common formats like email addresses, URLs and IP addresses
+//!
+//! Each builder returns a pattern with named captures for its parts, and those with a `_with`
+//! variant take options for how strict to be. The patterns check the syntax of a format, not whether
+//! an address exists, and a search can find a valid value inside a longer invalid one, like
+//! `1.2.3.4` in `1.2.3.45`, so anchor the pattern with [beginning](crate::beginning) and
+//! [end](crate::end) to validate a whole string.
+//!
+//! | Builder | Matches | Captures | Strictness |
+//! |---------|---------|----------|------------|
+//! | [email] | `user.name+tag@example.com` | `local`, `domain` | a dotted local part and a domain with a top-level domain |
+//! | [email_rfc5322] | `"john doe"@[192.0.2.1]` | `local`, `domain` | everything RFC 5322 allows, without comments or obsolete forms |
+//! | [url], [url_with] | `https://user@example.com:8080/a/b?q=1#top` | `scheme`, `userinfo`, `host`, `port`, `path`, `query`, `fragment` | [UrlOptions] |
+//! | [uri] | `urn:isbn:0451450523` | the same, with `opaque` for the path when there is no host | the generic syntax of RFC 3986 |
+//! | [hostname], [hostname_with] | `www.example.com` | `hostname` | [HostnameOptions] |
+//! | [ipv4], [ipv4_with] | `192.168.0.1` | `ipv4` | [Ipv4Options] |
+//! | [ipv6], [ipv6_with] | `2001:db8::1`, `::ffff:192.0.2.1` | `ipv6`, `zone` | [Ipv6Options] |
+//! | [mac_address], [mac_address_with] | `00:1a:2b:3c:4d:5e` | `mac` | [MacOptions] |
+//! | [uuid], [uuid_with] | `123e4567-e89b-12d3-a456-426614174000` | `uuid`, `version` | [UuidOptions] |
+//! | [semver], [semver_with] | `1.0.0-rc.1+build.5` | `major`, `minor`, `patch`, `prerelease`, `build` | [SemverOptions] |
+//! | [hex_color], [hex_color_with] | `#ff8800` | `color` | [HexColorOptions] |
+//! | [base64], [base64_with] | `aGVsbG8=` | `base64` | [Base64Options] |
+
+use super::humanregex::*;
+use super::*;
+use std::marker::PhantomData as pd;
+
+/// A hexadecimal digit
+const HEX: &str = "[0-9A-Fa-f]";
+
+/// The characters a URI can contain as they are, for use in a character class
+const UNRESERVED: &str = r"A-Za-z0-9\-._~";
+
+/// The characters with a special meaning in a URI that its components can still contain, for use in
+/// a character class
+const SUB_DELIMS: &str = "!$&'()*+,;=";
+
+/// The characters of an atom in an email address, for use in a character class
+const ATEXT: &str = r"A-Za-z0-9!#$%&'*+/=?^_`{|}~\-";
+
+/// The schemes [url] accepts
+const WEB_SCHEMES: [&str; 2] = ["https", "http"];
+
+/// How strictly [hostname_with] matches host names
+/// ```
+/// use human_regex::{beginning, end, hostname_with, HostnameOptions};
+/// let options = HostnameOptions { fully_qualified: true, ..Default::default() };
+/// let regex = (beginning() + hostname_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("mail.example.org"));
+/// assert!(!regex.is_match("localhost"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HostnameOptions {
+    /// Whether the name needs at least two labels and a top-level domain starting with a letter
+    pub fully_qualified: bool,
+    /// Whether labels may contain `_`, as in DNS service records like `_sip._tcp.example.com`
+    pub underscores: bool,
+}
+
+/// How strictly [ipv4_with] matches IPv4 addresses
+/// ```
+/// use human_regex::{beginning, end, ipv4_with, Ipv4Options};
+/// let regex = (beginning() + ipv4_with(&Ipv4Options { leading_zeros: true }) + end()).to_regex();
+/// assert!(regex.is_match("010.000.000.001"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Ipv4Options {
+    /// Whether each number may be padded with zeros up to three digits, as in `010.000.000.001`
+    pub leading_zeros: bool,
+}
+
+/// How strictly [ipv6_with] matches IPv6 addresses
+/// ```
+/// use human_regex::{beginning, end, ipv6_with, Ipv6Options};
+/// let options = Ipv6Options { zone_id: true, ..Default::default() };
+/// let regex = (beginning() + ipv6_with(&options) + end()).to_regex();
+/// assert_eq!(&regex.captures("fe80::1%eth0").unwrap()["zone"], "eth0");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ipv6Options {
+    /// Whether the last 32 bits may be written as an IPv4 address, as in `::ffff:192.0.2.1`
+    pub embedded_ipv4: bool,
+    /// Whether a zone may follow the address after a `%`, as in `fe80::1%eth0`
+    pub zone_id: bool,
+}
+
+impl Default for Ipv6Options {
+    fn default() -> Self {
+        Ipv6Options {
+            embedded_ipv4: true,
+            zone_id: false,
+        }
+    }
+}
+
+/// Which ways of writing a MAC address [mac_address_with] accepts, each with the same separator
+/// throughout
+/// ```
+/// use human_regex::{beginning, end, mac_address_with, MacOptions};
+/// let options = MacOptions { dots: true, bare: true, ..Default::default() };
+/// let regex = (beginning() + mac_address_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("001a.2b3c.4d5e"));
+/// assert!(regex.is_match("001A2B3C4D5E"));
+/// assert!(!regex.is_match("00:1a-2b:3c:4d:5e"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MacOptions {
+    /// Whether pairs of digits may be separated by colons, as in `00:1a:2b:3c:4d:5e`
+    pub colons: bool,
+    /// Whether pairs of digits may be separated by hyphens, as in `00-1A-2B-3C-4D-5E`
+    pub hyphens: bool,
+    /// Whether groups of four digits may be separated by dots, as in `001a.2b3c.4d5e`
+    pub dots: bool,
+    /// Whether the twelve digits may be written without separators
+    pub bare: bool,
+}
+
+impl Default for MacOptions {
+    fn default() -> Self {
+        MacOptions {
+            colons: true,
+            hyphens: true,
+            dots: false,
+            bare: false,
+        }
+    }
+}
+
+/// How strictly [uuid_with] matches UUIDs
+/// ```
+/// use human_regex::{beginning, end, uuid_with, UuidOptions};
+/// let options = UuidOptions { version: Some(4), rfc_variant: true, ..Default::default() };
+/// let regex = (beginning() + uuid_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("f47ac10b-58cc-4372-a567-0e02b2c3d479"));
+/// assert!(!regex.is_match("f47ac10b-58cc-1372-a567-0e02b2c3d479"));
+/// assert!(!regex.is_match("f47ac10b-58cc-4372-c567-0e02b2c3d479"));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UuidOptions {
+    /// The only version accepted, from 1 to 8, or any digit if `None`
+    pub version: Option<u8>,
+    /// Whether the variant needs to be the one of RFC 9562, with `8`, `9`, `a` or `b` starting the
+    /// fourth group, and the version needs to be from 1 to 8
+    pub rfc_variant: bool,
+    /// Whether the UUID is written in braces, as Windows does with `{...}`
+    pub braces: bool,
+}
+
+/// How strictly [semver_with] matches semantic versions
+/// ```
+/// use human_regex::{beginning, end, semver_with, SemverOptions};
+/// let options = SemverOptions { v_prefix: true, partial: true };
+/// let regex = (beginning() + semver_with(&options) + end()).to_regex();
+/// let caps = regex.captures("v2.1").unwrap();
+/// assert_eq!(&caps["minor"], "1");
+/// assert!(caps.name("patch").is_none());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SemverOptions {
+    /// Whether the version may start with `v`, as in git tags like `v1.2.3`
+    pub v_prefix: bool,
+    /// Whether the minor and patch numbers may be left out, as in `1` or `1.2`
+    pub partial: bool,
+}
+
+/// Which lengths of hexadecimal colors [hex_color_with] accepts
+/// ```
+/// use human_regex::{beginning, end, hex_color_with, HexColorOptions};
+/// let options = HexColorOptions { alpha: true, optional_hash: true, ..Default::default() };
+/// let regex = (beginning() + hex_color_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("ff880080"));
+/// assert!(regex.is_match("#f808"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HexColorOptions {
+    /// Whether the color may be written with one digit for each channel, as in `#f80`
+    pub short: bool,
+    /// Whether the color may have an alpha channel, as in `#ff880080` or `#f808`
+    pub alpha: bool,
+    /// Whether the `#` may be left out
+    pub optional_hash: bool,
+}
+
+impl Default for HexColorOptions {
+    fn default() -> Self {
+        HexColorOptions {
+            short: true,
+            alpha: false,
+            optional_hash: false,
+        }
+    }
+}
+
+/// How strictly [base64_with] matches Base64
+/// ```
+/// use human_regex::{base64_with, beginning, end, Base64Options};
+/// let options = Base64Options { url_safe: true, padding: false, ..Default::default() };
+/// let regex = (beginning() + base64_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("PDw_Pz4-"));
+/// assert!(regex.is_match("aGk"));
+/// assert!(!regex.is_match("aGk="));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Base64Options {
+    /// Whether to use the URL-safe alphabet of RFC 4648, with `-` and `_` instead of `+` and `/`
+    pub url_safe: bool,
+    /// Whether the text is padded with `=` to a multiple of four characters, which is otherwise not
+    /// allowed
+    pub padding: bool,
+    /// Whether the unused bits of the last character must be zero, so each value is written one way
+    pub canonical: bool,
+}
+
+impl Default for Base64Options {
+    fn default() -> Self {
+        Base64Options {
+            url_safe: false,
+            padding: true,
+            canonical: false,
+        }
+    }
+}
+
+/// Which URLs [url_with] matches
+/// ```
+/// use human_regex::{beginning, end, url_with, UrlOptions};
+/// let options = UrlOptions { schemes: vec!["ftp".to_string()], optional_scheme: true };
+/// let regex = (beginning() + url_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("ftp://ftp.example.com/pub/file.txt"));
+/// assert!(regex.is_match("ftp.example.com/pub"));
+/// assert!(!regex.is_match("https://example.com"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UrlOptions {
+    /// The schemes accepted, compared without case, or any scheme if empty
+    pub schemes: Vec<String>,
+    /// Whether the scheme and `://` may be left out, as in `example.com/path`
+    pub optional_scheme: bool,
+}
+
+impl Default for UrlOptions {
+    fn default() -> Self {
+        UrlOptions {
+            schemes: WEB_SCHEMES
+                .iter()
+                .map(|scheme| scheme.to_string())
+                .collect(),
+            optional_scheme: false,
+        }
+    }
+}
+
+/// Match an email address as people write them, with a dotted local part and a domain name that
+/// ends in a top-level domain
+/// ```
+/// use human_regex::{beginning, email, end};
+/// let regex = (beginning() + email() + end()).to_regex();
+/// let caps = regex.captures("jane.doe+news@mail.example.com").unwrap();
+/// assert_eq!(&caps["local"], "jane.doe+news");
+/// assert_eq!(&caps["domain"], "mail.example.com");
+/// assert!(!regex.is_match("jane..doe@example.com"));
+/// assert!(!regex.is_match("jane@localhost"));
+/// ```
+pub fn email() -> HumanRegex<SymbolChain> {
+    let domain = host_name(&HostnameOptions {
+        fully_qualified: true,
+        underscores: false,
+    });
+    HumanRegex(
+        format!("(?P<local>{})@(?P<domain>{})", dot_atom(), domain),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match an email address as RFC 5322 defines them, also allowing quoted local parts, domain literals
+/// and domains without a dot, but not comments or the obsolete forms
+/// ```
+/// use human_regex::{beginning, email_rfc5322, end};
+/// let regex = (beginning() + email_rfc5322() + end()).to_regex();
+/// let caps = regex.captures(r#""john \"jd\" doe"@[192.0.2.1]"#).unwrap();
+/// assert_eq!(&caps["domain"], "[192.0.2.1]");
+/// assert!(regex.is_match("root@localhost"));
+/// assert!(!regex.is_match("john doe@example.com"));
+/// ```
+pub fn email_rfc5322() -> HumanRegex<SymbolChain> {
+    let quoted = r#""(?:[\t \x21\x23-\x5B\x5D-\x7E]|\\[\t\x20-\x7E])*""#;
+    let literal = r"\[[\t \x21-\x5A\x5E-\x7E]*\]";
+    HumanRegex(
+        format!(
+            "(?P<local>{0}|{1})@(?P<domain>{0}|{2})",
+            dot_atom(),
+            quoted,
+            literal
+        ),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match an `http` or `https` URL, with a host name, IPv4 address or IPv6 address in brackets as its
+/// host
+/// ```
+/// use human_regex::url;
+/// let regex = url().to_regex();
+/// let caps = regex.captures("see https://example.com:8443/docs/index.html?lang=en#intro").unwrap();
+/// assert_eq!(&caps["host"], "example.com");
+/// assert_eq!(&caps["port"], "8443");
+/// assert_eq!(&caps["path"], "/docs/index.html");
+/// assert_eq!(&caps["query"], "lang=en");
+/// assert_eq!(&caps["fragment"], "intro");
+/// assert_eq!(&regex.captures("http://[::1]/").unwrap()["host"], "[::1]");
+/// ```
+pub fn url() -> HumanRegex<SymbolChain> {
+    url_with(&UrlOptions::default())
+}
+
+/// Match a URL with a host, like [url], with the schemes the options allow
+/// ```
+/// use human_regex::{beginning, end, url_with, UrlOptions};
+/// let options = UrlOptions { schemes: Vec::new(), ..Default::default() };
+/// let regex = (beginning() + url_with(&options) + end()).to_regex();
+/// assert_eq!(&regex.captures("ws://chat.example.com/room").unwrap()["scheme"], "ws");
+/// assert!(!regex.is_match("mailto:jane@example.com"));
+/// ```
+pub fn url_with(options: &UrlOptions) -> HumanRegex<SymbolChain> {
+    let scheme = match options.schemes.is_empty() {
+        true => r"[A-Za-z][A-Za-z0-9+.\-]*".to_string(),
+        false => format!("(?i:{})", escape_all(&options.schemes).join("|")),
+    };
+    let scheme = format!("(?P<scheme>{})://", scheme);
+    let scheme = match options.optional_scheme {
+        true => format!("(?:{})?", scheme),
+        false => scheme,
+    };
+    let host = format!(
+        r"\[{}\]|{}|{}",
+        ipv6_address(true),
+        ipv4_address(&Ipv4Options::default()),
+        host_name(&HostnameOptions::default())
+    );
+    HumanRegex(
+        format!(
+            r"{}(?:(?P<userinfo>{})@)?(?P<host>{})(?::(?P<port>{}))?(?P<path>(?:/{}*)*){}",
+            scheme,
+            userinfo(),
+            host,
+            number_in_range(0..=65535),
+            path_char(),
+            query_and_fragment()
+        ),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match any URI in the generic syntax of RFC 3986, with or without a host
+///
+/// Without a host, as in `mailto:jane@example.com`, what follows the scheme is captured as `opaque`
+/// rather than `path`, as the regex crate cannot give two groups the same name.
+/// ```
+/// use human_regex::{beginning, end, uri};
+/// let regex = (beginning() + uri() + end()).to_regex();
+/// let caps = regex.captures("urn:isbn:0451450523").unwrap();
+/// assert_eq!((&caps["scheme"], &caps["opaque"]), ("urn", "isbn:0451450523"));
+/// let caps = regex.captures("file:///etc/hosts").unwrap();
+/// assert_eq!((&caps["host"], &caps["path"]), ("", "/etc/hosts"));
+/// assert!(!regex.is_match("//example.com"));
+/// assert!(!regex.is_match("http://exa mple.com"));
+/// ```
+pub fn uri() -> HumanRegex<SymbolChain> {
+    let host = format!(
+        r"\[(?:{}|v{}+\.[{}{}:]+)\]|{}|(?:[{}{}]|%{}{{2}})*",
+        ipv6_address(true),
+        HEX,
+        UNRESERVED,
+        SUB_DELIMS,
+        ipv4_address(&Ipv4Options::default()),
+        UNRESERVED,
+        SUB_DELIMS,
+        HEX
+    );
+    let authority = format!(
+        r"(?:(?P<userinfo>{})@)?(?P<host>{})(?::(?P<port>[0-9]*))?",
+        userinfo(),
+        host
+    );
+    let segment = format!("{}*", path_char());
+    HumanRegex(
+        format!(
+            r"(?P<scheme>[A-Za-z][A-Za-z0-9+.\-]*):(?://{}(?P<path>(?:/{})*)|(?P<opaque>/?(?:{}+(?:/{})*)?)){}",
+            authority,
+            segment,
+            path_char(),
+            segment,
+            query_and_fragment()
+        ),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match a host name, made of labels of letters, digits and hyphens separated by dots
+///
+/// The length of the whole name, at most 253 characters, is not checked.
+/// ```
+/// use human_regex::{beginning, end, hostname};
+/// let regex = (beginning() + hostname() + end()).to_regex();
+/// assert!(regex.is_match("localhost"));
+/// assert!(regex.is_match("xn--bcher-kva.example"));
+/// assert!(!regex.is_match("-example.com"));
+/// assert!(!regex.is_match("example..com"));
+/// ```
+pub fn hostname() -> HumanRegex<SymbolChain> {
+    hostname_with(&HostnameOptions::default())
+}
+
+/// Match a host name like [hostname], as strictly as the options say
+/// ```
+/// use human_regex::{beginning, end, hostname_with, HostnameOptions};
+/// let options = HostnameOptions { underscores: true, ..Default::default() };
+/// let regex = (beginning() + hostname_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("_sip._tcp.example.com"));
+/// ```
+pub fn hostname_with(options: &HostnameOptions) -> HumanRegex<SymbolChain> {
+    HumanRegex(
+        format!("(?P<hostname>{})", host_name(options)),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match an IPv4 address in dotted decimal, with each number from 0 to 255 and no leading zeros
+/// ```
+/// use human_regex::{beginning, end, ipv4};
+/// let regex = (beginning() + ipv4() + end()).to_regex();
+/// assert!(regex.is_match("192.168.0.1"));
+/// assert!(!regex.is_match("256.1.1.1"));
+/// assert!(!regex.is_match("192.168.01.1"));
+/// ```
+pub fn ipv4() -> HumanRegex<SymbolChain> {
+    ipv4_with(&Ipv4Options::default())
+}
+
+/// Match an IPv4 address like [ipv4], as strictly as the options say
+/// ```
+/// use human_regex::{ipv4_with, Ipv4Options};
+/// let regex = ipv4_with(&Ipv4Options { leading_zeros: true }).to_regex();
+/// assert_eq!(&regex.captures("from 192.168.001.010").unwrap()["ipv4"], "192.168.001.010");
+/// ```
+pub fn ipv4_with(options: &Ipv4Options) -> HumanRegex<SymbolChain> {
+    HumanRegex(
+        format!("(?P<ipv4>{})", ipv4_address(options)),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match an IPv6 address in any of its forms, with `::` for one or more groups of zeros and the last
+/// 32 bits optionally written as an IPv4 address
+/// ```
+/// use human_regex::{beginning, end, ipv6};
+/// let regex = (beginning() + ipv6() + end()).to_regex();
+/// for address in ["2001:db8:0:0:0:0:2:1", "2001:db8::2:1", "::1", "::", "::ffff:192.0.2.1"] {
+///     assert!(regex.is_match(address));
+/// }
+/// assert!(!regex.is_match("2001:db8::2::1"));
+/// assert!(!regex.is_match("2001:db8:0:0:0:0:0:2:1"));
+/// ```
+pub fn ipv6() -> HumanRegex<SymbolChain> {
+    ipv6_with(&Ipv6Options::default())
+}
+
+/// Match an IPv6 address like [ipv6], as strictly as the options say
+/// ```
+/// use human_regex::{beginning, end, ipv6_with, Ipv6Options};
+/// let options = Ipv6Options { embedded_ipv4: false, ..Default::default() };
+/// let regex = (beginning() + ipv6_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("::ffff:c000:201"));
+/// assert!(!regex.is_match("::ffff:192.0.2.1"));
+/// ```
+pub fn ipv6_with(options: &Ipv6Options) -> HumanRegex<SymbolChain> {
+    let zone = match options.zone_id {
+        true => r"(?:%(?P<zone>[0-9A-Za-z\-._~]+))?",
+        false => "",
+    };
+    HumanRegex(
+        format!("(?P<ipv6>{}){}", ipv6_address(options.embedded_ipv4), zone),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match a MAC address written as six pairs of hexadecimal digits separated by colons or hyphens
+/// ```
+/// use human_regex::{beginning, end, mac_address};
+/// let regex = (beginning() + mac_address() + end()).to_regex();
+/// assert!(regex.is_match("00:1a:2b:3c:4d:5e"));
+/// assert!(regex.is_match("00-1A-2B-3C-4D-5E"));
+/// assert!(!regex.is_match("00:1a:2b:3c:4d"));
+/// ```
+pub fn mac_address() -> HumanRegex<SymbolChain> {
+    mac_address_with(&MacOptions::default())
+}
+
+/// Match a MAC address written in any of the ways the options allow
+/// ```
+/// use human_regex::{mac_address_with, MacOptions};
+/// let options = MacOptions { colons: false, hyphens: false, dots: true, bare: false };
+/// let regex = mac_address_with(&options).to_regex();
+/// assert_eq!(&regex.captures("mac 001a.2b3c.4d5e").unwrap()["mac"], "001a.2b3c.4d5e");
+/// ```
+/// # Panics
+/// If the options allow no way of writing the address
+pub fn mac_address_with(options: &MacOptions) -> HumanRegex<SymbolChain> {
+    let mut forms = Vec::new();
+    for (allowed, separator) in [(options.colons, ":"), (options.hyphens, "-")] {
+        if allowed {
+            forms.push(format!("{0}{{2}}(?:{1}{0}{{2}}){{5}}", HEX, separator));
+        }
+    }
+    if options.dots {
+        forms.push(format!(r"{0}{{4}}(?:\.{0}{{4}}){{2}}", HEX));
+    }
+    if options.bare {
+        forms.push(format!("{}{{12}}", HEX));
+    }
+    assert!(
+        !forms.is_empty(),
+        "the options allow no way of writing a MAC address"
+    );
+    HumanRegex(format!("(?P<mac>{})", forms.join("|")), pd::<SymbolChain>)
+}
+
+/// Match a UUID written as 32 hexadecimal digits in groups of 8, 4, 4, 4 and 12, capturing its version
+/// ```
+/// use human_regex::{beginning, end, uuid};
+/// let regex = (beginning() + uuid() + end()).to_regex();
+/// let caps = regex.captures("123e4567-e89b-12d3-a456-426614174000").unwrap();
+/// assert_eq!(&caps["version"], "1");
+/// assert!(regex.is_match("00000000-0000-0000-0000-000000000000"));
+/// assert!(!regex.is_match("123e4567e89b12d3a456426614174000"));
+/// ```
+pub fn uuid() -> HumanRegex<SymbolChain> {
+    uuid_with(&UuidOptions::default())
+}
+
+/// Match a UUID like [uuid], as strictly as the options say
+/// ```
+/// use human_regex::{beginning, end, uuid_with, UuidOptions};
+/// let options = UuidOptions { version: Some(7), braces: true, ..Default::default() };
+/// let regex = (beginning() + uuid_with(&options) + end()).to_regex();
+/// let caps = regex.captures("{018F3C9A-7B2E-7C1D-9E4F-0A1B2C3D4E5F}").unwrap();
+/// assert_eq!(&caps["uuid"], "018F3C9A-7B2E-7C1D-9E4F-0A1B2C3D4E5F");
+/// ```
+/// # Panics
+/// If the version is not from 1 to 8
+pub fn uuid_with(options: &UuidOptions) -> HumanRegex<SymbolChain> {
+    let version = match (options.version, options.rfc_variant) {
+        (Some(version @ 1..=8), _) => version.to_string(),
+        (Some(version), _) => panic!("there is no UUID version {}", version),
+        (None, true) => "[1-8]".to_string(),
+        (None, false) => HEX.to_string(),
+    };
+    let variant = match options.rfc_variant {
+        true => "[89ABab]",
+        false => HEX,
+    };
+    let uuid = format!(
+        "(?P<uuid>{0}{{8}}-{0}{{4}}-(?P<version>{1}){0}{{3}}-{2}{0}{{3}}-{0}{{12}})",
+        HEX, version, variant
+    );
+    let uuid = match options.braces {
+        true => format!(r"\{{{}\}}", uuid),
+        false => uuid,
+    };
+    HumanRegex(uuid, pd::<SymbolChain>)
+}
+
+/// Match a semantic version as defined by [semver.org](https://semver.org), capturing its parts
+/// ```
+/// use human_regex::{beginning, end, semver};
+/// let regex = (beginning() + semver() + end()).to_regex();
+/// let caps = regex.captures("1.0.0-alpha.1+exp.sha.5114f85").unwrap();
+/// assert_eq!(&caps["major"], "1");
+/// assert_eq!(&caps["prerelease"], "alpha.1");
+/// assert_eq!(&caps["build"], "exp.sha.5114f85");
+/// assert!(!regex.is_match("1.02.0"));
+/// assert!(!regex.is_match("1.0.0-01"));
+/// ```
+pub fn semver() -> HumanRegex<SymbolChain> {
+    semver_with(&SemverOptions::default())
+}
+
+/// Match a semantic version like [semver], as strictly as the options say
+/// ```
+/// use human_regex::{semver_with, SemverOptions};
+/// let regex = semver_with(&SemverOptions { v_prefix: true, ..Default::default() }).to_regex();
+/// assert_eq!(&regex.captures("release v0.3.0").unwrap()["minor"], "3");
+/// ```
+pub fn semver_with(options: &SemverOptions) -> HumanRegex<SymbolChain> {
+    let number = "0|[1-9][0-9]*";
+    let identifier = format!("{}|[0-9]*[A-Za-z-][0-9A-Za-z-]*", number);
+    let prefix = match options.v_prefix {
+        true => "v?",
+        false => "",
+    };
+    let minor_and_patch = format!(
+        r"\.(?P<minor>{0})(?:\.(?P<patch>{0})){1}",
+        number,
+        if options.partial { "?" } else { "" }
+    );
+    let minor_and_patch = match options.partial {
+        true => format!("(?:{})?", minor_and_patch),
+        false => minor_and_patch,
+    };
+    HumanRegex(
+        format!(
+            r"{0}(?P<major>{1}){2}(?:-(?P<prerelease>(?:{3})(?:\.(?:{3}))*))?(?:\+(?P<build>[0-9A-Za-z-]+(?:\.[0-9A-Za-z-]+)*))?",
+            prefix, number, minor_and_patch, identifier
+        ),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match a color written as `#` and three or six hexadecimal digits, as in CSS
+/// ```
+/// use human_regex::{beginning, end, hex_color};
+/// let regex = (beginning() + hex_color() + end()).to_regex();
+/// assert_eq!(&regex.captures("#FF8800").unwrap()["color"], "FF8800");
+/// assert!(regex.is_match("#f80"));
+/// assert!(!regex.is_match("#ff88"));
+/// assert!(!regex.is_match("ff8800"));
+/// ```
+pub fn hex_color() -> HumanRegex<SymbolChain> {
+    hex_color_with(&HexColorOptions::default())
+}
+
+/// Match a hexadecimal color like [hex_color], with the lengths the options allow
+/// ```
+/// use human_regex::{beginning, end, hex_color_with, HexColorOptions};
+/// let options = HexColorOptions { short: false, ..Default::default() };
+/// let regex = (beginning() + hex_color_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("#ff8800"));
+/// assert!(!regex.is_match("#f80"));
+/// ```
+pub fn hex_color_with(options: &HexColorOptions) -> HumanRegex<SymbolChain> {
+    let lengths: Vec<String> = [
+        (options.alpha, 8),
+        (true, 6),
+        (options.alpha && options.short, 4),
+        (options.short, 3),
+    ]
+    .iter()
+    .filter(|(allowed, _)| *allowed)
+    .map(|(_, length)| format!("{}{{{}}}", HEX, length))
+    .collect();
+    let hash = match options.optional_hash {
+        true => "#?",
+        false => "#",
+    };
+    HumanRegex(
+        format!("{}(?P<color>{})", hash, lengths.join("|")),
+        pd::<SymbolChain>,
+    )
+}
+
+/// Match text encoded in the standard Base64 alphabet, padded with `=` to a multiple of four
+/// characters
+///
+/// Many words are also valid Base64, so this is best used to validate a whole string.
+/// ```
+/// use human_regex::{base64, beginning, end};
+/// let regex = (beginning() + base64() + end()).to_regex();
+/// assert!(regex.is_match("aGVsbG8gd29ybGQ="));
+/// assert!(regex.is_match("YQ=="));
+/// assert!(!regex.is_match("aGVsbG8"));
+/// assert!(!regex.is_match("a==="));
+/// assert!(!regex.is_match(""));
+/// ```
+pub fn base64() -> HumanRegex<SymbolChain> {
+    base64_with(&Base64Options::default())
+}
+
+/// Match text encoded in Base64 like [base64], as strictly as the options say
+/// ```
+/// use human_regex::{base64_with, beginning, end, Base64Options};
+/// let options = Base64Options { canonical: true, ..Default::default() };
+/// let regex = (beginning() + base64_with(&options) + end()).to_regex();
+/// assert!(regex.is_match("YQ=="));
+/// assert!(!regex.is_match("YR=="));
+/// ```
+pub fn base64_with(options: &Base64Options) -> HumanRegex<SymbolChain> {
+    let alphabet = match options.url_safe {
+        true => r"[A-Za-z0-9\-_]",
+        false => "[A-Za-z0-9+/]",
+    };
+    // The last character of a shorter group only uses its top bits, leaving a multiple of 16 or 4
+    let (one_byte, two_bytes) = match options.canonical {
+        true => ("[AQgw]", "[AEIMQUYcgkosw048]"),
+        false => (alphabet, alphabet),
+    };
+    let padding = match options.padding {
+        true => ("==", "="),
+        false => ("", ""),
+    };
+    HumanRegex(
+        format!(
+            "(?P<base64>(?:{0}{{4}})*(?:{0}{{4}}|{0}{1}{3}|{0}{{2}}{2}{4}))",
+            alphabet, one_byte, two_bytes, padding.0, padding.1
+        ),
+        pd::<SymbolChain>,
+    )
+}
+
+/// A dot-atom of RFC 5322, atoms of letters, digits and some symbols separated by single dots
+fn dot_atom() -> String {
+    format!(r"[{0}]+(?:\.[{0}]+)*", ATEXT)
+}
+
+/// A host name without a capture
+fn host_name(options: &HostnameOptions) -> String {
+    let chars = match options.underscores {
+        true => "A-Za-z0-9_",
+        false => "A-Za-z0-9",
+    };
+    let label = format!("[{0}](?:[{0}-]{{0,61}}[{0}])?", chars);
+    match options.fully_qualified {
+        true => format!(
+            r"(?:{}\.)+[A-Za-z](?:[A-Za-z0-9-]{{0,61}}[A-Za-z0-9])?",
+            label
+        ),
+        false => format!(r"{0}(?:\.{0})*", label),
+    }
+}
+
+/// An IPv4 address without a capture
+fn ipv4_address(options: &Ipv4Options) -> String {
+    let octet = match options.leading_zeros {
+        true => {
+            let padded: Vec<String> = [3, 2, 0]
+                .iter()
+                .map(|&zero_pad| {
+                    let options = NumberOptions {
+                        zero_pad,
+                        ..Default::default()
+                    };
+                    number_in_range_with(0..=255, &options).to_string()
+                })
+                .collect();
+            format!("(?:{})", padded.join("|"))
+        }
+        false => number_in_range(0..=255).to_string(),
+    };
+    format!(r"{0}(?:\.{0}){{3}}", octet)
+}
+
+/// An IPv6 address without a capture, with the forms ending in an IPv4 address first so that a search
+/// does not stop before them
+fn ipv6_address(embedded_ipv4: bool) -> String {
+    let group = format!("{}{{1,4}}", HEX);
+    // `count` groups separated by colons, before a `::`
+    let left = |count: usize| match count {
+        0 => String::new(),
+        1 => group.clone(),
+        count => format!("{0}(?::{0}){{{1}}}", group, count - 1),
+    };
+    // Up to `most` groups separated by colons, after a `::`
+    let right = |most: usize| match most {
+        0 => String::new(),
+        1 => format!("(?:{})?", group),
+        most => format!("(?:{0}(?::{0}){{0,{1}}})?", group, most - 1),
+    };
+    let mut forms = Vec::new();
+    if embedded_ipv4 {
+        let ipv4 = ipv4_address(&Ipv4Options::default());
+        forms.push(format!("(?:{}:){{6}}{}", group, ipv4));
+        for before in 0..=5 {
+            let after = match 5 - before {
+                0 => String::new(),
+                most => format!("(?:{}:){{0,{}}}", group, most),
+            };
+            forms.push(format!("{}::{}{}", left(before), after, ipv4));
+        }
+    }
+    forms.push(format!("(?:{0}:){{7}}{0}", group));
+    for before in 0..=7 {
+        forms.push(format!("{}::{}", left(before), right(7 - before)));
+    }
+    format!("(?:{})", forms.join("|"))
+}
+
+/// The user and password before the host of a URI, without the `@`
+fn userinfo() -> String {
+    format!("(?:[{}{}:]|%{}{{2}})*", UNRESERVED, SUB_DELIMS, HEX)
+}
+
+/// A character of a URI path, either allowed as it is or percent-encoded
+fn path_char() -> String {
+    format!("(?:[{}{}:@]|%{}{{2}})", UNRESERVED, SUB_DELIMS, HEX)
+}
+
+/// The optional query and fragment that end a URI
+fn query_and_fragment() -> String {
+    let chars = format!("(?:{}|[/?])*", path_char());
+    format!(r"(?:\?(?P<query>{0}))?(?:\#(?P<fragment>{0}))?", chars)
+}
@@ -0,0 +1,456 @@
+//! Functions for drawing patterns as railroad diagrams
+//!
+//! [HumanRegex::to_railroad_svg] renders a pattern as a self-contained SVG syntax diagram: sequences
+//! run left to right, [or](crate::or) branches split the track, repetitions loop back with their
+//! counts, capture groups are labeled boxes and assertions are drawn as markers. The SVG needs no
+//! external tools or scripts, so it can be embedded directly in generated documentation.
+
+use super::humanregex::*;
+use super::syntax::{self, FlagState};
+use super::verbose::{atom_of, describe_flags, Description};
+use regex_syntax::ast::{self, Ast};
+
+impl<T> HumanRegex<T> {
+    /// Render the pattern as an SVG railroad diagram
+    /// ```
+    /// use human_regex::{between, digit, named_capture, or, text};
+    /// let regex_string = named_capture(between(2, 4, digit()), "year") + or(&[text("AD"), text("BC")]);
+    /// let svg = regex_string.to_railroad_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains(">year<"));
+    /// assert!(svg.contains(">2 to 4 times<"));
+    /// assert!(svg.contains(">\"AD\"<"));
+    /// ```
+    pub fn to_railroad_svg(&self) -> String {
+        let node = match syntax::parse(&self.0) {
+            Ok(ast) => Builder { pattern: &self.0 }.build(&ast, FlagState::default()),
+            Err(_) => Node::Terminal {
+                text: self.0.clone(),
+                kind: "invalid",
+            },
+        };
+        let width = node.width() + 2 * MARGIN + 2 * END_WIDTH;
+        let height = node.up() + node.down() + 2 * MARGIN;
+        let y = MARGIN + node.up();
+        let mut svg = format!(
+            concat!(
+                r#"<svg xmlns="http://www.w3.org/2000/svg" class="railroad-diagram" "#,
+                r#"width="{width}" height="{height}" viewBox="0 0 {width} {height}">"#,
+                "\n<style>{style}</style>\n"
+            ),
+            width = width,
+            height = height,
+            style = STYLE
+        );
+        let end = MARGIN + END_WIDTH + node.width();
+        svg.push_str(&format!(
+            "<path class=\"end\" d=\"M {0} {1} v 16 M {2} {1} v 16\"/>\n",
+            MARGIN,
+            y - 8,
+            MARGIN + 4
+        ));
+        line(&mut svg, MARGIN, MARGIN + END_WIDTH, y);
+        node.render(&mut svg, MARGIN + END_WIDTH, y);
+        line(&mut svg, end, end + END_WIDTH, y);
+        svg.push_str(&format!(
+            "<path class=\"end\" d=\"M {0} {1} v 16 M {2} {1} v 16\"/>\n",
+            end + END_WIDTH - 4,
+            y - 8,
+            end + END_WIDTH
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// The stylesheet embedded in every diagram
+const STYLE: &str = concat!(
+    "path { fill: none; stroke: #333; stroke-width: 1.5; } ",
+    "rect { fill: #eef; stroke: #333; stroke-width: 1.5; } ",
+    "rect.class { fill: #efe; } ",
+    "rect.assertion, rect.flags { fill: #fee; stroke-dasharray: 3 2; } ",
+    "rect.invalid { fill: #fcc; } ",
+    "rect.group { fill: none; stroke: #888; stroke-dasharray: 5 3; } ",
+    "text { font: 12px monospace; fill: #000; } ",
+    "text.label { font-size: 10px; fill: #555; }"
+);
+
+/// Space around the whole diagram
+const MARGIN: i32 = 10;
+/// Width of the track at the start and the end of the diagram
+const END_WIDTH: i32 = 20;
+/// Width of one character in a box
+const CHAR_WIDTH: i32 = 8;
+/// Width of one character in a label
+const LABEL_CHAR_WIDTH: i32 = 6;
+/// Half the height of a box
+const HALF_HEIGHT: i32 = 11;
+/// Space between the text of a box and its border, and between a group and its contents
+const PADDING: i32 = 10;
+/// Length of track between items in a sequence
+const GAP: i32 = 10;
+/// Width of the curves where tracks split and join
+const ARC: i32 = 20;
+/// Vertical space between branches and loops
+const SPACING: i32 = 10;
+/// Height reserved for a label
+const LABEL_HEIGHT: i32 = 14;
+
+/// A piece of a railroad diagram
+enum Node {
+    /// A box with text in it, styled by its kind
+    Terminal {
+        /// The text in the box
+        text: String,
+        /// The CSS class of the box: `literal`, `class`, `assertion`, `flags` or `invalid`
+        kind: &'static str,
+    },
+    /// A bare piece of track that matches nothing
+    Skip,
+    /// Nodes that follow each other
+    Sequence(Vec<Node>),
+    /// Branches that split from and rejoin the track, the first one on the track itself
+    Choice(Vec<Node>),
+    /// A node with a track looping back around it
+    Loop {
+        /// The repeated node
+        node: Box<Node>,
+        /// The count shown under the loop
+        label: String,
+    },
+    /// A labeled box around a node
+    Group {
+        /// The grouped node
+        node: Box<Node>,
+        /// The label at the top of the box
+        label: String,
+    },
+}
+
+impl Node {
+    /// Return the horizontal size of the node
+    fn width(&self) -> i32 {
+        match self {
+            Node::Terminal { text, .. } => text_width(text, CHAR_WIDTH) + 2 * PADDING,
+            Node::Skip => 0,
+            Node::Sequence(nodes) => {
+                nodes.iter().map(Node::width).sum::<i32>() + GAP * (nodes.len() as i32 - 1).max(0)
+            }
+            Node::Choice(nodes) => nodes.iter().map(Node::width).max().unwrap_or(0) + 2 * ARC,
+            Node::Loop { node, label } => {
+                node.width().max(text_width(label, LABEL_CHAR_WIDTH)) + 2 * ARC
+            }
+            Node::Group { node, label } => {
+                node.width().max(text_width(label, LABEL_CHAR_WIDTH)) + 2 * PADDING
+            }
+        }
+    }
+
+    /// Return the space taken above the track
+    fn up(&self) -> i32 {
+        match self {
+            Node::Terminal { .. } => HALF_HEIGHT,
+            Node::Skip => 0,
+            Node::Sequence(nodes) => nodes.iter().map(Node::up).max().unwrap_or(0),
+            Node::Choice(nodes) => nodes.first().map(Node::up).unwrap_or(0),
+            Node::Loop { node, .. } => node.up(),
+            Node::Group { node, .. } => node.up() + PADDING + LABEL_HEIGHT,
+        }
+    }
+
+    /// Return the space taken below the track
+    fn down(&self) -> i32 {
+        match self {
+            Node::Terminal { .. } => HALF_HEIGHT,
+            Node::Skip => 0,
+            Node::Sequence(nodes) => nodes.iter().map(Node::down).max().unwrap_or(0),
+            Node::Choice(nodes) => {
+                nodes.first().map(Node::down).unwrap_or(0)
+                    + nodes
+                        .iter()
+                        .skip(1)
+                        .map(|node| SPACING + node.up() + node.down())
+                        .sum::<i32>()
+            }
+            Node::Loop { node, label } => {
+                node.down() + SPACING + if label.is_empty() { 0 } else { LABEL_HEIGHT }
+            }
+            Node::Group { node, .. } => node.down() + PADDING,
+        }
+    }
+
+    /// Draw the node with its track entering at `x`, `y`
+    fn render(&self, svg: &mut String, x: i32, y: i32) {
+        match self {
+            Node::Terminal { text, kind } => {
+                let width = self.width();
+                let radius = match *kind {
+                    "literal" => HALF_HEIGHT,
+                    _ => 3,
+                };
+                svg.push_str(&format!(
+                    "<rect class=\"{}\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\"/>\n",
+                    kind,
+                    x,
+                    y - HALF_HEIGHT,
+                    width,
+                    2 * HALF_HEIGHT,
+                    radius
+                ));
+                svg.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+                    x + width / 2,
+                    y + 4,
+                    xml_escape(text)
+                ));
+            }
+            Node::Skip => {}
+            Node::Sequence(nodes) => {
+                let mut cursor = x;
+                for (idx, node) in nodes.iter().enumerate() {
+                    if idx > 0 {
+                        line(svg, cursor, cursor + GAP, y);
+                        cursor += GAP;
+                    }
+                    node.render(svg, cursor, y);
+                    cursor += node.width();
+                }
+            }
+            Node::Choice(nodes) => {
+                let width = self.width();
+                let mut branch_y = y;
+                for (idx, node) in nodes.iter().enumerate() {
+                    if idx > 0 {
+                        branch_y += nodes[idx - 1].down() + SPACING + node.up();
+                        curve(svg, x, y, x + ARC, branch_y);
+                        curve(svg, x + width - ARC, branch_y, x + width, y);
+                    } else {
+                        line(svg, x, x + ARC, y);
+                        line(svg, x + width - ARC, x + width, y);
+                    }
+                    centered(svg, node, x + ARC, x + width - ARC, branch_y);
+                }
+            }
+            Node::Loop { node, label } => {
+                let width = self.width();
+                let loop_y = y + node.down() + SPACING;
+                line(svg, x, x + ARC, y);
+                centered(svg, node, x + ARC, x + width - ARC, y);
+                line(svg, x + width - ARC, x + width, y);
+                svg.push_str(&format!(
+                    "<path class=\"loop\" d=\"M {0} {1} C {2} {1} {2} {3} {0} {3} L {4} {3} C {5} {3} {5} {1} {4} {1}\"/>\n",
+                    x + width - ARC,
+                    y,
+                    x + width - ARC / 2,
+                    loop_y,
+                    x + ARC,
+                    x + ARC / 2
+                ));
+                if !label.is_empty() {
+                    svg.push_str(&format!(
+                        "<text class=\"label\" x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>\n",
+                        x + width / 2,
+                        loop_y + 11,
+                        xml_escape(label)
+                    ));
+                }
+            }
+            Node::Group { node, label } => {
+                let width = self.width();
+                svg.push_str(&format!(
+                    "<rect class=\"group\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"6\"/>\n",
+                    x,
+                    y - self.up(),
+                    width,
+                    self.up() + self.down()
+                ));
+                svg.push_str(&format!(
+                    "<text class=\"label\" x=\"{}\" y=\"{}\">{}</text>\n",
+                    x + 4,
+                    y - self.up() + 11,
+                    xml_escape(label)
+                ));
+                centered(svg, node, x, x + width, y);
+            }
+        }
+    }
+}
+
+/// Turns the syntax tree of a pattern into diagram nodes
+struct Builder<'p> {
+    /// The regex string, used to show bracketed classes as written
+    pattern: &'p str,
+}
+
+impl Builder<'_> {
+    /// Build the node for a piece of the syntax tree with the flags in effect around it
+    fn build(&self, node: &Ast, state: FlagState) -> Node {
+        match node {
+            Ast::Empty(_) => Node::Skip,
+            Ast::Flags(flags) => match describe_flags(&flags.flags) {
+                described if described.is_empty() => Node::Skip,
+                described => Node::Terminal {
+                    text: format!("{} from here on", described),
+                    kind: "flags",
+                },
+            },
+            Ast::Alternation(alternation) => Node::Choice(
+                alternation
+                    .asts
+                    .iter()
+                    .map(|branch| self.build(branch, state))
+                    .collect(),
+            ),
+            Ast::Concat(concat) => {
+                let mut state = state;
+                let mut nodes = Vec::new();
+                let mut text = String::new();
+                for node in &concat.asts {
+                    if let Ast::Literal(literal) = node {
+                        text.push(literal.c);
+                        continue;
+                    }
+                    if !text.is_empty() {
+                        nodes.push(literal(&text));
+                        text.clear();
+                    }
+                    if let Ast::Flags(flags) = node {
+                        state = state.apply(&flags.flags);
+                    }
+                    match self.build(node, state) {
+                        Node::Skip => {}
+                        node => nodes.push(node),
+                    }
+                }
+                if !text.is_empty() {
+                    nodes.push(literal(&text));
+                }
+                match nodes.len() {
+                    0 => Node::Skip,
+                    1 => nodes.remove(0),
+                    _ => Node::Sequence(nodes),
+                }
+            }
+            Ast::Repetition(repetition) => self.repetition(repetition, state),
+            Ast::Group(group) => {
+                let (label, inner) = match &group.kind {
+                    ast::GroupKind::CaptureIndex(index) => (format!("group {}", index), state),
+                    ast::GroupKind::CaptureName { name, .. } => (name.name.clone(), state),
+                    ast::GroupKind::NonCapturing(flags) => {
+                        (describe_flags(flags), state.apply(flags))
+                    }
+                };
+                let node = self.build(&group.ast, inner);
+                if label.is_empty() {
+                    node
+                } else {
+                    Node::Group {
+                        node: Box::new(node),
+                        label,
+                    }
+                }
+            }
+            Ast::ClassBracketed(class) => Node::Terminal {
+                text: syntax::source(self.pattern, &class.span).to_string(),
+                kind: "class",
+            },
+            atom => {
+                let (_, description) = atom_of(atom, state);
+                let kind = match atom {
+                    Ast::Assertion(_) => "assertion",
+                    Ast::Literal(_) => "literal",
+                    _ => "class",
+                };
+                let text = match description {
+                    Description::Noun(singular, _) => singular,
+                    Description::Phrase(phrase) => phrase,
+                };
+                Node::Terminal { text, kind }
+            }
+        }
+    }
+
+    /// Build the node for a repetition, as a loop, an optional branch or both
+    fn repetition(&self, repetition: &ast::Repetition, state: FlagState) -> Node {
+        use ast::RepetitionKind::*;
+        use ast::RepetitionRange::*;
+        let node = self.build(&repetition.ast, state);
+        let greedy = state.greedy(repetition);
+        let label = |count: String| match (count.is_empty(), greedy) {
+            (_, true) => count,
+            (true, false) => "lazy".to_string(),
+            (false, false) => format!("{}, lazy", count),
+        };
+        let (minimum, label) = match &repetition.op.kind {
+            ZeroOrOne => (0, None),
+            Range(Exactly(1)) => (1, None),
+            ZeroOrMore => (0, Some(label(String::new()))),
+            OneOrMore => (1, Some(label(String::new()))),
+            Range(Exactly(n)) => (*n, Some(label(format!("{} times", n)))),
+            Range(AtLeast(n)) => (*n, Some(label(format!("{} or more times", n)))),
+            Range(Bounded(n, m)) => (*n, Some(label(format!("{} to {} times", n, m)))),
+        };
+        let repeated = match label {
+            Some(label) => Node::Loop {
+                node: Box::new(node),
+                label,
+            },
+            None => node,
+        };
+        match (minimum, greedy) {
+            (0, true) => Node::Choice(vec![repeated, Node::Skip]),
+            (0, false) => Node::Choice(vec![Node::Skip, repeated]),
+            _ => repeated,
+        }
+    }
+}
+
+/// Build the box for a run of literal text
+fn literal(text: &str) -> Node {
+    Node::Terminal {
+        text: format!("{:?}", text),
+        kind: "literal",
+    }
+}
+
+/// Draw a node centered between `start` and `end`, extending the track to both sides of it
+fn centered(svg: &mut String, node: &Node, start: i32, end: i32, y: i32) {
+    let left = start + (end - start - node.width()) / 2;
+    line(svg, start, left, y);
+    node.render(svg, left, y);
+    line(svg, left + node.width(), end, y);
+}
+
+/// Draw a horizontal piece of track
+fn line(svg: &mut String, start: i32, end: i32, y: i32) {
+    if start < end {
+        svg.push_str(&format!("<path d=\"M {} {} H {}\"/>\n", start, y, end));
+    }
+}
+
+/// Draw a curve between two tracks at different heights
+fn curve(svg: &mut String, x1: i32, y1: i32, x2: i32, y2: i32) {
+    let middle = (x1 + x2) / 2;
+    svg.push_str(&format!(
+        "<path d=\"M {} {} C {} {} {} {} {} {}\"/>\n",
+        x1, y1, middle, y1, middle, y2, x2, y2
+    ));
+}
+
+/// Return the width of text at the given width per character
+fn text_width(text: &str, char_width: i32) -> i32 {
+    text.chars().count() as i32 * char_width
+}
+
+/// Escape text for use inside of an SVG element
+fn xml_escape(text: &str) -> String {
+    text.chars()
+        .map(|chr| match chr {
+            '&' => "&amp;".to_string(),
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            chr if chr.is_control() => format!("\\u{{{:x}}}", chr as u32),
+            chr => chr.to_string(),
+        })
+        .collect()
+}
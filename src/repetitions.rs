@@ -2,6 +2,13 @@
 
 use super::humanregex::*;
 use std::marker::PhantomData as pd;
+use std::ops::{Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive};
+
+/// The largest count the repetition functions accept, as for the regex crate
+///
+/// Counts this large can still exceed the default size limit of the compiled regex when the target
+/// is a large class like [word](crate::word); raise it with [to_regex_with](HumanRegex::to_regex_with).
+pub const MAX_REPETITIONS: u32 = 1000;
 
 /// Match at least _n_ of a certain target
 /// ```
@@ -10,8 +17,11 @@ use std::marker::PhantomData as pd;
 /// assert!(regex_string.to_regex().is_match("aaaa"));
 /// assert!(!regex_string.to_regex().is_match("aa"));
 /// ```
-pub fn at_least<T>(n: u8, target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{}){{{},}}", target, n), pd::<SymbolChain>)
+/// # Panics
+/// If _n_ is larger than [MAX_REPETITIONS]
+pub fn at_least<T: State>(n: u32, target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    check_count(n);
+    HumanRegex(format!("(?:{}){{{},}}", target, n), pd::<T::Chain>)
 }
 
 /// Match at least _n_ and at most _m_ of a certain target
@@ -21,8 +31,17 @@ pub fn at_least<T>(n: u8, target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
 /// assert!(regex_string.to_regex().is_match("aaaa"));
 /// assert!(!regex_string.to_regex().is_match("aa"));
 /// ```
-pub fn between<T>(n: u8, m: u8, target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{}){{{},{}}}", target, n, m), pd::<SymbolChain>)
+/// # Panics
+/// If _n_ or _m_ is larger than [MAX_REPETITIONS], or _n_ is larger than _m_
+/// ```should_panic
+/// use human_regex::{between, text};
+/// between(5, 2, text("a"));
+/// ```
+pub fn between<T: State>(n: u32, m: u32, target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    check_count(n);
+    check_count(m);
+    assert!(n <= m, "the range of repetitions {}..={} is empty", n, m);
+    HumanRegex(format!("(?:{}){{{},{}}}", target, n, m), pd::<T::Chain>)
 }
 
 /// Match one or more of a certain target
@@ -32,8 +51,8 @@ pub fn between<T>(n: u8, m: u8, target: HumanRegex<T>) -> HumanRegex<SymbolChain
 /// assert!(regex_string.to_regex().is_match("aaaa"));
 /// assert!(!regex_string.to_regex().is_match("bb"));
 /// ```
-pub fn one_or_more<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{})+", target), pd::<SymbolChain>)
+pub fn one_or_more<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?:{})+", target), pd::<T::Chain>)
 }
 
 /// Match zero or more of a certain target
@@ -44,8 +63,8 @@ pub fn one_or_more<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
 /// assert!(regex_string.to_regex().is_match("aaaaa"));
 /// assert!(regex_string.to_regex().is_match("bb"));
 /// ```
-pub fn zero_or_more<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{})*", target), pd::<SymbolChain>)
+pub fn zero_or_more<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?:{})*", target), pd::<T::Chain>)
 }
 
 /// Match zero or one of a certain target
@@ -55,8 +74,8 @@ pub fn zero_or_more<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
 /// assert!(regex_string.to_regex().is_match("a"));
 /// assert!(regex_string.to_regex().is_match("bb"));
 /// ```
-pub fn zero_or_one<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{})?", target), pd::<SymbolChain>)
+pub fn zero_or_one<T: State>(target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    HumanRegex(format!("(?:{})?", target), pd::<T::Chain>)
 }
 
 /// Match exactly _n_ of a certain target
@@ -65,7 +84,130 @@ pub fn zero_or_one<T>(target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
 /// let regex_string = exactly(5, text("a"));
 /// assert!(regex_string.to_regex().is_match("aaaaa"));
 /// assert!(!regex_string.to_regex().is_match("aaa"));
+/// assert!(exactly(300, text("a")).to_regex().is_match(&"a".repeat(300)));
+/// ```
+/// # Panics
+/// If _n_ is larger than [MAX_REPETITIONS]
+pub fn exactly<T: State>(n: u32, target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    check_count(n);
+    HumanRegex(format!("(?:{}){{{}}}", target, n), pd::<T::Chain>)
+}
+
+/// Match a certain target a number of times given as a count or a range, like `4`, `3..`, `2..=5` or `..=4`
 /// ```
-pub fn exactly<T>(n: u8, target: HumanRegex<T>) -> HumanRegex<SymbolChain> {
-    HumanRegex(format!("(?:{}){{{}}}", target, n), pd::<SymbolChain>)
+/// use human_regex::{digit, repeat};
+/// assert_eq!(repeat(4, digit()).to_string(), r"(?:\d){4}");
+/// assert_eq!(repeat(3.., digit()).to_string(), r"(?:\d){3,}");
+/// assert_eq!(repeat(2..=5, digit()).to_string(), r"(?:\d){2,5}");
+/// assert_eq!(repeat(..=4, digit()).to_string(), r"(?:\d){0,4}");
+/// assert_eq!(repeat(1.., digit()).to_string(), r"(?:\d)+");
+/// assert!(repeat(2..4, digit()).to_regex().is_match("123"));
+/// ```
+/// # Panics
+/// If the range is empty or a bound is larger than [MAX_REPETITIONS]
+pub fn repeat<T: State>(range: impl Repetitions, target: HumanRegex<T>) -> HumanRegex<T::Chain> {
+    let (min, max) = range.bounds();
+    check_count(min);
+    if let Some(max) = max {
+        check_count(max);
+        assert!(
+            min <= max,
+            "the range of repetitions {}..={} is empty",
+            min,
+            max
+        );
+    }
+    let quantifier = match (min, max) {
+        (0, None) => "*".to_string(),
+        (1, None) => "+".to_string(),
+        (min, None) => format!("{{{},}}", min),
+        (0, Some(1)) => "?".to_string(),
+        (min, Some(max)) if min == max => format!("{{{}}}", min),
+        (min, Some(max)) => format!("{{{},{}}}", min, max),
+    };
+    HumanRegex(format!("(?:{}){}", target, quantifier), pd::<T::Chain>)
+}
+
+/// Match a certain target a number of times given as a count or a range, as few times as possible
+/// ```
+/// use human_regex::{any, repeat_lazy, text};
+/// let regex_string = text("<") + repeat_lazy(1.., any()) + text(">");
+/// assert_eq!(regex_string.to_regex().find("<a> <b>").unwrap().as_str(), "<a>");
+/// ```
+/// # Panics
+/// If the range is empty or a bound is larger than [MAX_REPETITIONS]
+pub fn repeat_lazy<T: State>(
+    range: impl Repetitions,
+    target: HumanRegex<T>,
+) -> HumanRegex<T::Chain> {
+    repeat(range, target).lazy()
+}
+
+/// A number of repetitions accepted by [repeat]: a count, or a range of counts
+pub trait Repetitions {
+    /// Return the minimum and, if there is one, the maximum number of repetitions
+    /// ```
+    /// use human_regex::Repetitions;
+    /// assert_eq!((2..=5).bounds(), (2, Some(5)));
+    /// assert_eq!((3..).bounds(), (3, None));
+    /// ```
+    fn bounds(&self) -> (u32, Option<u32>);
+}
+
+impl Repetitions for u32 {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        (*self, Some(*self))
+    }
+}
+
+impl Repetitions for Range<u32> {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        assert!(
+            self.start < self.end,
+            "the range of repetitions {:?} is empty",
+            self
+        );
+        (self.start, Some(self.end - 1))
+    }
+}
+
+impl Repetitions for RangeInclusive<u32> {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        (*self.start(), Some(*self.end()))
+    }
+}
+
+impl Repetitions for RangeFrom<u32> {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        (self.start, None)
+    }
+}
+
+impl Repetitions for RangeTo<u32> {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        assert!(self.end > 0, "the range of repetitions {:?} is empty", self);
+        (0, Some(self.end - 1))
+    }
+}
+
+impl Repetitions for RangeToInclusive<u32> {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        (0, Some(self.end))
+    }
+}
+
+impl Repetitions for RangeFull {
+    fn bounds(&self) -> (u32, Option<u32>) {
+        (0, None)
+    }
+}
+
+/// Panic if a count is larger than the repetition functions accept
+fn check_count(n: u32) {
+    assert!(
+        n <= MAX_REPETITIONS,
+        "a repetition count of {} is larger than the limit of {}",
+        n,
+        MAX_REPETITIONS
+    );
 }
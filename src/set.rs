@@ -0,0 +1,193 @@
+//! Functions for matching many labeled patterns at once
+//!
+//! ```
+//! use human_regex::{digit, exactly, named_capture, text, HumanRegexSet};
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Line {
+//!     Error,
+//!     Date,
+//! }
+//!
+//! let set = HumanRegexSet::new()
+//!     .add(Line::Error, text("ERROR"))
+//!     .add(Line::Date, named_capture(exactly(4, digit()), "year") + text("-"))
+//!     .to_regex_set();
+//!
+//! assert_eq!(set.matches("2023- ERROR disk full"), vec![&Line::Error, &Line::Date]);
+//! assert_eq!(set.matches("INFO all good"), Vec::<&Line>::new());
+//!
+//! let (label, caps) = &set.captures("logged 2023-01-01")[0];
+//! assert_eq!(*label, &Line::Date);
+//! assert_eq!(&caps["year"], "2023");
+//! assert_eq!(caps.get(0).unwrap().range(), 7..12);
+//! ```
+
+use super::humanregex::*;
+use regex::{Captures, Regex, RegexSet};
+use std::sync::OnceLock;
+
+/// A builder collecting labeled [HumanRegex] values to be compiled into a single [RegexSet].
+///
+/// Labels can be any type, such as a user-defined enum or a string.
+pub struct HumanRegexSet<L> {
+    /// The label of each member, in insertion order
+    labels: Vec<L>,
+    /// The regex string of each member, in insertion order
+    patterns: Vec<String>,
+}
+
+impl<L> HumanRegexSet<L> {
+    /// Create an empty set
+    /// ```
+    /// use human_regex::HumanRegexSet;
+    /// let set = HumanRegexSet::<&str>::new().to_regex_set();
+    /// assert!(set.matches("anything").is_empty());
+    /// ```
+    pub fn new() -> Self {
+        HumanRegexSet {
+            labels: Vec::new(),
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Add a labeled pattern to the set
+    /// ```
+    /// use human_regex::{digit, one_or_more, text, HumanRegexSet};
+    /// let set = HumanRegexSet::new()
+    ///     .add("number", one_or_more(digit()))
+    ///     .add("greeting", text("hello"))
+    ///     .to_regex_set();
+    /// assert_eq!(set.matches("hello 42"), vec![&"number", &"greeting"]);
+    /// ```
+    pub fn add<T: State<Chain = SymbolChain>>(mut self, label: L, regex: HumanRegex<T>) -> Self {
+        self.labels.push(label);
+        self.patterns.push(regex.0);
+        self
+    }
+
+    /// Convert to a [LabeledRegexSet], compiling every member into a single rust [RegexSet]
+    /// ```
+    /// use human_regex::{digit, text, HumanRegexSet};
+    /// let set = HumanRegexSet::new()
+    ///     .add('d', digit())
+    ///     .add('x', text("x"))
+    ///     .to_regex_set();
+    /// assert_eq!(set.matches("x1"), vec![&'d', &'x']);
+    /// ```
+    pub fn to_regex_set(self) -> LabeledRegexSet<L> {
+        LabeledRegexSet {
+            set: RegexSet::new(&self.patterns).unwrap(),
+            members: self.patterns.iter().map(|_| OnceLock::new()).collect(),
+            labels: self.labels,
+        }
+    }
+}
+
+impl<L> Default for HumanRegexSet<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Collect labeled patterns into a set
+/// ```
+/// use human_regex::{text, HumanRegexSet};
+/// let set: HumanRegexSet<String> = ["cat", "dog"]
+///     .into_iter()
+///     .map(|word| (word.to_string(), text(word)))
+///     .collect();
+/// assert_eq!(set.to_regex_set().matches("hotdog"), vec![&"dog".to_string()]);
+/// ```
+impl<L, T: State<Chain = SymbolChain>> FromIterator<(L, HumanRegex<T>)> for HumanRegexSet<L> {
+    fn from_iter<I: IntoIterator<Item = (L, HumanRegex<T>)>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(HumanRegexSet::new(), |set, (label, regex)| {
+                set.add(label, regex)
+            })
+    }
+}
+
+/// A compiled [HumanRegexSet] that reports matches by label rather than by index
+pub struct LabeledRegexSet<L> {
+    /// The compiled set used for the first pass
+    set: RegexSet,
+    /// Individually compiled members, built on first use by the second pass
+    members: Vec<OnceLock<Regex>>,
+    /// The label of each member, in insertion order
+    labels: Vec<L>,
+}
+
+impl<L> LabeledRegexSet<L> {
+    /// Return the labels of every member that matches anywhere in the haystack, in insertion order
+    /// ```
+    /// use human_regex::{digit, text, HumanRegexSet};
+    /// let set = HumanRegexSet::new()
+    ///     .add("number", digit())
+    ///     .add("cat", text("cat"))
+    ///     .add("dog", text("dog"))
+    ///     .to_regex_set();
+    /// assert_eq!(set.matches("dog and cat"), vec![&"cat", &"dog"]);
+    /// ```
+    pub fn matches(&self, haystack: &str) -> Vec<&L> {
+        self.set
+            .matches(haystack)
+            .into_iter()
+            .map(|idx| &self.labels[idx])
+            .collect()
+    }
+
+    /// Return whether any member matches anywhere in the haystack
+    /// ```
+    /// use human_regex::{text, HumanRegexSet};
+    /// let set = HumanRegexSet::new().add(1, text("a")).to_regex_set();
+    /// assert!(set.is_match("cat"));
+    /// assert!(!set.is_match("dog"));
+    /// ```
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.set.is_match(haystack)
+    }
+
+    /// Run a second pass over the members that matched, returning the label and the captures
+    /// (including the span of the whole match) of the leftmost match of each one
+    /// ```
+    /// use human_regex::{digit, named_capture, one_or_more, text, HumanRegexSet};
+    /// let set = HumanRegexSet::new()
+    ///     .add("price", text("$") + named_capture(one_or_more(digit()), "amount"))
+    ///     .add("free", text("free"))
+    ///     .to_regex_set();
+    /// let found = set.captures("from $25");
+    /// assert_eq!(found.len(), 1);
+    /// assert_eq!(found[0].0, &"price");
+    /// assert_eq!(&found[0].1["amount"], "25");
+    /// ```
+    pub fn captures<'h>(&self, haystack: &'h str) -> Vec<(&L, Captures<'h>)> {
+        self.set
+            .matches(haystack)
+            .into_iter()
+            .filter_map(|idx| {
+                self.member(idx)
+                    .captures(haystack)
+                    .map(|caps| (&self.labels[idx], caps))
+            })
+            .collect()
+    }
+
+    /// Return the labels of every member, in insertion order
+    /// ```
+    /// use human_regex::{digit, text, HumanRegexSet};
+    /// let set = HumanRegexSet::new()
+    ///     .add(2, digit())
+    ///     .add(1, text("a"))
+    ///     .to_regex_set();
+    /// assert_eq!(set.labels(), &[2, 1]);
+    /// ```
+    pub fn labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    /// Return the individually compiled member at an index
+    fn member(&self, idx: usize) -> &Regex {
+        self.members[idx].get_or_init(|| Regex::new(&self.set.patterns()[idx]).unwrap())
+    }
+}
@@ -1,5 +1,6 @@
 //! Functions for general purpose matches
 
+use super::charset::escape_class_char;
 use super::humanregex::*;
 use std::marker::PhantomData as pd;
 
@@ -79,7 +80,11 @@ pub fn non_whitespace() -> HumanRegex<SymbolClass<Standard>> {
 ///```
 pub fn within_range(range: std::ops::RangeInclusive<char>) -> HumanRegex<SymbolClass<Custom>> {
     HumanRegex(
-        format!("[{}-{}]", range.start(), range.end()),
+        format!(
+            "[{}-{}]",
+            escape_class_char(*range.start()),
+            escape_class_char(*range.end())
+        ),
         pd::<SymbolClass<Custom>>,
     )
 }
@@ -92,12 +97,21 @@ pub fn within_range(range: std::ops::RangeInclusive<char>) -> HumanRegex<SymbolC
 ///```
 pub fn without_range(range: std::ops::RangeInclusive<char>) -> HumanRegex<SymbolClass<Custom>> {
     HumanRegex(
-        format!("[^{}-{}]", range.start(), range.end()),
+        format!(
+            "[^{}-{}]",
+            escape_class_char(*range.start()),
+            escape_class_char(*range.end())
+        ),
         pd::<SymbolClass<Custom>>,
     )
 }
 
 /// Matches anything within a specified set of characters
+///
+/// Every character of every item stands for itself, so characters like `]`, `-` and `^` need no
+/// escaping. Items escaped with [escape_all](crate::escape_all) are read back as the characters they
+/// escape, so they still match those characters. Use a [CharSet](crate::CharSet) to combine sets
+/// before turning them into a class.
 /// ```
 /// use human_regex::{text,within_set};
 /// let regex_string = text("gr") + within_set(&['a','e']) + text("y");
@@ -109,13 +123,7 @@ pub fn within_set<T>(set: &[T]) -> HumanRegex<SymbolClass<Custom>>
 where
     T: Into<String> + fmt::Display,
 {
-    HumanRegex(
-        format!(
-            "[{}]",
-            set.into_iter().map(|c| c.to_string()).collect::<String>()
-        ),
-        pd::<SymbolClass<Custom>>,
-    )
+    HumanRegex(format!("[{}]", escape_set(set)), pd::<SymbolClass<Custom>>)
 }
 
 /// Matches anything outside a specified set of characters
@@ -130,19 +138,51 @@ pub fn without_set<T>(set: &[T]) -> HumanRegex<SymbolClass<Custom>>
 where
     T: Into<String> + fmt::Display,
 {
-    HumanRegex(
-        format!(
-            "[^{}]",
-            set.into_iter().map(|c| c.to_string()).collect::<String>()
-        ),
-        pd::<SymbolClass<Custom>>,
-    )
+    HumanRegex(format!("[^{}]", escape_set(set)), pd::<SymbolClass<Custom>>)
+}
+
+/// Escape the characters of every item of a set for use in a bracketed class, keeping their order
+fn escape_set<T: fmt::Display>(set: &[T]) -> String {
+    set.iter()
+        .flat_map(|item| unescape(&item.to_string()))
+        .map(escape_class_char)
+        .collect()
+}
+
+/// Read the characters of an item, undoing the escapes added by [escape_all](crate::escape_all)
+/// so that they aren't escaped twice
+fn unescape(item: &str) -> Vec<char> {
+    let mut chars = Vec::new();
+    let mut rest = item;
+    while let Some(chr) = rest.chars().next() {
+        rest = &rest[chr.len_utf8()..];
+        let escaped = match rest.chars().next() {
+            Some(next) if chr == '\\' && (regex_syntax::is_meta_character(next) || next == ' ') => {
+                Some((next, next.len_utf8()))
+            }
+            Some('x') if chr == '\\' => rest
+                .strip_prefix("x{")
+                .and_then(|hex| hex.split_once('}'))
+                .and_then(|(hex, _)| Some((u32::from_str_radix(hex, 16).ok()?, hex.len() + 3)))
+                .and_then(|(code, len)| Some((char::from_u32(code)?, len))),
+            _ => None,
+        };
+        match escaped {
+            Some((chr, len)) => {
+                chars.push(chr);
+                rest = &rest[len..];
+            }
+            None => chars.push(chr),
+        }
+    }
+    chars
 }
 
 /// An enum covering all Unicode character categories
 ///
 /// Used in the [unicode_category] function.
 #[allow(missing_docs)] // variants are self documenting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UnicodeCategory {
     Letter,
     LowercaseLetter,
@@ -180,10 +220,52 @@ pub enum UnicodeCategory {
     Control,
     Format,
     PrivateUse,
-    Surrogate,
     Unassigned,
 }
 
+impl UnicodeCategory {
+    /// Every variant, in order of declaration
+    pub const ALL: [UnicodeCategory; 37] = [
+        UnicodeCategory::Letter,
+        UnicodeCategory::LowercaseLetter,
+        UnicodeCategory::UppercaseLetter,
+        UnicodeCategory::TitlecaseLetter,
+        UnicodeCategory::CasedLetter,
+        UnicodeCategory::ModifierLetter,
+        UnicodeCategory::OtherLetter,
+        UnicodeCategory::Mark,
+        UnicodeCategory::NonSpacingMark,
+        UnicodeCategory::SpaceCombiningMark,
+        UnicodeCategory::EnclosingMark,
+        UnicodeCategory::Separator,
+        UnicodeCategory::SpaceSeparator,
+        UnicodeCategory::LineSeparator,
+        UnicodeCategory::ParagraphSeparator,
+        UnicodeCategory::Symbol,
+        UnicodeCategory::MathSymbol,
+        UnicodeCategory::CurrencySymbol,
+        UnicodeCategory::ModifierSymbol,
+        UnicodeCategory::OtherSymbol,
+        UnicodeCategory::Number,
+        UnicodeCategory::DecimalDigitNumber,
+        UnicodeCategory::LetterNumber,
+        UnicodeCategory::OtherNumber,
+        UnicodeCategory::Punctuation,
+        UnicodeCategory::DashPunctuation,
+        UnicodeCategory::OpenPunctuation,
+        UnicodeCategory::ClosePunctuation,
+        UnicodeCategory::InitialPunctuation,
+        UnicodeCategory::FinalPunctuation,
+        UnicodeCategory::ConnectorPunctuation,
+        UnicodeCategory::OtherPunctuation,
+        UnicodeCategory::Other,
+        UnicodeCategory::Control,
+        UnicodeCategory::Format,
+        UnicodeCategory::PrivateUse,
+        UnicodeCategory::Unassigned,
+    ];
+}
+
 /// A function for matching Unicode character categories. For matching script categories see [unicode_script].
 /// ```
 /// use human_regex::{beginning, end, one_or_more, unicode_category, UnicodeCategory};
@@ -204,8 +286,8 @@ pub fn unicode_category(category: UnicodeCategory) -> HumanRegex<SymbolClass<Sta
             UnicodeCategory::ModifierLetter => r"\p{Modifier_Letter}".to_string(),
             UnicodeCategory::OtherLetter => r"\p{Other_Letter}".to_string(),
             UnicodeCategory::Mark => r"\p{Mark}".to_string(),
-            UnicodeCategory::NonSpacingMark => r"\p{NonSpacing_Mark}".to_string(),
-            UnicodeCategory::SpaceCombiningMark => r"\p{SpaceCombining_Mark}".to_string(),
+            UnicodeCategory::NonSpacingMark => r"\p{Nonspacing_Mark}".to_string(),
+            UnicodeCategory::SpaceCombiningMark => r"\p{Spacing_Mark}".to_string(),
             UnicodeCategory::EnclosingMark => r"\p{Enclosing_Mark}".to_string(),
             UnicodeCategory::Separator => r"\p{Separator}".to_string(),
             UnicodeCategory::SpaceSeparator => r"\p{Space_Separator}".to_string(),
@@ -217,7 +299,7 @@ pub fn unicode_category(category: UnicodeCategory) -> HumanRegex<SymbolClass<Sta
             UnicodeCategory::ModifierSymbol => r"\p{Modifier_Symbol}".to_string(),
             UnicodeCategory::OtherSymbol => r"\p{Other_Symbol}".to_string(),
             UnicodeCategory::Number => r"\p{Number}".to_string(),
-            UnicodeCategory::DecimalDigitNumber => r"\p{DecimalDigit_Number}".to_string(),
+            UnicodeCategory::DecimalDigitNumber => r"\p{Decimal_Number}".to_string(),
             UnicodeCategory::LetterNumber => r"\p{Letter_Number}".to_string(),
             UnicodeCategory::OtherNumber => r"\p{Other_Number}".to_string(),
             UnicodeCategory::Punctuation => r"\p{Punctuation}".to_string(),
@@ -232,7 +314,6 @@ pub fn unicode_category(category: UnicodeCategory) -> HumanRegex<SymbolClass<Sta
             UnicodeCategory::Control => r"\p{Control}".to_string(),
             UnicodeCategory::Format => r"\p{Format}".to_string(),
             UnicodeCategory::PrivateUse => r"\p{Private_Use}".to_string(),
-            UnicodeCategory::Surrogate => r"\p{Surrogate}".to_string(),
             UnicodeCategory::Unassigned => r"\p{Unassigned}".to_string(),
         },
         pd::<SymbolClass<Standard>>,
@@ -252,54 +333,532 @@ pub fn non_unicode_category(category: UnicodeCategory) -> HumanRegex<SymbolClass
 
 /// An enum for covering all Unicode script categories
 ///
-/// Used in the [unicode_script] function
+/// Used in the [unicode_script] and [unicode_script_extensions] functions
 #[allow(missing_docs)] // variants are self documenting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum UnicodeScript {
-    Common,
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
     Arabic,
     Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
     Bengali,
+    Bhaiksuki,
     Bopomofo,
+    Brahmi,
     Braille,
+    Buginese,
     Buhid,
-    CandianAboriginal,
+    CanadianAboriginal,
+    Carian,
+    CaucasianAlbanian,
+    Chakma,
+    Cham,
     Cherokee,
+    Chorasmian,
+    Common,
+    Coptic,
+    Cuneiform,
+    Cypriot,
+    CyproMinoan,
     Cyrillic,
+    Deseret,
     Devanagari,
+    DivesAkuru,
+    Dogra,
+    Duployan,
+    EgyptianHieroglyphs,
+    Elbasan,
+    Elymaic,
     Ethiopic,
+    Garay,
     Georgian,
+    Glagolitic,
+    Gothic,
+    Grantha,
     Greek,
     Gujarati,
-    Gurkmukhi,
+    GunjalaGondi,
+    Gurmukhi,
+    GurungKhema,
     Han,
     Hangul,
+    HanifiRohingya,
     Hanunoo,
+    Hatran,
     Hebrew,
-    Hirigana,
+    Hiragana,
+    ImperialAramaic,
     Inherited,
+    InscriptionalPahlavi,
+    InscriptionalParthian,
+    Javanese,
+    Kaithi,
     Kannada,
     Katakana,
+    Kawi,
+    KayahLi,
+    Kharoshthi,
+    KhitanSmallScript,
     Khmer,
+    Khojki,
+    Khudawadi,
+    KiratRai,
     Lao,
     Latin,
+    Lepcha,
     Limbu,
+    LinearA,
+    LinearB,
+    Lisu,
+    Lycian,
+    Lydian,
+    Mahajani,
+    Makasar,
     Malayalam,
+    Mandaic,
+    Manichaean,
+    Marchen,
+    MasaramGondi,
+    Medefaidrin,
+    MeeteiMayek,
+    MendeKikakui,
+    MeroiticCursive,
+    MeroiticHieroglyphs,
+    Miao,
+    Modi,
     Mongolian,
+    Mro,
+    Multani,
     Myanmar,
+    Nabataean,
+    NagMundari,
+    Nandinagari,
+    NewTaiLue,
+    Newa,
+    Nko,
+    Nushu,
+    NyiakengPuachueHmong,
     Ogham,
+    OlChiki,
+    OlOnal,
+    OldHungarian,
+    OldItalic,
+    OldNorthArabian,
+    OldPermic,
+    OldPersian,
+    OldSogdian,
+    OldSouthArabian,
+    OldTurkic,
+    OldUyghur,
     Oriya,
+    Osage,
+    Osmanya,
+    PahawhHmong,
+    Palmyrene,
+    PauCinHau,
+    PhagsPa,
+    Phoenician,
+    PsalterPahlavi,
+    Rejang,
     Runic,
+    Samaritan,
+    Saurashtra,
+    Sharada,
+    Shavian,
+    Siddham,
+    SignWriting,
     Sinhala,
+    Sogdian,
+    SoraSompeng,
+    Soyombo,
+    Sundanese,
+    Sunuwar,
+    SylotiNagri,
     Syriac,
     Tagalog,
     Tagbanwa,
     TaiLe,
+    TaiTham,
+    TaiViet,
+    Takri,
     Tamil,
+    Tangsa,
+    Tangut,
     Telugu,
     Thaana,
     Thai,
     Tibetan,
+    Tifinagh,
+    Tirhuta,
+    Todhri,
+    Toto,
+    TuluTigalari,
+    Ugaritic,
+    Vai,
+    Vithkuqi,
+    Wancho,
+    WarangCiti,
+    Yezidi,
     Yi,
+    ZanabazarSquare,
+}
+
+impl UnicodeScript {
+    /// Every variant, in order of declaration
+    pub const ALL: [UnicodeScript; 170] = [
+        UnicodeScript::Adlam,
+        UnicodeScript::Ahom,
+        UnicodeScript::AnatolianHieroglyphs,
+        UnicodeScript::Arabic,
+        UnicodeScript::Armenian,
+        UnicodeScript::Avestan,
+        UnicodeScript::Balinese,
+        UnicodeScript::Bamum,
+        UnicodeScript::BassaVah,
+        UnicodeScript::Batak,
+        UnicodeScript::Bengali,
+        UnicodeScript::Bhaiksuki,
+        UnicodeScript::Bopomofo,
+        UnicodeScript::Brahmi,
+        UnicodeScript::Braille,
+        UnicodeScript::Buginese,
+        UnicodeScript::Buhid,
+        UnicodeScript::CanadianAboriginal,
+        UnicodeScript::Carian,
+        UnicodeScript::CaucasianAlbanian,
+        UnicodeScript::Chakma,
+        UnicodeScript::Cham,
+        UnicodeScript::Cherokee,
+        UnicodeScript::Chorasmian,
+        UnicodeScript::Common,
+        UnicodeScript::Coptic,
+        UnicodeScript::Cuneiform,
+        UnicodeScript::Cypriot,
+        UnicodeScript::CyproMinoan,
+        UnicodeScript::Cyrillic,
+        UnicodeScript::Deseret,
+        UnicodeScript::Devanagari,
+        UnicodeScript::DivesAkuru,
+        UnicodeScript::Dogra,
+        UnicodeScript::Duployan,
+        UnicodeScript::EgyptianHieroglyphs,
+        UnicodeScript::Elbasan,
+        UnicodeScript::Elymaic,
+        UnicodeScript::Ethiopic,
+        UnicodeScript::Garay,
+        UnicodeScript::Georgian,
+        UnicodeScript::Glagolitic,
+        UnicodeScript::Gothic,
+        UnicodeScript::Grantha,
+        UnicodeScript::Greek,
+        UnicodeScript::Gujarati,
+        UnicodeScript::GunjalaGondi,
+        UnicodeScript::Gurmukhi,
+        UnicodeScript::GurungKhema,
+        UnicodeScript::Han,
+        UnicodeScript::Hangul,
+        UnicodeScript::HanifiRohingya,
+        UnicodeScript::Hanunoo,
+        UnicodeScript::Hatran,
+        UnicodeScript::Hebrew,
+        UnicodeScript::Hiragana,
+        UnicodeScript::ImperialAramaic,
+        UnicodeScript::Inherited,
+        UnicodeScript::InscriptionalPahlavi,
+        UnicodeScript::InscriptionalParthian,
+        UnicodeScript::Javanese,
+        UnicodeScript::Kaithi,
+        UnicodeScript::Kannada,
+        UnicodeScript::Katakana,
+        UnicodeScript::Kawi,
+        UnicodeScript::KayahLi,
+        UnicodeScript::Kharoshthi,
+        UnicodeScript::KhitanSmallScript,
+        UnicodeScript::Khmer,
+        UnicodeScript::Khojki,
+        UnicodeScript::Khudawadi,
+        UnicodeScript::KiratRai,
+        UnicodeScript::Lao,
+        UnicodeScript::Latin,
+        UnicodeScript::Lepcha,
+        UnicodeScript::Limbu,
+        UnicodeScript::LinearA,
+        UnicodeScript::LinearB,
+        UnicodeScript::Lisu,
+        UnicodeScript::Lycian,
+        UnicodeScript::Lydian,
+        UnicodeScript::Mahajani,
+        UnicodeScript::Makasar,
+        UnicodeScript::Malayalam,
+        UnicodeScript::Mandaic,
+        UnicodeScript::Manichaean,
+        UnicodeScript::Marchen,
+        UnicodeScript::MasaramGondi,
+        UnicodeScript::Medefaidrin,
+        UnicodeScript::MeeteiMayek,
+        UnicodeScript::MendeKikakui,
+        UnicodeScript::MeroiticCursive,
+        UnicodeScript::MeroiticHieroglyphs,
+        UnicodeScript::Miao,
+        UnicodeScript::Modi,
+        UnicodeScript::Mongolian,
+        UnicodeScript::Mro,
+        UnicodeScript::Multani,
+        UnicodeScript::Myanmar,
+        UnicodeScript::Nabataean,
+        UnicodeScript::NagMundari,
+        UnicodeScript::Nandinagari,
+        UnicodeScript::NewTaiLue,
+        UnicodeScript::Newa,
+        UnicodeScript::Nko,
+        UnicodeScript::Nushu,
+        UnicodeScript::NyiakengPuachueHmong,
+        UnicodeScript::Ogham,
+        UnicodeScript::OlChiki,
+        UnicodeScript::OlOnal,
+        UnicodeScript::OldHungarian,
+        UnicodeScript::OldItalic,
+        UnicodeScript::OldNorthArabian,
+        UnicodeScript::OldPermic,
+        UnicodeScript::OldPersian,
+        UnicodeScript::OldSogdian,
+        UnicodeScript::OldSouthArabian,
+        UnicodeScript::OldTurkic,
+        UnicodeScript::OldUyghur,
+        UnicodeScript::Oriya,
+        UnicodeScript::Osage,
+        UnicodeScript::Osmanya,
+        UnicodeScript::PahawhHmong,
+        UnicodeScript::Palmyrene,
+        UnicodeScript::PauCinHau,
+        UnicodeScript::PhagsPa,
+        UnicodeScript::Phoenician,
+        UnicodeScript::PsalterPahlavi,
+        UnicodeScript::Rejang,
+        UnicodeScript::Runic,
+        UnicodeScript::Samaritan,
+        UnicodeScript::Saurashtra,
+        UnicodeScript::Sharada,
+        UnicodeScript::Shavian,
+        UnicodeScript::Siddham,
+        UnicodeScript::SignWriting,
+        UnicodeScript::Sinhala,
+        UnicodeScript::Sogdian,
+        UnicodeScript::SoraSompeng,
+        UnicodeScript::Soyombo,
+        UnicodeScript::Sundanese,
+        UnicodeScript::Sunuwar,
+        UnicodeScript::SylotiNagri,
+        UnicodeScript::Syriac,
+        UnicodeScript::Tagalog,
+        UnicodeScript::Tagbanwa,
+        UnicodeScript::TaiLe,
+        UnicodeScript::TaiTham,
+        UnicodeScript::TaiViet,
+        UnicodeScript::Takri,
+        UnicodeScript::Tamil,
+        UnicodeScript::Tangsa,
+        UnicodeScript::Tangut,
+        UnicodeScript::Telugu,
+        UnicodeScript::Thaana,
+        UnicodeScript::Thai,
+        UnicodeScript::Tibetan,
+        UnicodeScript::Tifinagh,
+        UnicodeScript::Tirhuta,
+        UnicodeScript::Todhri,
+        UnicodeScript::Toto,
+        UnicodeScript::TuluTigalari,
+        UnicodeScript::Ugaritic,
+        UnicodeScript::Vai,
+        UnicodeScript::Vithkuqi,
+        UnicodeScript::Wancho,
+        UnicodeScript::WarangCiti,
+        UnicodeScript::Yezidi,
+        UnicodeScript::Yi,
+        UnicodeScript::ZanabazarSquare,
+    ];
+
+    /// The name of the script in the Unicode character database
+    fn name(self) -> &'static str {
+        match self {
+            UnicodeScript::Adlam => "Adlam",
+            UnicodeScript::Ahom => "Ahom",
+            UnicodeScript::AnatolianHieroglyphs => "Anatolian_Hieroglyphs",
+            UnicodeScript::Arabic => "Arabic",
+            UnicodeScript::Armenian => "Armenian",
+            UnicodeScript::Avestan => "Avestan",
+            UnicodeScript::Balinese => "Balinese",
+            UnicodeScript::Bamum => "Bamum",
+            UnicodeScript::BassaVah => "Bassa_Vah",
+            UnicodeScript::Batak => "Batak",
+            UnicodeScript::Bengali => "Bengali",
+            UnicodeScript::Bhaiksuki => "Bhaiksuki",
+            UnicodeScript::Bopomofo => "Bopomofo",
+            UnicodeScript::Brahmi => "Brahmi",
+            UnicodeScript::Braille => "Braille",
+            UnicodeScript::Buginese => "Buginese",
+            UnicodeScript::Buhid => "Buhid",
+            UnicodeScript::CanadianAboriginal => "Canadian_Aboriginal",
+            UnicodeScript::Carian => "Carian",
+            UnicodeScript::CaucasianAlbanian => "Caucasian_Albanian",
+            UnicodeScript::Chakma => "Chakma",
+            UnicodeScript::Cham => "Cham",
+            UnicodeScript::Cherokee => "Cherokee",
+            UnicodeScript::Chorasmian => "Chorasmian",
+            UnicodeScript::Common => "Common",
+            UnicodeScript::Coptic => "Coptic",
+            UnicodeScript::Cuneiform => "Cuneiform",
+            UnicodeScript::Cypriot => "Cypriot",
+            UnicodeScript::CyproMinoan => "Cypro_Minoan",
+            UnicodeScript::Cyrillic => "Cyrillic",
+            UnicodeScript::Deseret => "Deseret",
+            UnicodeScript::Devanagari => "Devanagari",
+            UnicodeScript::DivesAkuru => "Dives_Akuru",
+            UnicodeScript::Dogra => "Dogra",
+            UnicodeScript::Duployan => "Duployan",
+            UnicodeScript::EgyptianHieroglyphs => "Egyptian_Hieroglyphs",
+            UnicodeScript::Elbasan => "Elbasan",
+            UnicodeScript::Elymaic => "Elymaic",
+            UnicodeScript::Ethiopic => "Ethiopic",
+            UnicodeScript::Garay => "Garay",
+            UnicodeScript::Georgian => "Georgian",
+            UnicodeScript::Glagolitic => "Glagolitic",
+            UnicodeScript::Gothic => "Gothic",
+            UnicodeScript::Grantha => "Grantha",
+            UnicodeScript::Greek => "Greek",
+            UnicodeScript::Gujarati => "Gujarati",
+            UnicodeScript::GunjalaGondi => "Gunjala_Gondi",
+            UnicodeScript::Gurmukhi => "Gurmukhi",
+            UnicodeScript::GurungKhema => "Gurung_Khema",
+            UnicodeScript::Han => "Han",
+            UnicodeScript::Hangul => "Hangul",
+            UnicodeScript::HanifiRohingya => "Hanifi_Rohingya",
+            UnicodeScript::Hanunoo => "Hanunoo",
+            UnicodeScript::Hatran => "Hatran",
+            UnicodeScript::Hebrew => "Hebrew",
+            UnicodeScript::Hiragana => "Hiragana",
+            UnicodeScript::ImperialAramaic => "Imperial_Aramaic",
+            UnicodeScript::Inherited => "Inherited",
+            UnicodeScript::InscriptionalPahlavi => "Inscriptional_Pahlavi",
+            UnicodeScript::InscriptionalParthian => "Inscriptional_Parthian",
+            UnicodeScript::Javanese => "Javanese",
+            UnicodeScript::Kaithi => "Kaithi",
+            UnicodeScript::Kannada => "Kannada",
+            UnicodeScript::Katakana => "Katakana",
+            UnicodeScript::Kawi => "Kawi",
+            UnicodeScript::KayahLi => "Kayah_Li",
+            UnicodeScript::Kharoshthi => "Kharoshthi",
+            UnicodeScript::KhitanSmallScript => "Khitan_Small_Script",
+            UnicodeScript::Khmer => "Khmer",
+            UnicodeScript::Khojki => "Khojki",
+            UnicodeScript::Khudawadi => "Khudawadi",
+            UnicodeScript::KiratRai => "Kirat_Rai",
+            UnicodeScript::Lao => "Lao",
+            UnicodeScript::Latin => "Latin",
+            UnicodeScript::Lepcha => "Lepcha",
+            UnicodeScript::Limbu => "Limbu",
+            UnicodeScript::LinearA => "Linear_A",
+            UnicodeScript::LinearB => "Linear_B",
+            UnicodeScript::Lisu => "Lisu",
+            UnicodeScript::Lycian => "Lycian",
+            UnicodeScript::Lydian => "Lydian",
+            UnicodeScript::Mahajani => "Mahajani",
+            UnicodeScript::Makasar => "Makasar",
+            UnicodeScript::Malayalam => "Malayalam",
+            UnicodeScript::Mandaic => "Mandaic",
+            UnicodeScript::Manichaean => "Manichaean",
+            UnicodeScript::Marchen => "Marchen",
+            UnicodeScript::MasaramGondi => "Masaram_Gondi",
+            UnicodeScript::Medefaidrin => "Medefaidrin",
+            UnicodeScript::MeeteiMayek => "Meetei_Mayek",
+            UnicodeScript::MendeKikakui => "Mende_Kikakui",
+            UnicodeScript::MeroiticCursive => "Meroitic_Cursive",
+            UnicodeScript::MeroiticHieroglyphs => "Meroitic_Hieroglyphs",
+            UnicodeScript::Miao => "Miao",
+            UnicodeScript::Modi => "Modi",
+            UnicodeScript::Mongolian => "Mongolian",
+            UnicodeScript::Mro => "Mro",
+            UnicodeScript::Multani => "Multani",
+            UnicodeScript::Myanmar => "Myanmar",
+            UnicodeScript::Nabataean => "Nabataean",
+            UnicodeScript::NagMundari => "Nag_Mundari",
+            UnicodeScript::Nandinagari => "Nandinagari",
+            UnicodeScript::NewTaiLue => "New_Tai_Lue",
+            UnicodeScript::Newa => "Newa",
+            UnicodeScript::Nko => "Nko",
+            UnicodeScript::Nushu => "Nushu",
+            UnicodeScript::NyiakengPuachueHmong => "Nyiakeng_Puachue_Hmong",
+            UnicodeScript::Ogham => "Ogham",
+            UnicodeScript::OlChiki => "Ol_Chiki",
+            UnicodeScript::OlOnal => "Ol_Onal",
+            UnicodeScript::OldHungarian => "Old_Hungarian",
+            UnicodeScript::OldItalic => "Old_Italic",
+            UnicodeScript::OldNorthArabian => "Old_North_Arabian",
+            UnicodeScript::OldPermic => "Old_Permic",
+            UnicodeScript::OldPersian => "Old_Persian",
+            UnicodeScript::OldSogdian => "Old_Sogdian",
+            UnicodeScript::OldSouthArabian => "Old_South_Arabian",
+            UnicodeScript::OldTurkic => "Old_Turkic",
+            UnicodeScript::OldUyghur => "Old_Uyghur",
+            UnicodeScript::Oriya => "Oriya",
+            UnicodeScript::Osage => "Osage",
+            UnicodeScript::Osmanya => "Osmanya",
+            UnicodeScript::PahawhHmong => "Pahawh_Hmong",
+            UnicodeScript::Palmyrene => "Palmyrene",
+            UnicodeScript::PauCinHau => "Pau_Cin_Hau",
+            UnicodeScript::PhagsPa => "Phags_Pa",
+            UnicodeScript::Phoenician => "Phoenician",
+            UnicodeScript::PsalterPahlavi => "Psalter_Pahlavi",
+            UnicodeScript::Rejang => "Rejang",
+            UnicodeScript::Runic => "Runic",
+            UnicodeScript::Samaritan => "Samaritan",
+            UnicodeScript::Saurashtra => "Saurashtra",
+            UnicodeScript::Sharada => "Sharada",
+            UnicodeScript::Shavian => "Shavian",
+            UnicodeScript::Siddham => "Siddham",
+            UnicodeScript::SignWriting => "SignWriting",
+            UnicodeScript::Sinhala => "Sinhala",
+            UnicodeScript::Sogdian => "Sogdian",
+            UnicodeScript::SoraSompeng => "Sora_Sompeng",
+            UnicodeScript::Soyombo => "Soyombo",
+            UnicodeScript::Sundanese => "Sundanese",
+            UnicodeScript::Sunuwar => "Sunuwar",
+            UnicodeScript::SylotiNagri => "Syloti_Nagri",
+            UnicodeScript::Syriac => "Syriac",
+            UnicodeScript::Tagalog => "Tagalog",
+            UnicodeScript::Tagbanwa => "Tagbanwa",
+            UnicodeScript::TaiLe => "Tai_Le",
+            UnicodeScript::TaiTham => "Tai_Tham",
+            UnicodeScript::TaiViet => "Tai_Viet",
+            UnicodeScript::Takri => "Takri",
+            UnicodeScript::Tamil => "Tamil",
+            UnicodeScript::Tangsa => "Tangsa",
+            UnicodeScript::Tangut => "Tangut",
+            UnicodeScript::Telugu => "Telugu",
+            UnicodeScript::Thaana => "Thaana",
+            UnicodeScript::Thai => "Thai",
+            UnicodeScript::Tibetan => "Tibetan",
+            UnicodeScript::Tifinagh => "Tifinagh",
+            UnicodeScript::Tirhuta => "Tirhuta",
+            UnicodeScript::Todhri => "Todhri",
+            UnicodeScript::Toto => "Toto",
+            UnicodeScript::TuluTigalari => "Tulu_Tigalari",
+            UnicodeScript::Ugaritic => "Ugaritic",
+            UnicodeScript::Vai => "Vai",
+            UnicodeScript::Vithkuqi => "Vithkuqi",
+            UnicodeScript::Wancho => "Wancho",
+            UnicodeScript::WarangCiti => "Warang_Citi",
+            UnicodeScript::Yezidi => "Yezidi",
+            UnicodeScript::Yi => "Yi",
+            UnicodeScript::ZanabazarSquare => "Zanabazar_Square",
+        }
+    }
 }
 
 /// A function for matching Unicode characters belonging to a certain script category. For matching other categories see [unicode_category].
@@ -313,53 +872,7 @@ pub enum UnicodeScript {
 /// ```
 pub fn unicode_script(category: UnicodeScript) -> HumanRegex<SymbolClass<Standard>> {
     HumanRegex(
-        match category {
-            UnicodeScript::Common => r"\p{Common}".to_string(),
-            UnicodeScript::Arabic => r"\p{Arabic}".to_string(),
-            UnicodeScript::Armenian => r"\p{Armenian}".to_string(),
-            UnicodeScript::Bengali => r"\p{Bengali}".to_string(),
-            UnicodeScript::Bopomofo => r"\p{Bopomofo}".to_string(),
-            UnicodeScript::Braille => r"\p{Braille}".to_string(),
-            UnicodeScript::Buhid => r"\p{Buhid}".to_string(),
-            UnicodeScript::CandianAboriginal => r"\p{CandianAboriginal}".to_string(),
-            UnicodeScript::Cherokee => r"\p{Cherokee}".to_string(),
-            UnicodeScript::Cyrillic => r"\p{Cyrillic}".to_string(),
-            UnicodeScript::Devanagari => r"\p{Devanagari}".to_string(),
-            UnicodeScript::Ethiopic => r"\p{Ethiopic}".to_string(),
-            UnicodeScript::Georgian => r"\p{Georgian}".to_string(),
-            UnicodeScript::Greek => r"\p{Greek}".to_string(),
-            UnicodeScript::Gujarati => r"\p{Gujarati}".to_string(),
-            UnicodeScript::Gurkmukhi => r"\p{Gurkmukhi}".to_string(),
-            UnicodeScript::Han => r"\p{Han}".to_string(),
-            UnicodeScript::Hangul => r"\p{Hangul}".to_string(),
-            UnicodeScript::Hanunoo => r"\p{Hanunoo}".to_string(),
-            UnicodeScript::Hebrew => r"\p{Hebrew}".to_string(),
-            UnicodeScript::Hirigana => r"\p{Hirigana}".to_string(),
-            UnicodeScript::Inherited => r"\p{Inherited}".to_string(),
-            UnicodeScript::Kannada => r"\p{Kannada}".to_string(),
-            UnicodeScript::Katakana => r"\p{Katakana}".to_string(),
-            UnicodeScript::Khmer => r"\p{Khmer}".to_string(),
-            UnicodeScript::Lao => r"\p{Lao}".to_string(),
-            UnicodeScript::Latin => r"\p{Latin}".to_string(),
-            UnicodeScript::Limbu => r"\p{Limbu}".to_string(),
-            UnicodeScript::Malayalam => r"\p{Malayalam}".to_string(),
-            UnicodeScript::Mongolian => r"\p{Mongolian}".to_string(),
-            UnicodeScript::Myanmar => r"\p{Myanmar}".to_string(),
-            UnicodeScript::Ogham => r"\p{Ogham}".to_string(),
-            UnicodeScript::Oriya => r"\p{Oriya}".to_string(),
-            UnicodeScript::Runic => r"\p{Runic}".to_string(),
-            UnicodeScript::Sinhala => r"\p{Sinhala}".to_string(),
-            UnicodeScript::Syriac => r"\p{Syriac}".to_string(),
-            UnicodeScript::Tagalog => r"\p{Tagalog}".to_string(),
-            UnicodeScript::Tagbanwa => r"\p{Tagbanwa}".to_string(),
-            UnicodeScript::TaiLe => r"\p{TaiLe}".to_string(),
-            UnicodeScript::Tamil => r"\p{Tamil}".to_string(),
-            UnicodeScript::Telugu => r"\p{Telugu}".to_string(),
-            UnicodeScript::Thaana => r"\p{Thaana}".to_string(),
-            UnicodeScript::Thai => r"\p{Thai}".to_string(),
-            UnicodeScript::Tibetan => r"\p{Tibetan}".to_string(),
-            UnicodeScript::Yi => r"\p{Yi}".to_string(),
-        },
+        format!(r"\p{{{}}}", category.name()),
         pd::<SymbolClass<Standard>>,
     )
 }
@@ -374,3 +887,273 @@ pub fn unicode_script(category: UnicodeScript) -> HumanRegex<SymbolClass<Standar
 pub fn non_unicode_script(category: UnicodeScript) -> HumanRegex<SymbolClass<Standard>> {
     !unicode_script(category)
 }
+
+/// A function for matching characters whose Script_Extensions property includes a script, which also covers characters
+/// shared between scripts, like the Devanagari danda used in Bengali text. For matching only the
+/// characters assigned to the script itself see [unicode_script].
+/// ```
+/// use human_regex::{beginning, end, one_or_more, unicode_script, unicode_script_extensions, UnicodeScript};
+/// let regex_string = beginning()
+///     + one_or_more(unicode_script_extensions(UnicodeScript::Bengali))
+///     + end();
+/// assert!(regex_string.to_regex().is_match("\u{0995}\u{0964}"));
+/// assert!(!unicode_script(UnicodeScript::Bengali).to_regex().is_match("\u{0964}"));
+/// ```
+pub fn unicode_script_extensions(script: UnicodeScript) -> HumanRegex<SymbolClass<Standard>> {
+    HumanRegex(
+        format!(r"\p{{scx={}}}", script.name()),
+        pd::<SymbolClass<Standard>>,
+    )
+}
+
+/// A function for matching characters whose Script_Extensions property does not include a script, see [unicode_script_extensions].
+/// ```
+/// use human_regex::{non_unicode_script_extensions, UnicodeScript};
+/// let regex_string = non_unicode_script_extensions(UnicodeScript::Bengali);
+/// assert!(regex_string.to_regex().is_match("a"));
+/// assert!(!regex_string.to_regex().is_match("\u{0964}"));
+/// ```
+pub fn non_unicode_script_extensions(script: UnicodeScript) -> HumanRegex<SymbolClass<Standard>> {
+    !unicode_script_extensions(script)
+}
+
+/// An enum covering the binary Unicode properties
+///
+/// Used in the [unicode_property] function. Indic_Conjunct_Break (InCB) is left out: the regex
+/// crate lists it among its properties but cannot resolve `\p{InCB}` yet.
+#[allow(missing_docs)] // variants are self documenting
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnicodeProperty {
+    AsciiHexDigit,
+    Alphabetic,
+    BidiControl,
+    BidiMirrored,
+    CaseIgnorable,
+    Cased,
+    ChangesWhenCasefolded,
+    ChangesWhenCasemapped,
+    ChangesWhenLowercased,
+    ChangesWhenTitlecased,
+    ChangesWhenUppercased,
+    Dash,
+    DefaultIgnorableCodePoint,
+    Deprecated,
+    Diacritic,
+    Emoji,
+    EmojiComponent,
+    EmojiModifier,
+    EmojiModifierBase,
+    EmojiPresentation,
+    ExtendedPictographic,
+    Extender,
+    GraphemeBase,
+    GraphemeExtend,
+    GraphemeLink,
+    HexDigit,
+    Hyphen,
+    IdsBinaryOperator,
+    IdsTrinaryOperator,
+    IdsUnaryOperator,
+    IdCompatMathContinue,
+    IdCompatMathStart,
+    IdContinue,
+    IdStart,
+    Ideographic,
+    JoinControl,
+    LogicalOrderException,
+    Lowercase,
+    Math,
+    ModifierCombiningMark,
+    NoncharacterCodePoint,
+    OtherAlphabetic,
+    OtherDefaultIgnorableCodePoint,
+    OtherGraphemeExtend,
+    OtherIdContinue,
+    OtherIdStart,
+    OtherLowercase,
+    OtherMath,
+    OtherUppercase,
+    PatternSyntax,
+    PatternWhiteSpace,
+    PrependedConcatenationMark,
+    QuotationMark,
+    Radical,
+    RegionalIndicator,
+    SentenceTerminal,
+    SoftDotted,
+    TerminalPunctuation,
+    UnifiedIdeograph,
+    Uppercase,
+    VariationSelector,
+    WhiteSpace,
+    XidContinue,
+    XidStart,
+}
+
+impl UnicodeProperty {
+    /// Every variant, in order of declaration
+    pub const ALL: [UnicodeProperty; 64] = [
+        UnicodeProperty::AsciiHexDigit,
+        UnicodeProperty::Alphabetic,
+        UnicodeProperty::BidiControl,
+        UnicodeProperty::BidiMirrored,
+        UnicodeProperty::CaseIgnorable,
+        UnicodeProperty::Cased,
+        UnicodeProperty::ChangesWhenCasefolded,
+        UnicodeProperty::ChangesWhenCasemapped,
+        UnicodeProperty::ChangesWhenLowercased,
+        UnicodeProperty::ChangesWhenTitlecased,
+        UnicodeProperty::ChangesWhenUppercased,
+        UnicodeProperty::Dash,
+        UnicodeProperty::DefaultIgnorableCodePoint,
+        UnicodeProperty::Deprecated,
+        UnicodeProperty::Diacritic,
+        UnicodeProperty::Emoji,
+        UnicodeProperty::EmojiComponent,
+        UnicodeProperty::EmojiModifier,
+        UnicodeProperty::EmojiModifierBase,
+        UnicodeProperty::EmojiPresentation,
+        UnicodeProperty::ExtendedPictographic,
+        UnicodeProperty::Extender,
+        UnicodeProperty::GraphemeBase,
+        UnicodeProperty::GraphemeExtend,
+        UnicodeProperty::GraphemeLink,
+        UnicodeProperty::HexDigit,
+        UnicodeProperty::Hyphen,
+        UnicodeProperty::IdsBinaryOperator,
+        UnicodeProperty::IdsTrinaryOperator,
+        UnicodeProperty::IdsUnaryOperator,
+        UnicodeProperty::IdCompatMathContinue,
+        UnicodeProperty::IdCompatMathStart,
+        UnicodeProperty::IdContinue,
+        UnicodeProperty::IdStart,
+        UnicodeProperty::Ideographic,
+        UnicodeProperty::JoinControl,
+        UnicodeProperty::LogicalOrderException,
+        UnicodeProperty::Lowercase,
+        UnicodeProperty::Math,
+        UnicodeProperty::ModifierCombiningMark,
+        UnicodeProperty::NoncharacterCodePoint,
+        UnicodeProperty::OtherAlphabetic,
+        UnicodeProperty::OtherDefaultIgnorableCodePoint,
+        UnicodeProperty::OtherGraphemeExtend,
+        UnicodeProperty::OtherIdContinue,
+        UnicodeProperty::OtherIdStart,
+        UnicodeProperty::OtherLowercase,
+        UnicodeProperty::OtherMath,
+        UnicodeProperty::OtherUppercase,
+        UnicodeProperty::PatternSyntax,
+        UnicodeProperty::PatternWhiteSpace,
+        UnicodeProperty::PrependedConcatenationMark,
+        UnicodeProperty::QuotationMark,
+        UnicodeProperty::Radical,
+        UnicodeProperty::RegionalIndicator,
+        UnicodeProperty::SentenceTerminal,
+        UnicodeProperty::SoftDotted,
+        UnicodeProperty::TerminalPunctuation,
+        UnicodeProperty::UnifiedIdeograph,
+        UnicodeProperty::Uppercase,
+        UnicodeProperty::VariationSelector,
+        UnicodeProperty::WhiteSpace,
+        UnicodeProperty::XidContinue,
+        UnicodeProperty::XidStart,
+    ];
+
+    /// The name of the property in the Unicode character database
+    fn name(self) -> &'static str {
+        match self {
+            UnicodeProperty::AsciiHexDigit => "ASCII_Hex_Digit",
+            UnicodeProperty::Alphabetic => "Alphabetic",
+            UnicodeProperty::BidiControl => "Bidi_Control",
+            UnicodeProperty::BidiMirrored => "Bidi_Mirrored",
+            UnicodeProperty::CaseIgnorable => "Case_Ignorable",
+            UnicodeProperty::Cased => "Cased",
+            UnicodeProperty::ChangesWhenCasefolded => "Changes_When_Casefolded",
+            UnicodeProperty::ChangesWhenCasemapped => "Changes_When_Casemapped",
+            UnicodeProperty::ChangesWhenLowercased => "Changes_When_Lowercased",
+            UnicodeProperty::ChangesWhenTitlecased => "Changes_When_Titlecased",
+            UnicodeProperty::ChangesWhenUppercased => "Changes_When_Uppercased",
+            UnicodeProperty::Dash => "Dash",
+            UnicodeProperty::DefaultIgnorableCodePoint => "Default_Ignorable_Code_Point",
+            UnicodeProperty::Deprecated => "Deprecated",
+            UnicodeProperty::Diacritic => "Diacritic",
+            UnicodeProperty::Emoji => "Emoji",
+            UnicodeProperty::EmojiComponent => "Emoji_Component",
+            UnicodeProperty::EmojiModifier => "Emoji_Modifier",
+            UnicodeProperty::EmojiModifierBase => "Emoji_Modifier_Base",
+            UnicodeProperty::EmojiPresentation => "Emoji_Presentation",
+            UnicodeProperty::ExtendedPictographic => "Extended_Pictographic",
+            UnicodeProperty::Extender => "Extender",
+            UnicodeProperty::GraphemeBase => "Grapheme_Base",
+            UnicodeProperty::GraphemeExtend => "Grapheme_Extend",
+            UnicodeProperty::GraphemeLink => "Grapheme_Link",
+            UnicodeProperty::HexDigit => "Hex_Digit",
+            UnicodeProperty::Hyphen => "Hyphen",
+            UnicodeProperty::IdsBinaryOperator => "IDS_Binary_Operator",
+            UnicodeProperty::IdsTrinaryOperator => "IDS_Trinary_Operator",
+            UnicodeProperty::IdsUnaryOperator => "IDS_Unary_Operator",
+            UnicodeProperty::IdCompatMathContinue => "ID_Compat_Math_Continue",
+            UnicodeProperty::IdCompatMathStart => "ID_Compat_Math_Start",
+            UnicodeProperty::IdContinue => "ID_Continue",
+            UnicodeProperty::IdStart => "ID_Start",
+            UnicodeProperty::Ideographic => "Ideographic",
+            UnicodeProperty::JoinControl => "Join_Control",
+            UnicodeProperty::LogicalOrderException => "Logical_Order_Exception",
+            UnicodeProperty::Lowercase => "Lowercase",
+            UnicodeProperty::Math => "Math",
+            UnicodeProperty::ModifierCombiningMark => "Modifier_Combining_Mark",
+            UnicodeProperty::NoncharacterCodePoint => "Noncharacter_Code_Point",
+            UnicodeProperty::OtherAlphabetic => "Other_Alphabetic",
+            UnicodeProperty::OtherDefaultIgnorableCodePoint => "Other_Default_Ignorable_Code_Point",
+            UnicodeProperty::OtherGraphemeExtend => "Other_Grapheme_Extend",
+            UnicodeProperty::OtherIdContinue => "Other_ID_Continue",
+            UnicodeProperty::OtherIdStart => "Other_ID_Start",
+            UnicodeProperty::OtherLowercase => "Other_Lowercase",
+            UnicodeProperty::OtherMath => "Other_Math",
+            UnicodeProperty::OtherUppercase => "Other_Uppercase",
+            UnicodeProperty::PatternSyntax => "Pattern_Syntax",
+            UnicodeProperty::PatternWhiteSpace => "Pattern_White_Space",
+            UnicodeProperty::PrependedConcatenationMark => "Prepended_Concatenation_Mark",
+            UnicodeProperty::QuotationMark => "Quotation_Mark",
+            UnicodeProperty::Radical => "Radical",
+            UnicodeProperty::RegionalIndicator => "Regional_Indicator",
+            UnicodeProperty::SentenceTerminal => "Sentence_Terminal",
+            UnicodeProperty::SoftDotted => "Soft_Dotted",
+            UnicodeProperty::TerminalPunctuation => "Terminal_Punctuation",
+            UnicodeProperty::UnifiedIdeograph => "Unified_Ideograph",
+            UnicodeProperty::Uppercase => "Uppercase",
+            UnicodeProperty::VariationSelector => "Variation_Selector",
+            UnicodeProperty::WhiteSpace => "White_Space",
+            UnicodeProperty::XidContinue => "XID_Continue",
+            UnicodeProperty::XidStart => "XID_Start",
+        }
+    }
+}
+
+/// A function for matching characters that have a binary Unicode property
+/// ```
+/// use human_regex::{beginning, end, one_or_more, unicode_property, UnicodeProperty};
+/// let regex_string = beginning()
+///     + one_or_more(unicode_property(UnicodeProperty::Emoji))
+///     + end();
+/// assert!(regex_string.to_regex().is_match("\u{1F600}\u{1F980}"));
+/// assert!(!regex_string.to_regex().is_match("smile"));
+/// assert!(unicode_property(UnicodeProperty::WhiteSpace).to_regex().is_match("\u{3000}"));
+/// ```
+pub fn unicode_property(property: UnicodeProperty) -> HumanRegex<SymbolClass<Standard>> {
+    HumanRegex(
+        format!(r"\p{{{}}}", property.name()),
+        pd::<SymbolClass<Standard>>,
+    )
+}
+
+/// A function for matching characters that don't have a binary Unicode property
+/// ```
+/// use human_regex::{non_unicode_property, UnicodeProperty};
+/// let regex_string = non_unicode_property(UnicodeProperty::Ideographic);
+/// assert!(regex_string.to_regex().is_match("a"));
+/// assert!(!regex_string.to_regex().is_match("\u{8C4A}"));
+/// ```
+pub fn non_unicode_property(property: UnicodeProperty) -> HumanRegex<SymbolClass<Standard>> {
+    !unicode_property(property)
+}
@@ -0,0 +1,192 @@
+//! Helpers for inspecting the structure of a regex string, shared by the modules that render, analyze or convert patterns
+
+use regex_syntax::ast::{self, Ast};
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+/// Parse a regex string into its abstract syntax tree
+pub(crate) fn parse(pattern: &str) -> Result<Ast, String> {
+    ast::parse::Parser::new()
+        .parse(pattern)
+        .map_err(|error| error.to_string())
+}
+
+/// Parse a regex string into its abstract syntax tree, keeping the comments of verbose sections
+pub(crate) fn parse_with_comments(pattern: &str) -> Result<ast::WithComments, String> {
+    ast::parse::Parser::new()
+        .parse_with_comments(pattern)
+        .map_err(|error| error.to_string())
+}
+
+/// Return the part of the regex string covered by a span
+pub(crate) fn source<'p>(pattern: &'p str, span: &ast::Span) -> &'p str {
+    &pattern[span.start.offset..span.end.offset]
+}
+
+/// The state of every flag at some point in a regex string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct FlagState {
+    /// `i`
+    pub(crate) case_insensitive: bool,
+    /// `m`
+    pub(crate) multi_line: bool,
+    /// `s`
+    pub(crate) dot_matches_new_line: bool,
+    /// `R`
+    pub(crate) crlf: bool,
+    /// `U`
+    pub(crate) swap_greed: bool,
+    /// `u`
+    pub(crate) unicode: bool,
+}
+
+impl Default for FlagState {
+    fn default() -> Self {
+        FlagState {
+            case_insensitive: false,
+            multi_line: false,
+            dot_matches_new_line: false,
+            crlf: false,
+            swap_greed: false,
+            unicode: true,
+        }
+    }
+}
+
+impl FlagState {
+    /// Return the state after a group or standalone flags like `(?i-u)` take effect
+    pub(crate) fn apply(&self, flags: &ast::Flags) -> FlagState {
+        let mut state = *self;
+        let mut enable = true;
+        for item in &flags.items {
+            match item.kind {
+                ast::FlagsItemKind::Negation => enable = false,
+                ast::FlagsItemKind::Flag(ast::Flag::CaseInsensitive) => {
+                    state.case_insensitive = enable
+                }
+                ast::FlagsItemKind::Flag(ast::Flag::MultiLine) => state.multi_line = enable,
+                ast::FlagsItemKind::Flag(ast::Flag::DotMatchesNewLine) => {
+                    state.dot_matches_new_line = enable
+                }
+                ast::FlagsItemKind::Flag(ast::Flag::CRLF) => state.crlf = enable,
+                ast::FlagsItemKind::Flag(ast::Flag::SwapGreed) => state.swap_greed = enable,
+                ast::FlagsItemKind::Flag(ast::Flag::Unicode) => state.unicode = enable,
+                ast::FlagsItemKind::Flag(ast::Flag::IgnoreWhitespace) => {}
+            }
+        }
+        state
+    }
+
+    /// Return whether a repetition is greedy once `U` is taken into account
+    pub(crate) fn greedy(&self, repetition: &ast::Repetition) -> bool {
+        repetition.greedy != self.swap_greed
+    }
+
+    /// Translate a piece of a regex string into its high-level form, as if it appeared where these flags are in effect
+    pub(crate) fn translate(&self, source: &str) -> Option<Hir> {
+        regex_syntax::ParserBuilder::new()
+            .case_insensitive(self.case_insensitive)
+            .multi_line(self.multi_line)
+            .dot_matches_new_line(self.dot_matches_new_line)
+            .crlf(self.crlf)
+            .swap_greed(self.swap_greed)
+            .unicode(self.unicode)
+            .build()
+            .parse(source)
+            .ok()
+    }
+
+    /// Return the ranges of characters matched by a character class, as if it appeared where these flags are in effect
+    pub(crate) fn class_ranges(&self, source: &str) -> Option<Vec<(char, char)>> {
+        match self.translate(source)?.kind() {
+            HirKind::Class(Class::Unicode(class)) => Some(
+                class
+                    .ranges()
+                    .iter()
+                    .map(|range| (range.start(), range.end()))
+                    .collect(),
+            ),
+            // Classes without Unicode, and classes that can never match, are translated into byte classes
+            HirKind::Class(Class::Bytes(class)) if class.is_ascii() => Some(
+                class
+                    .ranges()
+                    .iter()
+                    .map(|range| (range.start() as char, range.end() as char))
+                    .collect(),
+            ),
+            HirKind::Literal(literal) => {
+                let chr = std::str::from_utf8(&literal.0).ok()?.chars().next()?;
+                Some(vec![(chr, chr)])
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Return the POSIX name of an ASCII class
+pub(crate) fn ascii_name(kind: &ast::ClassAsciiKind) -> &'static str {
+    use ast::ClassAsciiKind::*;
+    match kind {
+        Alnum => "alnum",
+        Alpha => "alpha",
+        Ascii => "ascii",
+        Blank => "blank",
+        Cntrl => "cntrl",
+        Digit => "digit",
+        Graph => "graph",
+        Lower => "lower",
+        Print => "print",
+        Punct => "punct",
+        Space => "space",
+        Upper => "upper",
+        Word => "word",
+        Xdigit => "xdigit",
+    }
+}
+
+/// Return the letter of a flag in regex syntax
+pub(crate) fn flag_letter(flag: ast::Flag) -> char {
+    match flag {
+        ast::Flag::CaseInsensitive => 'i',
+        ast::Flag::MultiLine => 'm',
+        ast::Flag::DotMatchesNewLine => 's',
+        ast::Flag::SwapGreed => 'U',
+        ast::Flag::Unicode => 'u',
+        ast::Flag::CRLF => 'R',
+        ast::Flag::IgnoreWhitespace => 'x',
+    }
+}
+
+/// Return the ranges of characters *not* in a sorted list of ranges
+pub(crate) fn complement(ranges: &[(char, char)]) -> Vec<(char, char)> {
+    let mut result = Vec::new();
+    let mut next = Some('\0');
+    for &(start, end) in ranges {
+        if let Some(from) = next {
+            if from < start {
+                result.push((from, previous_char(start)));
+            }
+        }
+        next = next_char(end);
+    }
+    if let Some(from) = next {
+        result.push((from, char::MAX));
+    }
+    result
+}
+
+/// Return the character after a character, skipping surrogates
+pub(crate) fn next_char(chr: char) -> Option<char> {
+    match chr {
+        '\u{D7FF}' => Some('\u{E000}'),
+        char::MAX => None,
+        chr => char::from_u32(chr as u32 + 1),
+    }
+}
+
+/// Return the character before a character, skipping surrogates
+pub(crate) fn previous_char(chr: char) -> char {
+    match chr {
+        '\u{E000}' => '\u{D7FF}',
+        chr => char::from_u32(chr as u32 - 1).unwrap_or(chr),
+    }
+}
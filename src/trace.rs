@@ -0,0 +1,835 @@
+//! Functions for following a match step by step
+//!
+//! [HumanRegex::trace] runs a pattern against a haystack with a simple backtracking matcher and records
+//! every step it takes: which literal, class or assertion is tried at each position, which iteration of
+//! a repetition and which branch of an [or](crate::or) is active, when the matcher backtracks and where the
+//! final match and captures are decided. The steps are available as a list of [TraceEvent]s and as a
+//! human-readable log, which makes the difference between a greedy repetition and a
+//! [lazy](HumanRegex::lazy) one easy to see:
+//! ```
+//! use human_regex::{any, one_or_more, text};
+//! let greedy = text("<") + one_or_more(any()) + text(">");
+//! let lazy = text("<") + one_or_more(any()).lazy() + text(">");
+//! let haystack = "<b>hi</b>";
+//! assert_eq!(greedy.trace(haystack).found, Some(0..9));
+//! assert_eq!(lazy.trace(haystack).found, Some(0..3));
+//! println!("{}", lazy.trace(haystack));
+//! ```
+//! The matcher follows the same leftmost-first rules as [regex](https://docs.rs/regex), so it finds the
+//! same match, but it is meant for teaching and debugging: it is much slower and gives up after
+//! [MAX_TRACE_EVENTS] steps.
+
+use super::humanregex::*;
+use super::syntax::{self, FlagState};
+use super::verbose::{atom_of, Description};
+use regex_syntax::ast::{self, Ast};
+use regex_syntax::hir::{Class, HirKind};
+use std::ops::Range;
+
+/// The number of events after which a trace stops, to keep catastrophic backtracking from running forever
+pub const MAX_TRACE_EVENTS: usize = 100_000;
+
+/// The kind of builder node that a [TraceNode] stands for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceNodeKind {
+    /// Literal text, like [text](crate::text)
+    Literal,
+    /// A character class, like [digit](crate::digit) or [within_set](crate::within_set)
+    Class,
+    /// A zero-width assertion, like [beginning](crate::beginning) or [word_boundary](crate::word_boundary)
+    Assertion,
+    /// A repetition, like [one_or_more](crate::one_or_more) or [between](crate::between)
+    Repetition,
+    /// A choice between branches, like [or](crate::or)
+    Alternation,
+    /// A capture group, like [capture](crate::capture) or [named_capture](crate::named_capture)
+    Capture,
+}
+
+/// A part of the pattern that events refer to
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceNode {
+    /// What kind of node this is
+    pub kind: TraceNodeKind,
+    /// A description of the node, like "one or more digits"
+    pub description: String,
+    /// The part of the regex string the node was built from
+    pub source: String,
+    /// How deeply the node is nested in groups and repetitions
+    pub depth: usize,
+}
+
+/// What happened in a step of a trace
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceAction {
+    /// A new match attempt starts at this position
+    Attempt,
+    /// A literal or class matched the haystack from this position up to `end`
+    Consumed {
+        /// The position after the matched text
+        end: usize,
+    },
+    /// A class or assertion did not match at this position, or a literal did not match the character here
+    Rejected,
+    /// An assertion holds at this position
+    Passed,
+    /// A repetition starts its iteration with this number, counting from 1
+    Iteration(u32),
+    /// A repetition stops after this many iterations
+    Stopped(u32),
+    /// An iteration with this number of an unbounded repetition matched nothing, so it is given up like a
+    /// failed match, because repeating it could never end
+    EmptyIteration(u32),
+    /// An alternation tries the branch with this number, counting from 1
+    Branch(usize),
+    /// A capture group starts at this position
+    CaptureOpened,
+    /// A capture group ends at this position, having started at `start`
+    CaptureClosed {
+        /// The position where the group started
+        start: usize,
+    },
+    /// The matcher returns to an earlier choice at this position, after everything since then failed
+    Backtrack,
+    /// The whole pattern matched, from `start` up to this position
+    Match {
+        /// The position where the match started
+        start: usize,
+    },
+}
+
+/// A step of a trace
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceEvent {
+    /// The position in the haystack, in bytes
+    pub position: usize,
+    /// The index of the node in [Trace::nodes] that the step concerns, if any
+    pub node: Option<usize>,
+    /// What happened
+    pub action: TraceAction,
+}
+
+/// The record of a match, as returned by [HumanRegex::trace]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Trace {
+    /// The haystack that was searched
+    pub haystack: String,
+    /// The parts of the pattern that events refer to
+    pub nodes: Vec<TraceNode>,
+    /// Every step taken, in order
+    pub events: Vec<TraceEvent>,
+    /// The range of the match, if there was one
+    pub found: Option<Range<usize>>,
+    /// The range of each capture group in the match, with the whole match at index 0
+    pub captures: Vec<Option<Range<usize>>>,
+    /// Whether the trace stopped after [MAX_TRACE_EVENTS] events, before finding out whether there is a match
+    pub truncated: bool,
+}
+
+impl<T> HumanRegex<T> {
+    /// Search the haystack step by step, recording how the match proceeds
+    /// ```
+    /// use human_regex::{digit, named_capture, one_or_more, text, TraceAction};
+    /// let regex_string = text("v") + named_capture(one_or_more(digit()), "major");
+    /// let trace = regex_string.trace("v12");
+    /// assert_eq!(trace.found, Some(0..3));
+    /// assert_eq!(trace.captures[1], Some(1..3));
+    /// let iterations = trace
+    ///     .events
+    ///     .iter()
+    ///     .filter(|event| matches!(event.action, TraceAction::Iteration(_)))
+    ///     .count();
+    /// assert_eq!(iterations, 3);
+    /// assert!(trace.log().contains(r#"one or more digits: stop after 2 iterations"#));
+    /// ```
+    pub fn trace(&self, haystack: &str) -> Trace {
+        let ast = syntax::parse(&self.0).expect("the pattern should be a valid regex");
+        let mut compiler = Compiler {
+            pattern: &self.0,
+            nodes: Vec::new(),
+            program: Vec::new(),
+            repetitions: 0,
+            groups: 0,
+        };
+        compiler.compile(&ast, FlagState::default(), 0);
+        compiler.program.push(Instruction::Match);
+        let mut matcher = Matcher {
+            program: &compiler.program,
+            haystack,
+            events: Vec::new(),
+        };
+        let mut found = None;
+        let mut captures = vec![None; compiler.groups + 1];
+        let mut truncated = false;
+        let starts = haystack
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain([haystack.len()]);
+        for start in starts {
+            let thread = Thread {
+                pc: 0,
+                position: start,
+                slots: vec![None; 2 * (compiler.groups + 1)],
+                counters: vec![(0, start); compiler.repetitions],
+            };
+            match matcher.attempt(thread) {
+                Outcome::Match(slots) => {
+                    found = Some(start..matcher.events.last().map_or(start, |e| e.position));
+                    captures = slots
+                        .chunks(2)
+                        .map(|slot| match slot {
+                            [Some(start), Some(end)] => Some(*start..*end),
+                            _ => None,
+                        })
+                        .collect();
+                    captures[0] = found.clone();
+                    break;
+                }
+                Outcome::NoMatch => {}
+                Outcome::Truncated => {
+                    truncated = true;
+                    break;
+                }
+            }
+        }
+        Trace {
+            haystack: haystack.to_string(),
+            nodes: compiler.nodes,
+            events: matcher.events,
+            found,
+            captures,
+            truncated,
+        }
+    }
+}
+
+impl Trace {
+    /// Describe the trace as a human-readable log, with one line per event indented by nesting depth
+    /// ```
+    /// use human_regex::{or, text};
+    /// let log = or(&[text("cat"), text("car")]).trace("car").log();
+    /// assert_eq!(
+    ///     log,
+    ///     r#"trying a match at 0
+    ///     0  one of 2 branches: trying branch 1
+    ///     2    "cat" did not match "r"
+    ///     0  backtrack
+    ///     0  one of 2 branches: trying branch 2
+    ///     0    "car" matched "car"
+    ///     3  match found: "car" at 0..3
+    /// "#
+    /// );
+    /// ```
+    pub fn log(&self) -> String {
+        let mut log = String::new();
+        for event in &self.events {
+            let node = event.node.map(|idx| &self.nodes[idx]);
+            let description = node.map_or("", |node| node.description.as_str());
+            let rest = &self.haystack[event.position..];
+            let line = match event.action {
+                TraceAction::Attempt => {
+                    log.push_str(&format!("trying a match at {}\n", event.position));
+                    continue;
+                }
+                TraceAction::Consumed { end } => format!(
+                    "{} matched {:?}",
+                    description,
+                    &self.haystack[event.position..end]
+                ),
+                TraceAction::Rejected => match rest.chars().next() {
+                    Some(chr) => format!("{} did not match {:?}", description, chr.to_string()),
+                    None => format!("{} did not match at the end", description),
+                },
+                TraceAction::Passed => format!("{} holds", description),
+                TraceAction::Iteration(count) => format!("{}: iteration {}", description, count),
+                TraceAction::Stopped(1) => format!("{}: stop after 1 iteration", description),
+                TraceAction::Stopped(count) => {
+                    format!("{}: stop after {} iterations", description, count)
+                }
+                TraceAction::EmptyIteration(count) => {
+                    format!("{}: iteration {} matched nothing", description, count)
+                }
+                TraceAction::Branch(branch) => format!("{}: trying branch {}", description, branch),
+                TraceAction::CaptureOpened => format!("{} starts", description),
+                TraceAction::CaptureClosed { start } => format!(
+                    "{} ends, capturing {:?}",
+                    description,
+                    &self.haystack[start..event.position]
+                ),
+                TraceAction::Backtrack => "backtrack".to_string(),
+                TraceAction::Match { start } => format!(
+                    "match found: {:?} at {}..{}",
+                    &self.haystack[start..event.position],
+                    start,
+                    event.position
+                ),
+            };
+            let depth = match event.action {
+                TraceAction::Backtrack | TraceAction::Match { .. } => 0,
+                _ => node.map_or(0, |node| node.depth),
+            };
+            log.push_str(&format!(
+                "{:>5}  {}{}\n",
+                event.position,
+                "  ".repeat(depth),
+                line
+            ));
+        }
+        if self.truncated {
+            log.push_str(&format!("gave up after {} events\n", self.events.len()));
+        } else if self.found.is_none() {
+            log.push_str("no match\n");
+        }
+        log
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.log())
+    }
+}
+
+/// A step of the compiled program that a [Matcher] runs
+enum Instruction {
+    /// Match a sequence of characters, each from its own set of ranges
+    Chars {
+        /// The node being matched
+        node: usize,
+        /// The ranges allowed for each character in turn
+        chars: Vec<Vec<(char, char)>>,
+    },
+    /// Check a zero-width assertion
+    Assert {
+        /// The node being checked
+        node: usize,
+        /// The kind of assertion
+        kind: ast::AssertionKind,
+        /// The flags in effect for the assertion
+        state: FlagState,
+    },
+    /// Try each target in order until one leads to a match
+    Branch {
+        /// The alternation node
+        node: usize,
+        /// The start of each branch
+        targets: Vec<usize>,
+    },
+    /// Continue at another instruction
+    Jump(usize),
+    /// Record the start of a capture group
+    Open {
+        /// The capture node
+        node: usize,
+        /// The index of the group
+        group: usize,
+    },
+    /// Record the end of a capture group
+    Close {
+        /// The capture node
+        node: usize,
+        /// The index of the group
+        group: usize,
+    },
+    /// Reset the iteration count of a repetition
+    Reset(usize),
+    /// Decide whether a repetition iterates again, with its body following this instruction
+    Repeat {
+        /// The repetition node
+        node: usize,
+        /// The index of the repetition's counter
+        counter: usize,
+        /// The least number of iterations
+        min: u32,
+        /// The greatest number of iterations, if any
+        max: Option<u32>,
+        /// Whether to prefer iterating again over stopping
+        greedy: bool,
+        /// The instruction after the repetition
+        exit: usize,
+    },
+    /// Report a match
+    Match,
+}
+
+/// Turns the syntax tree of a pattern into a program for the [Matcher]
+struct Compiler<'p> {
+    /// The regex string, used to translate atoms and to show where nodes come from
+    pattern: &'p str,
+    /// The nodes that events refer to
+    nodes: Vec<TraceNode>,
+    /// The instructions compiled so far
+    program: Vec<Instruction>,
+    /// The number of repetition counters
+    repetitions: usize,
+    /// The highest capture group index
+    groups: usize,
+}
+
+impl Compiler<'_> {
+    /// Add a node and return its index
+    fn node(
+        &mut self,
+        kind: TraceNodeKind,
+        description: String,
+        span: &ast::Span,
+        depth: usize,
+    ) -> usize {
+        self.nodes.push(TraceNode {
+            kind,
+            description,
+            source: syntax::source(self.pattern, span).to_string(),
+            depth,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Compile a piece of the syntax tree with the flags in effect around it
+    fn compile(&mut self, node: &Ast, state: FlagState, depth: usize) {
+        match node {
+            Ast::Empty(_) | Ast::Flags(_) => {}
+            Ast::Concat(concat) => {
+                let mut state = state;
+                let mut idx = 0;
+                while idx < concat.asts.len() {
+                    let node = &concat.asts[idx];
+                    if let Ast::Literal(_) = node {
+                        let first = idx;
+                        while let Some(Ast::Literal(_)) = concat.asts.get(idx) {
+                            idx += 1;
+                        }
+                        self.literals(&concat.asts[first..idx], state, depth);
+                        continue;
+                    }
+                    if let Ast::Flags(flags) = node {
+                        state = state.apply(&flags.flags);
+                    }
+                    self.compile(node, state, depth);
+                    idx += 1;
+                }
+            }
+            Ast::Literal(_) => self.literals(std::slice::from_ref(node), state, depth),
+            Ast::Alternation(alternation) => {
+                let node = self.node(
+                    TraceNodeKind::Alternation,
+                    format!("one of {} branches", alternation.asts.len()),
+                    &alternation.span,
+                    depth,
+                );
+                let branch = self.program.len();
+                self.program.push(Instruction::Jump(0));
+                let mut targets = Vec::new();
+                let mut jumps = Vec::new();
+                for ast in &alternation.asts {
+                    targets.push(self.program.len());
+                    self.compile(ast, state, depth + 1);
+                    jumps.push(self.program.len());
+                    self.program.push(Instruction::Jump(0));
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Instruction::Jump(end);
+                }
+                self.program[branch] = Instruction::Branch { node, targets };
+            }
+            Ast::Group(group) => match &group.kind {
+                ast::GroupKind::NonCapturing(flags) => {
+                    self.compile(&group.ast, state.apply(flags), depth)
+                }
+                kind => {
+                    let (index, description) = match kind {
+                        ast::GroupKind::CaptureName { name, .. } => {
+                            (name.index as usize, format!("named group {:?}", name.name))
+                        }
+                        ast::GroupKind::CaptureIndex(index) => {
+                            (*index as usize, format!("group {}", index))
+                        }
+                        ast::GroupKind::NonCapturing(_) => unreachable!(),
+                    };
+                    self.groups = self.groups.max(index);
+                    let node = self.node(TraceNodeKind::Capture, description, &group.span, depth);
+                    self.program.push(Instruction::Open { node, group: index });
+                    self.compile(&group.ast, state, depth + 1);
+                    self.program.push(Instruction::Close { node, group: index });
+                }
+            },
+            Ast::Repetition(repetition) => {
+                use ast::RepetitionKind::*;
+                use ast::RepetitionRange::*;
+                let greedy = state.greedy(repetition);
+                let description =
+                    describe_repeated(&repetition.ast, state).repeated(&repetition.op.kind, greedy);
+                let node = self.node(
+                    TraceNodeKind::Repetition,
+                    description,
+                    &repetition.span,
+                    depth,
+                );
+                let (min, max) = match repetition.op.kind {
+                    ZeroOrOne => (0, Some(1)),
+                    ZeroOrMore => (0, None),
+                    OneOrMore => (1, None),
+                    Range(Exactly(n)) => (n, Some(n)),
+                    Range(AtLeast(n)) => (n, None),
+                    Range(Bounded(n, m)) => (n, Some(m)),
+                };
+                let counter = self.repetitions;
+                self.repetitions += 1;
+                self.program.push(Instruction::Reset(counter));
+                let check = self.program.len();
+                self.program.push(Instruction::Jump(0));
+                self.compile(&repetition.ast, state, depth + 1);
+                self.program.push(Instruction::Jump(check));
+                self.program[check] = Instruction::Repeat {
+                    node,
+                    counter,
+                    min,
+                    max,
+                    greedy,
+                    exit: self.program.len(),
+                };
+            }
+            Ast::Assertion(assertion) => {
+                let description = atom_of(node, state).1.alone();
+                let node = self.node(
+                    TraceNodeKind::Assertion,
+                    description,
+                    &assertion.span,
+                    depth,
+                );
+                self.program.push(Instruction::Assert {
+                    node,
+                    kind: assertion.kind.clone(),
+                    state,
+                });
+            }
+            atom => {
+                let span = atom.span();
+                let description = atom_of(atom, state).1.alone();
+                let node = self.node(TraceNodeKind::Class, description, span, depth);
+                let chars = vec![self.ranges(span, state)];
+                self.program.push(Instruction::Chars { node, chars });
+            }
+        }
+    }
+
+    /// Compile a run of literals into a single node
+    fn literals(&mut self, literals: &[Ast], state: FlagState, depth: usize) {
+        let text: String = literals
+            .iter()
+            .filter_map(|node| match node {
+                Ast::Literal(literal) => Some(literal.c),
+                _ => None,
+            })
+            .collect();
+        let span = ast::Span::new(
+            literals[0].span().start,
+            literals[literals.len() - 1].span().end,
+        );
+        let chars = literals
+            .iter()
+            .map(|node| self.ranges(node.span(), state))
+            .collect();
+        let node = self.node(TraceNodeKind::Literal, format!("{:?}", text), &span, depth);
+        self.program.push(Instruction::Chars { node, chars });
+    }
+
+    /// Return the characters an atom matches, with case-insensitivity and other flags applied
+    fn ranges(&self, span: &ast::Span, state: FlagState) -> Vec<(char, char)> {
+        let hir = state
+            .translate(syntax::source(self.pattern, span))
+            .expect("an atom of a valid regex should translate");
+        match hir.kind() {
+            HirKind::Literal(literal) => String::from_utf8_lossy(&literal.0)
+                .chars()
+                .map(|chr| (chr, chr))
+                .collect(),
+            HirKind::Class(Class::Unicode(class)) => class
+                .ranges()
+                .iter()
+                .map(|range| (range.start(), range.end()))
+                .collect(),
+            HirKind::Class(Class::Bytes(class)) => class
+                .ranges()
+                .iter()
+                .filter(|range| range.start().is_ascii())
+                .map(|range| (range.start() as char, range.end().min(0x7F) as char))
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
+
+/// Describe the thing a repetition repeats
+fn describe_repeated(node: &Ast, state: FlagState) -> Description {
+    match node {
+        Ast::Group(group) => match &group.kind {
+            ast::GroupKind::NonCapturing(flags) if flags.items.is_empty() => {
+                describe_repeated(&group.ast, state)
+            }
+            _ => Description::Phrase("group".to_string()),
+        },
+        Ast::Concat(concat)
+            if concat
+                .asts
+                .iter()
+                .all(|node| matches!(node, Ast::Literal(_))) =>
+        {
+            let text: String = concat
+                .asts
+                .iter()
+                .filter_map(|node| match node {
+                    Ast::Literal(literal) => Some(literal.c),
+                    _ => None,
+                })
+                .collect();
+            Description::Phrase(format!("{:?}", text))
+        }
+        Ast::Literal(_)
+        | Ast::Dot(_)
+        | Ast::ClassPerl(_)
+        | Ast::ClassUnicode(_)
+        | Ast::ClassBracketed(_)
+        | Ast::Assertion(_) => atom_of(node, state).1,
+        _ => Description::Phrase("group".to_string()),
+    }
+}
+
+/// The state of the matcher at one point of an attempt
+#[derive(Clone)]
+struct Thread {
+    /// The instruction to run next
+    pc: usize,
+    /// The position in the haystack
+    position: usize,
+    /// The start and end of each capture group so far
+    slots: Vec<Option<usize>>,
+    /// The iteration count of each repetition, with the position where its last iteration started
+    counters: Vec<(u32, usize)>,
+}
+
+/// How an attempt ended
+enum Outcome {
+    /// The pattern matched, with these capture slots
+    Match(Vec<Option<usize>>),
+    /// Every choice failed
+    NoMatch,
+    /// The trace grew too long
+    Truncated,
+}
+
+/// A backtracking matcher that records events as it goes
+struct Matcher<'a> {
+    /// The compiled pattern
+    program: &'a [Instruction],
+    /// The text being searched
+    haystack: &'a str,
+    /// The events recorded so far
+    events: Vec<TraceEvent>,
+}
+
+impl Matcher<'_> {
+    /// Record an event
+    fn event(&mut self, position: usize, node: Option<usize>, action: TraceAction) {
+        self.events.push(TraceEvent {
+            position,
+            node,
+            action,
+        });
+    }
+
+    /// Try to match starting at the position of the thread
+    fn attempt(&mut self, mut thread: Thread) -> Outcome {
+        let start = thread.position;
+        self.event(start, None, TraceAction::Attempt);
+        // Choices to come back to, with the event to record when resuming each of them
+        let mut stack: Vec<(Thread, TraceEvent)> = Vec::new();
+        loop {
+            if self.events.len() >= MAX_TRACE_EVENTS {
+                return Outcome::Truncated;
+            }
+            let position = thread.position;
+            let failed = match &self.program[thread.pc] {
+                Instruction::Chars { node, chars } => match self.consume(position, chars) {
+                    Ok(end) => {
+                        self.event(position, Some(*node), TraceAction::Consumed { end });
+                        thread.position = end;
+                        thread.pc += 1;
+                        false
+                    }
+                    Err(mismatch) => {
+                        self.event(mismatch, Some(*node), TraceAction::Rejected);
+                        true
+                    }
+                },
+                Instruction::Assert { node, kind, state } => {
+                    if self.holds(position, kind, *state) {
+                        self.event(position, Some(*node), TraceAction::Passed);
+                        thread.pc += 1;
+                        false
+                    } else {
+                        self.event(position, Some(*node), TraceAction::Rejected);
+                        true
+                    }
+                }
+                Instruction::Branch { node, targets } => {
+                    for (idx, target) in targets.iter().enumerate().skip(1).rev() {
+                        let mut alternative = thread.clone();
+                        alternative.pc = *target;
+                        let event = TraceEvent {
+                            position,
+                            node: Some(*node),
+                            action: TraceAction::Branch(idx + 1),
+                        };
+                        stack.push((alternative, event));
+                    }
+                    self.event(position, Some(*node), TraceAction::Branch(1));
+                    thread.pc = targets[0];
+                    false
+                }
+                Instruction::Jump(target) => {
+                    thread.pc = *target;
+                    false
+                }
+                Instruction::Open { node, group } => {
+                    thread.slots[2 * group] = Some(position);
+                    self.event(position, Some(*node), TraceAction::CaptureOpened);
+                    thread.pc += 1;
+                    false
+                }
+                Instruction::Close { node, group } => {
+                    thread.slots[2 * group + 1] = Some(position);
+                    let start = thread.slots[2 * group].unwrap_or(position);
+                    self.event(position, Some(*node), TraceAction::CaptureClosed { start });
+                    thread.pc += 1;
+                    false
+                }
+                Instruction::Reset(counter) => {
+                    thread.counters[*counter] = (0, position);
+                    thread.pc += 1;
+                    false
+                }
+                Instruction::Repeat {
+                    node,
+                    counter,
+                    min,
+                    max,
+                    greedy,
+                    exit,
+                } => {
+                    let (count, last) = thread.counters[*counter];
+                    // Like regex, give up an extra iteration of an unbounded loop that matched nothing,
+                    // since repeating it could never end
+                    if max.is_none() && count > (*min).max(1) && last == position {
+                        let action = TraceAction::EmptyIteration(count);
+                        self.event(position, Some(*node), action);
+                        true
+                    } else {
+                        let mut iterate = thread.clone();
+                        iterate.counters[*counter] = (count + 1, position);
+                        iterate.pc += 1;
+                        let mut stop = thread.clone();
+                        stop.pc = *exit;
+                        let may_iterate = max.is_none_or(|max| count < max);
+                        let iteration = TraceAction::Iteration(count + 1);
+                        let stopped = TraceAction::Stopped(count);
+                        let (next, action) = if count < *min {
+                            (iterate, iteration)
+                        } else if !may_iterate {
+                            (stop, stopped)
+                        } else if *greedy {
+                            let event = TraceEvent {
+                                position,
+                                node: Some(*node),
+                                action: stopped,
+                            };
+                            stack.push((stop, event));
+                            (iterate, iteration)
+                        } else {
+                            let event = TraceEvent {
+                                position,
+                                node: Some(*node),
+                                action: iteration,
+                            };
+                            stack.push((iterate, event));
+                            (stop, stopped)
+                        };
+                        self.event(position, Some(*node), action);
+                        thread = next;
+                        false
+                    }
+                }
+                Instruction::Match => {
+                    self.event(position, None, TraceAction::Match { start });
+                    return Outcome::Match(thread.slots);
+                }
+            };
+            if failed {
+                match stack.pop() {
+                    Some((resumed, event)) => {
+                        self.event(event.position, None, TraceAction::Backtrack);
+                        self.events.push(event);
+                        thread = resumed;
+                    }
+                    None => return Outcome::NoMatch,
+                }
+            }
+        }
+    }
+
+    /// Match a sequence of characters at a position, returning the position after them or the position of the first
+    /// character that does not match
+    fn consume(&self, position: usize, chars: &[Vec<(char, char)>]) -> Result<usize, usize> {
+        let mut end = position;
+        let mut rest = self.haystack[position..].chars();
+        for ranges in chars {
+            match rest.next() {
+                Some(chr)
+                    if ranges
+                        .iter()
+                        .any(|(start, end)| *start <= chr && chr <= *end) =>
+                {
+                    end += chr.len_utf8()
+                }
+                _ => return Err(end),
+            }
+        }
+        Ok(end)
+    }
+
+    /// Check whether an assertion holds at a position
+    fn holds(&self, position: usize, kind: &ast::AssertionKind, state: FlagState) -> bool {
+        use ast::AssertionKind::*;
+        let before = self.haystack[..position].chars().next_back();
+        let after = self.haystack[position..].chars().next();
+        let word = |chr: Option<char>| match chr {
+            Some(chr) if state.unicode => regex_syntax::is_word_character(chr),
+            Some(chr) => chr.is_ascii_alphanumeric() || chr == '_',
+            None => false,
+        };
+        match kind {
+            StartText => before.is_none(),
+            EndText => after.is_none(),
+            StartLine if !state.multi_line => before.is_none(),
+            EndLine if !state.multi_line => after.is_none(),
+            StartLine if state.crlf => match before {
+                None | Some('\n') => true,
+                Some('\r') => after != Some('\n'),
+                _ => false,
+            },
+            EndLine if state.crlf => match after {
+                None | Some('\r') => true,
+                Some('\n') => before != Some('\r'),
+                _ => false,
+            },
+            StartLine => matches!(before, None | Some('\n')),
+            EndLine => matches!(after, None | Some('\n')),
+            WordBoundary => word(before) != word(after),
+            NotWordBoundary => word(before) == word(after),
+            WordBoundaryStart | WordBoundaryStartAngle => !word(before) && word(after),
+            WordBoundaryEnd | WordBoundaryEndAngle => word(before) && !word(after),
+            WordBoundaryStartHalf => !word(before),
+            WordBoundaryEndHalf => !word(after),
+        }
+    }
+}
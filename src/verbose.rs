@@ -0,0 +1,724 @@
+//! Functions for rendering self-documenting regex strings
+//!
+//! [HumanRegex::to_verbose_string] lays a pattern out in ignore-whitespace mode, with one construct per
+//! line, indentation by nesting depth and a `#` comment explaining each line. The result is still a
+//! regex string that matches exactly what the original does, so it can be pasted into configuration
+//! files and read by people who do not use this crate. [HumanRegex::comment] attaches your own
+//! explanation to any part of a pattern.
+
+use super::direct::escape_whitespace;
+use super::humanregex::*;
+use super::syntax::{self, ascii_name, FlagState};
+use regex_syntax::ast::{self, Ast};
+use std::marker::PhantomData as pd;
+
+impl<T: State> HumanRegex<T> {
+    /// Attach a comment to this part of the pattern, to be shown by [to_verbose_string](HumanRegex::to_verbose_string).
+    /// The comment does not change what the pattern matches.
+    /// ```
+    /// use human_regex::{exactly, digit, text};
+    /// let regex_string = exactly(4, digit()).comment("the year") + text("-");
+    /// assert!(regex_string.to_regex().is_match("2024-"));
+    /// assert_eq!(
+    ///     regex_string.to_verbose_string(),
+    ///     "(?x)\n\\d{4}  # the year\n-      # \"-\""
+    /// );
+    /// ```
+    pub fn comment(self, comment: &str) -> HumanRegex<T::Chain> {
+        HumanRegex(
+            format!(
+                "(?:(?x:#{}\n)(?:{}))",
+                comment.replace(['\n', '\r'], " "),
+                self.0
+            ),
+            pd::<T::Chain>,
+        )
+    }
+}
+
+impl<T> HumanRegex<T> {
+    /// Render the pattern as a commented regex string in ignore-whitespace mode
+    /// ```
+    /// use human_regex::{beginning, digit, end, exactly, named_capture, text};
+    /// let regex_string = beginning()
+    ///     + named_capture(exactly(4, digit()), "year")
+    ///     + text("-")
+    ///     + named_capture(exactly(2, digit()), "month")
+    ///     + end();
+    /// let verbose = regex_string.to_verbose_string();
+    /// assert_eq!(
+    ///     verbose,
+    ///     r#"(?x)
+    /// ^           # start of the text
+    /// (?P<year>   # named group "year"
+    ///   \d{4}     # exactly 4 digits
+    /// )
+    /// -           # "-"
+    /// (?P<month>  # named group "month"
+    ///   \d{2}     # exactly 2 digits
+    /// )
+    /// $           # end of the text"#
+    /// );
+    /// assert!(regex::Regex::new(&verbose).unwrap().is_match("2024-02"));
+    /// ```
+    pub fn to_verbose_string(&self) -> String {
+        let parsed = match syntax::parse_with_comments(&self.0) {
+            Ok(parsed) => parsed,
+            Err(_) => return self.0.clone(),
+        };
+        let renderer = Renderer {
+            comments: parsed.comments,
+        };
+        let lines = renderer.render(&parsed.ast, 0, FlagState::default());
+        let column = lines
+            .iter()
+            .filter(|line| line.comment.is_some() && !line.code.is_empty())
+            .map(|line| line.width())
+            .filter(|width| *width <= MAX_COLUMN)
+            .max()
+            .unwrap_or(0);
+        let mut verbose = "(?x)".to_string();
+        for line in lines {
+            verbose.push('\n');
+            verbose.push_str(&"  ".repeat(line.depth));
+            verbose.push_str(&line.code);
+            let width = line.width();
+            if let Some(comment) = line.comment {
+                if !line.code.is_empty() {
+                    let padding = column.saturating_sub(width) + 2;
+                    verbose.push_str(&" ".repeat(padding));
+                }
+                verbose.push_str("# ");
+                verbose.push_str(&comment);
+            }
+        }
+        verbose
+    }
+}
+
+/// Lines longer than this are not used to align comments
+const MAX_COLUMN: usize = 40;
+
+/// One line of verbose output
+struct Line {
+    /// The nesting depth, shown as indentation
+    depth: usize,
+    /// The regex syntax on the line
+    code: String,
+    /// The explanation shown after the code
+    comment: Option<String>,
+}
+
+impl Line {
+    /// Build a line
+    fn new(depth: usize, code: String, comment: Option<String>) -> Self {
+        Line {
+            depth,
+            code,
+            comment,
+        }
+    }
+
+    /// Return the width of the indented code
+    fn width(&self) -> usize {
+        self.depth * 2 + self.code.chars().count()
+    }
+}
+
+/// How a single construct is explained
+pub(crate) enum Description {
+    /// A kind of character, with its singular and plural forms, like "digit" and "digits"
+    Noun(String, String),
+    /// Anything else, like `"abc"` or "word boundary"
+    Phrase(String),
+}
+
+impl Description {
+    /// Build a noun that is pluralized by adding an "s"
+    fn noun(singular: &str) -> Self {
+        Description::Noun(singular.to_string(), format!("{}s", singular))
+    }
+
+    /// Explain the construct on its own
+    pub(crate) fn alone(&self) -> String {
+        match self {
+            Description::Noun(singular, _) => with_article(singular),
+            Description::Phrase(phrase) => phrase.clone(),
+        }
+    }
+
+    /// Explain the construct repeated by an operator
+    pub(crate) fn repeated(&self, op: &ast::RepetitionKind, greedy: bool) -> String {
+        use ast::RepetitionKind::*;
+        use ast::RepetitionRange::*;
+        let described = match (self, op) {
+            (Description::Noun(singular, _), ZeroOrOne) => {
+                format!("optionally {}", with_article(singular))
+            }
+            (Description::Noun(singular, _), Range(Exactly(1))) => {
+                format!("exactly 1 {}", singular)
+            }
+            (Description::Noun(_, plural), op) => format!("{} {}", quantity(op), plural),
+            (Description::Phrase(phrase), ZeroOrOne) => format!("{}, optional", phrase),
+            (Description::Phrase(phrase), op) => format!("{}, {} times", phrase, quantity(op)),
+        };
+        if greedy {
+            described
+        } else {
+            format!("{}, as few as possible", described)
+        }
+    }
+}
+
+/// Describe how many times a repetition operator matches
+fn quantity(op: &ast::RepetitionKind) -> String {
+    use ast::RepetitionKind::*;
+    use ast::RepetitionRange::*;
+    match op {
+        ZeroOrOne => "zero or one".to_string(),
+        ZeroOrMore => "zero or more".to_string(),
+        OneOrMore => "one or more".to_string(),
+        Range(Exactly(n)) => format!("exactly {}", n),
+        Range(AtLeast(n)) => format!("at least {}", n),
+        Range(Bounded(n, m)) => format!("between {} and {}", n, m),
+    }
+}
+
+/// Put "a" or "an" in front of a noun
+fn with_article(noun: &str) -> String {
+    let vowel = noun
+        .chars()
+        .next()
+        .is_some_and(|chr| "aeiouAEIOU".contains(chr));
+    format!("{} {}", if vowel { "an" } else { "a" }, noun)
+}
+
+/// Walks the syntax tree and produces the lines of verbose output
+struct Renderer {
+    /// The comments found in verbose sections of the pattern, including those added by [HumanRegex::comment]
+    comments: Vec<ast::Comment>,
+}
+
+impl Renderer {
+    /// Render a node of the syntax tree with the flags in effect around it
+    fn render(&self, node: &Ast, depth: usize, state: FlagState) -> Vec<Line> {
+        match node {
+            Ast::Empty(_) => vec![],
+            Ast::Flags(flags) => match flag_syntax(&flags.flags) {
+                Some(code) => vec![Line::new(
+                    depth,
+                    format!("(?{})", code),
+                    Some(format!("{} from here on", describe_flags(&flags.flags))),
+                )],
+                None => vec![],
+            },
+            Ast::Alternation(alternation) => {
+                // Branches are indented one level past the `|` lines, which at the top level needs an extra level
+                let depth = depth.max(1);
+                let mut lines = Vec::new();
+                for (idx, branch) in alternation.asts.iter().enumerate() {
+                    if idx > 0 {
+                        lines.push(Line::new(
+                            depth - 1,
+                            "|".to_string(),
+                            Some("or".to_string()),
+                        ));
+                    }
+                    lines.extend(self.render(branch, depth, state));
+                }
+                lines
+            }
+            Ast::Concat(concat) => self.concat(&concat.asts, depth, state),
+            Ast::Repetition(repetition) => self.repetition(repetition, depth, state),
+            Ast::Group(group) => match self.comment_of(group) {
+                Some(comment) => vec![Line::new(depth, String::new(), Some(comment))],
+                None => match &group.kind {
+                    ast::GroupKind::NonCapturing(flags)
+                        if flag_syntax(flags).is_none() && !needs_group(&group.ast) =>
+                    {
+                        self.render(&group.ast, depth, state.apply(flags))
+                    }
+                    _ => self.group(group, depth, state, String::new(), None),
+                },
+            },
+            atom => {
+                let (code, description) = atom_of(atom, state);
+                vec![Line::new(depth, code, Some(description.alone()))]
+            }
+        }
+    }
+
+    /// Render a sequence of nodes, merging runs of literals and attaching comments to what follows them
+    fn concat(&self, nodes: &[Ast], depth: usize, mut state: FlagState) -> Vec<Line> {
+        let mut lines = Vec::new();
+        let mut pending: Option<String> = None;
+        let mut idx = 0;
+        while idx < nodes.len() {
+            let mut rendered = match &nodes[idx] {
+                Ast::Literal(_) => {
+                    let mut text = String::new();
+                    while let Some(Ast::Literal(literal)) = nodes.get(idx) {
+                        text.push(literal.c);
+                        idx += 1;
+                    }
+                    vec![Line::new(
+                        depth,
+                        verbose_literal(&text),
+                        Some(format!("{:?}", text)),
+                    )]
+                }
+                Ast::Group(group) if self.comment_of(group).is_some() => {
+                    idx += 1;
+                    if let Some(comment) =
+                        pending.replace(self.comment_of(group).unwrap_or_default())
+                    {
+                        lines.push(Line::new(depth, String::new(), Some(comment)));
+                    }
+                    continue;
+                }
+                node => {
+                    if let Ast::Flags(flags) = node {
+                        state = state.apply(&flags.flags);
+                    }
+                    idx += 1;
+                    self.render(node, depth, state)
+                }
+            };
+            if rendered.is_empty() {
+                continue;
+            }
+            if let Some(comment) = pending.take() {
+                if rendered.len() == 1 {
+                    rendered[0].comment = Some(comment);
+                } else {
+                    lines.push(Line::new(depth, String::new(), Some(comment)));
+                }
+            }
+            lines.extend(rendered);
+        }
+        if let Some(comment) = pending {
+            lines.push(Line::new(depth, String::new(), Some(comment)));
+        }
+        lines
+    }
+
+    /// Render a repetition, on one line when it repeats a single construct
+    fn repetition(
+        &self,
+        repetition: &ast::Repetition,
+        depth: usize,
+        state: FlagState,
+    ) -> Vec<Line> {
+        let operator = repetition_operator(repetition);
+        let greedy = state.greedy(repetition);
+        let mut child = &*repetition.ast;
+        while let Ast::Group(group) = child {
+            match &group.kind {
+                ast::GroupKind::NonCapturing(flags) if flags.items.is_empty() => child = &group.ast,
+                _ => break,
+            }
+        }
+        match child {
+            Ast::Literal(_)
+            | Ast::Dot(_)
+            | Ast::ClassPerl(_)
+            | Ast::ClassUnicode(_)
+            | Ast::ClassBracketed(_)
+            | Ast::Assertion(_) => {
+                let (code, description) = atom_of(child, state);
+                vec![Line::new(
+                    depth,
+                    format!("{}{}", code, operator),
+                    Some(description.repeated(&repetition.op.kind, greedy)),
+                )]
+            }
+            Ast::Concat(concat)
+                if concat
+                    .asts
+                    .iter()
+                    .all(|node| matches!(node, Ast::Literal(_))) =>
+            {
+                let text: String = concat
+                    .asts
+                    .iter()
+                    .filter_map(|node| match node {
+                        Ast::Literal(literal) => Some(literal.c),
+                        _ => None,
+                    })
+                    .collect();
+                let description = Description::Phrase(format!("{:?}", text));
+                vec![Line::new(
+                    depth,
+                    format!("(?:{}){}", verbose_literal(&text), operator),
+                    Some(description.repeated(&repetition.op.kind, greedy)),
+                )]
+            }
+            Ast::Group(group) if self.comment_of(group).is_none() => self.group(
+                group,
+                depth,
+                state,
+                operator,
+                Some((&repetition.op.kind, greedy)),
+            ),
+            child => {
+                let description = Description::Phrase("group".to_string());
+                let mut lines = vec![Line::new(
+                    depth,
+                    "(?:".to_string(),
+                    Some(description.repeated(&repetition.op.kind, greedy)),
+                )];
+                lines.extend(self.render(child, depth + 1, state));
+                lines.push(Line::new(depth, format!("){}", operator), None));
+                lines
+            }
+        }
+    }
+
+    /// Render a group over several lines, with an optional repetition operator after it
+    fn group(
+        &self,
+        group: &ast::Group,
+        depth: usize,
+        state: FlagState,
+        operator: String,
+        repetition: Option<(&ast::RepetitionKind, bool)>,
+    ) -> Vec<Line> {
+        let (opening, description, inner) = match &group.kind {
+            ast::GroupKind::CaptureIndex(index) => {
+                ("(".to_string(), format!("group {}", index), state)
+            }
+            ast::GroupKind::CaptureName { name, .. } => (
+                format!("(?P<{}>", name.name),
+                format!("named group {:?}", name.name),
+                state,
+            ),
+            ast::GroupKind::NonCapturing(flags) => match flag_syntax(flags) {
+                Some(code) => (
+                    format!("(?{}:", code),
+                    format!("{} group", describe_flags(flags)),
+                    state.apply(flags),
+                ),
+                None => ("(?:".to_string(), "group".to_string(), state.apply(flags)),
+            },
+        };
+        let description = match repetition {
+            Some((op, greedy)) => Description::Phrase(description).repeated(op, greedy),
+            None => description,
+        };
+        let mut lines = vec![Line::new(depth, opening, Some(description))];
+        lines.extend(self.render(&group.ast, depth + 1, inner));
+        lines.push(Line::new(depth, format!("){}", operator), None));
+        lines
+    }
+
+    /// Return the text of a group made by [HumanRegex::comment], if it is one
+    fn comment_of(&self, group: &ast::Group) -> Option<String> {
+        comment_of(group, &self.comments)
+    }
+}
+
+/// Return the text of a group made by [HumanRegex::comment], if it is one, from the comments of the pattern
+pub(crate) fn comment_of(group: &ast::Group, comments: &[ast::Comment]) -> Option<String> {
+    let ast::GroupKind::NonCapturing(flags) = &group.kind else {
+        return None;
+    };
+    let only_verbose = flags.items.len() == 1
+        && flags.items[0].kind == ast::FlagsItemKind::Flag(ast::Flag::IgnoreWhitespace);
+    if !only_verbose || !matches!(&*group.ast, Ast::Empty(_)) {
+        return None;
+    }
+    comments
+        .iter()
+        .find(|comment| {
+            group.span.start.offset <= comment.span.start.offset
+                && comment.span.end.offset <= group.span.end.offset
+        })
+        .map(|comment| comment.comment.trim().to_string())
+}
+
+/// Return the syntax of a repetition operator, including the laziness marker
+fn repetition_operator(repetition: &ast::Repetition) -> String {
+    use ast::RepetitionKind::*;
+    use ast::RepetitionRange::*;
+    let operator = match &repetition.op.kind {
+        ZeroOrOne => "?".to_string(),
+        ZeroOrMore => "*".to_string(),
+        OneOrMore => "+".to_string(),
+        Range(Exactly(n)) => format!("{{{}}}", n),
+        Range(AtLeast(n)) => format!("{{{},}}", n),
+        Range(Bounded(n, m)) => format!("{{{},{}}}", n, m),
+    };
+    if repetition.greedy {
+        operator
+    } else {
+        format!("{}?", operator)
+    }
+}
+
+/// Return the syntax and description of a single construct
+pub(crate) fn atom_of(node: &Ast, state: FlagState) -> (String, Description) {
+    let ascii = if state.unicode { "" } else { "ASCII " };
+    match node {
+        Ast::Literal(literal) => {
+            let text = literal.c.to_string();
+            (
+                verbose_literal(&text),
+                Description::Phrase(format!("{:?}", text)),
+            )
+        }
+        Ast::Dot(_) if state.dot_matches_new_line => {
+            (".".to_string(), Description::noun("character"))
+        }
+        Ast::Dot(_) => (
+            ".".to_string(),
+            Description::Noun(
+                "character except newline".to_string(),
+                "characters except newline".to_string(),
+            ),
+        ),
+        Ast::ClassPerl(class) => {
+            let noun = match class.kind {
+                ast::ClassPerlKind::Digit => "digit",
+                ast::ClassPerlKind::Space => "whitespace character",
+                ast::ClassPerlKind::Word => "word character",
+            };
+            let negated = if class.negated { "non-" } else { "" };
+            let code = match (&class.kind, class.negated) {
+                (ast::ClassPerlKind::Digit, false) => r"\d",
+                (ast::ClassPerlKind::Digit, true) => r"\D",
+                (ast::ClassPerlKind::Space, false) => r"\s",
+                (ast::ClassPerlKind::Space, true) => r"\S",
+                (ast::ClassPerlKind::Word, false) => r"\w",
+                (ast::ClassPerlKind::Word, true) => r"\W",
+            };
+            (
+                code.to_string(),
+                Description::noun(&format!("{}{}{}", ascii, negated, noun)),
+            )
+        }
+        Ast::ClassUnicode(class) => {
+            let name = match &class.kind {
+                ast::ClassUnicodeKind::OneLetter(letter) => letter.to_string(),
+                ast::ClassUnicodeKind::Named(name) => name.clone(),
+                ast::ClassUnicodeKind::NamedValue { name, value, op } => match op {
+                    ast::ClassUnicodeOpKind::NotEqual => format!("{}!={}", name, value),
+                    _ => format!("{}={}", name, value),
+                },
+            };
+            let relation = if class.negated { "not in" } else { "in" };
+            let code = format!(r"\{}{{{}}}", if class.negated { "P" } else { "p" }, name);
+            (
+                code,
+                Description::Noun(
+                    format!("character {} {}", relation, name),
+                    format!("characters {} {}", relation, name),
+                ),
+            )
+        }
+        Ast::ClassBracketed(class) => {
+            let relation = if class.negated { "other than" } else { "from" };
+            let listed = match &class.kind {
+                ast::ClassSet::BinaryOp(_) => "a combined set".to_string(),
+                ast::ClassSet::Item(item) => describe_item(item),
+            };
+            (
+                bracketed(class),
+                Description::Noun(
+                    format!("character {} {}", relation, listed),
+                    format!("characters {} {}", relation, listed),
+                ),
+            )
+        }
+        Ast::Assertion(assertion) => {
+            use ast::AssertionKind::*;
+            let (code, phrase) = match assertion.kind {
+                StartLine if state.multi_line => ("^", "start of a line"),
+                StartLine => ("^", "start of the text"),
+                EndLine if state.multi_line => ("$", "end of a line"),
+                EndLine => ("$", "end of the text"),
+                StartText => (r"\A", "start of the text"),
+                EndText => (r"\z", "end of the text"),
+                WordBoundary => (r"\b", "word boundary"),
+                NotWordBoundary => (r"\B", "not a word boundary"),
+                WordBoundaryStart => (r"\b{start}", "start of a word"),
+                WordBoundaryEnd => (r"\b{end}", "end of a word"),
+                WordBoundaryStartAngle => (r"\<", "start of a word"),
+                WordBoundaryEndAngle => (r"\>", "end of a word"),
+                WordBoundaryStartHalf => (r"\b{start-half}", "not after a word character"),
+                WordBoundaryEndHalf => (r"\b{end-half}", "not before a word character"),
+            };
+            let phrase = match assertion.kind {
+                StartLine | EndLine | StartText | EndText => phrase.to_string(),
+                _ => format!("{}{}", ascii, phrase),
+            };
+            (code.to_string(), Description::Phrase(phrase))
+        }
+        _ => (String::new(), Description::Phrase(String::new())),
+    }
+}
+
+/// Render a bracketed class so that it survives ignore-whitespace mode
+fn bracketed(class: &ast::ClassBracketed) -> String {
+    format!(
+        "[{}{}]",
+        if class.negated { "^" } else { "" },
+        class_set(&class.kind)
+    )
+}
+
+/// Render the inside of a bracketed class
+fn class_set(set: &ast::ClassSet) -> String {
+    match set {
+        ast::ClassSet::Item(item) => class_item(item),
+        ast::ClassSet::BinaryOp(op) => {
+            let operator = match op.kind {
+                ast::ClassSetBinaryOpKind::Intersection => "&&",
+                ast::ClassSetBinaryOpKind::Difference => "--",
+                ast::ClassSetBinaryOpKind::SymmetricDifference => "~~",
+            };
+            format!("{}{}{}", class_set(&op.lhs), operator, class_set(&op.rhs))
+        }
+    }
+}
+
+/// Render one item of a bracketed class
+fn class_item(item: &ast::ClassSetItem) -> String {
+    match item {
+        ast::ClassSetItem::Empty(_) => String::new(),
+        ast::ClassSetItem::Literal(literal) => class_literal(literal.c),
+        ast::ClassSetItem::Range(range) => format!(
+            "{}-{}",
+            class_literal(range.start.c),
+            class_literal(range.end.c)
+        ),
+        ast::ClassSetItem::Ascii(class) => format!(
+            "[:{}{}:]",
+            if class.negated { "^" } else { "" },
+            ascii_name(&class.kind)
+        ),
+        ast::ClassSetItem::Unicode(class) => {
+            atom_of(
+                &Ast::ClassUnicode(Box::new(class.clone())),
+                FlagState::default(),
+            )
+            .0
+        }
+        ast::ClassSetItem::Perl(class) => {
+            atom_of(
+                &Ast::ClassPerl(Box::new(class.clone())),
+                FlagState::default(),
+            )
+            .0
+        }
+        ast::ClassSetItem::Bracketed(class) => bracketed(class),
+        ast::ClassSetItem::Union(union) => union.items.iter().map(class_item).collect(),
+    }
+}
+
+/// Describe the items of a bracketed class, like `a-z, 0-9, _`
+fn describe_item(item: &ast::ClassSetItem) -> String {
+    match item {
+        ast::ClassSetItem::Empty(_) => "nothing".to_string(),
+        ast::ClassSetItem::Literal(literal) => describe_char(literal.c),
+        ast::ClassSetItem::Range(range) => format!(
+            "{}-{}",
+            describe_char(range.start.c),
+            describe_char(range.end.c)
+        ),
+        ast::ClassSetItem::Union(union) => union
+            .items
+            .iter()
+            .map(describe_item)
+            .collect::<Vec<String>>()
+            .join(", "),
+        item => class_item(item),
+    }
+}
+
+/// Describe a character listed in a class
+fn describe_char(chr: char) -> String {
+    match chr {
+        ' ' => "space".to_string(),
+        chr if chr.is_whitespace() || chr.is_control() => format!("{:?}", chr),
+        chr => chr.to_string(),
+    }
+}
+
+/// Escape a character inside of a class so that it survives ignore-whitespace mode
+fn class_literal(chr: char) -> String {
+    match chr {
+        '\\' | '[' | ']' | '^' | '-' | '&' | '~' | '#' => format!(r"\{}", chr),
+        chr if chr.is_whitespace() => escape_whitespace(&chr.to_string()),
+        chr if chr.is_control() => format!(r"\x{{{:X}}}", chr as u32),
+        chr => chr.to_string(),
+    }
+}
+
+/// Escape text outside of a class so that it survives ignore-whitespace mode
+fn verbose_literal(text: &str) -> String {
+    text.chars()
+        .map(|chr| match chr {
+            chr if chr.is_control() && !chr.is_whitespace() => format!(r"\x{{{:X}}}", chr as u32),
+            // These only need escaping inside of a class
+            '-' | '&' | '~' => chr.to_string(),
+            chr => escape_whitespace(&regex_syntax::escape(&chr.to_string())),
+        })
+        .collect()
+}
+
+/// Return whether the contents of a group change meaning without it, because an alternation would
+/// take in its neighbours or standalone flags like `(?i)` would apply after the group
+fn needs_group(node: &Ast) -> bool {
+    let standalone_flags =
+        |node: &Ast| matches!(node, Ast::Flags(flags) if flag_syntax(&flags.flags).is_some());
+    match node {
+        Ast::Alternation(_) => true,
+        Ast::Concat(concat) => concat.asts.iter().any(standalone_flags),
+        node => standalone_flags(node),
+    }
+}
+
+/// Return the syntax of a set of flags without `x`, or nothing if no other flags remain
+fn flag_syntax(flags: &ast::Flags) -> Option<String> {
+    let mut code = String::new();
+    for item in &flags.items {
+        match item.kind {
+            ast::FlagsItemKind::Negation => code.push('-'),
+            ast::FlagsItemKind::Flag(ast::Flag::IgnoreWhitespace) => {}
+            ast::FlagsItemKind::Flag(flag) => code.push(syntax::flag_letter(flag)),
+        }
+    }
+    let code = code.trim_end_matches('-').to_string();
+    if code.is_empty() {
+        None
+    } else {
+        Some(code)
+    }
+}
+
+/// Describe a set of flags, like "case-insensitive, not Unicode"
+pub(crate) fn describe_flags(flags: &ast::Flags) -> String {
+    let mut enable = true;
+    let mut described = Vec::new();
+    for item in &flags.items {
+        let name = match item.kind {
+            ast::FlagsItemKind::Negation => {
+                enable = false;
+                continue;
+            }
+            ast::FlagsItemKind::Flag(ast::Flag::CaseInsensitive) => "case-insensitive",
+            ast::FlagsItemKind::Flag(ast::Flag::MultiLine) => "multi-line",
+            ast::FlagsItemKind::Flag(ast::Flag::DotMatchesNewLine) => "dot matches newline",
+            ast::FlagsItemKind::Flag(ast::Flag::SwapGreed) => "swapped greed",
+            ast::FlagsItemKind::Flag(ast::Flag::Unicode) => "Unicode",
+            ast::FlagsItemKind::Flag(ast::Flag::CRLF) => "CRLF",
+            ast::FlagsItemKind::Flag(ast::Flag::IgnoreWhitespace) => continue,
+        };
+        described.push(if enable {
+            name.to_string()
+        } else {
+            format!("not {}", name)
+        });
+    }
+    described.join(", ")
+}
@@ -0,0 +1,523 @@
+//! Functions for matching any of a long list of words
+//!
+//! A regex built from thousands of alternatives takes a long time and a lot of memory to compile, and
+//! can go over the size limit, as every word becomes part of the automaton the regex crate builds.
+//! [one_of_words] builds such an alternation, and [HumanRegex::to_matcher] compiles any pattern
+//! that is only an alternation of literal words, with or without [case_insensitive](crate::case_insensitive)
+//! or a [word_boundary](crate::word_boundary) on each side, into an
+//! [Aho-Corasick](https://docs.rs/aho-corasick) automaton instead, which handles hundreds of
+//! thousands of words. Other patterns are compiled into a regex, behind the same API.
+//!
+//! ```
+//! use human_regex::{one_of_words, WordOptions};
+//! let words: Vec<String> = (0..20_000).map(|idx| format!("word{}", idx)).collect();
+//! let options = WordOptions { whole_word: true, ..Default::default() };
+//! let matcher = one_of_words(&words, &options).to_matcher();
+//! assert!(matcher.uses_automaton());
+//! assert_eq!(matcher.replace_all("word7 and word19999x", "_"), "_ and word19999x");
+//! ```
+
+use super::humanregex::*;
+use super::options::successive_matches;
+use super::syntax::{self, FlagState};
+use aho_corasick::{AhoCorasick, Input, MatchKind};
+use regex::Regex;
+use regex_automata::Match;
+use regex_syntax::ast::{self, Ast};
+use std::borrow::Cow;
+use std::marker::PhantomData as pd;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// How the words given to [one_of_words] are matched
+/// ```
+/// use human_regex::{one_of_words, WordOptions};
+/// let options = WordOptions { case_insensitive: true, longest_first: true, ..Default::default() };
+/// let matcher = one_of_words(&["in", "int", "integer"], &options).to_matcher();
+/// assert_eq!(matcher.find("AN INTEGER").unwrap().range(), 3..10);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WordOptions {
+    /// Whether words only match on their own, with a [word_boundary](crate::word_boundary) on each side
+    pub whole_word: bool,
+    /// Whether words match regardless of case
+    pub case_insensitive: bool,
+    /// Whether longer words are preferred to shorter ones starting at the same place, rather than the
+    /// word that comes first in the list
+    pub longest_first: bool,
+}
+
+/// A compiled pattern that uses an Aho-Corasick automaton when the pattern is only an alternation of
+/// literal words, and a regex otherwise, see [HumanRegex::to_matcher]
+#[derive(Clone, Debug)]
+pub struct Matcher {
+    /// The engine doing the searching
+    engine: Engine,
+}
+
+/// A match found by a [Matcher], with the part of the haystack it covers
+/// ```
+/// use human_regex::{one_of_words, WordOptions};
+/// let matcher = one_of_words(&["cat", "dog"], &WordOptions::default()).to_matcher();
+/// let found = matcher.find("hotdog").unwrap();
+/// assert_eq!((found.as_str(), found.range()), ("dog", 3..6));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WordMatch<'h> {
+    /// The haystack the match was found in
+    haystack: &'h str,
+    /// Where the match starts, as a byte offset into the haystack
+    start: usize,
+    /// Where the match ends, as a byte offset into the haystack
+    end: usize,
+}
+
+impl<'h> WordMatch<'h> {
+    /// Return where the match starts, as a byte offset into the haystack
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Return where the match ends, as a byte offset into the haystack
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    /// Return the byte range of the match in the haystack
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+
+    /// Return whether the match is empty
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Return the length of the match in bytes
+    pub fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Return the part of the haystack the match covers
+    pub fn as_str(&self) -> &'h str {
+        &self.haystack[self.range()]
+    }
+}
+
+/// The engine behind a [Matcher]
+#[derive(Clone, Debug)]
+enum Engine {
+    /// A regex, for anything other than literal words
+    Regex(Regex),
+    /// An automaton, for an alternation of literal words
+    Words(Words),
+}
+
+/// An Aho-Corasick automaton matching the words of an alternation like the regex would
+#[derive(Clone, Debug)]
+struct Words {
+    /// The automaton, with leftmost-first semantics, or standard semantics to report overlapping
+    /// matches when words must be whole
+    automaton: AhoCorasick,
+    /// Whether a match needs a word boundary on each side
+    whole_word: bool,
+    /// Whether the words match regardless of case
+    case_insensitive: bool,
+    /// The regex string the automaton was built from
+    pattern: String,
+    /// The regex, compiled only when a haystack needs it
+    fallback: OnceLock<Regex>,
+}
+
+/// The Kelvin sign, `\u{212A}`, encoded in UTF-8
+const KELVIN_SIGN: &[u8] = "\u{212A}".as_bytes();
+
+/// The long s, `\u{17F}`, encoded in UTF-8
+const LONG_S: &[u8] = "\u{17F}".as_bytes();
+
+/// A pure alternation of literal words found in a regex string
+struct Alternation {
+    /// The words, in order of priority
+    words: Vec<String>,
+    /// Whether the words match regardless of case
+    case_insensitive: bool,
+    /// Whether the alternation has a word boundary on each side
+    whole_word: bool,
+}
+
+/// Match any of a list of words, each escaped, as an alternation that [HumanRegex::to_matcher] compiles
+/// into an Aho-Corasick automaton however many words there are
+/// ```
+/// use human_regex::{one_of_words, WordOptions};
+/// let options = WordOptions { whole_word: true, ..Default::default() };
+/// let colors = one_of_words(&["red", "green", "blue"], &options);
+/// assert_eq!(colors.to_string(), r"\b(?:red|green|blue)\b");
+/// assert!(colors.to_regex().is_match("a red car"));
+/// assert!(!colors.to_matcher().is_match("a reddish car"));
+/// ```
+/// # Panics
+/// If there are no words, or one of them is empty
+pub fn one_of_words<T: AsRef<str>>(words: &[T], options: &WordOptions) -> HumanRegex<SymbolChain> {
+    assert!(!words.is_empty(), "there are no words to match");
+    let mut unique: Vec<&str> = Vec::with_capacity(words.len());
+    let mut seen = std::collections::HashSet::new();
+    for word in words {
+        let word = word.as_ref();
+        assert!(!word.is_empty(), "the words to match cannot be empty");
+        if seen.insert(word) {
+            unique.push(word);
+        }
+    }
+    if options.longest_first {
+        unique.sort_by_key(|word| std::cmp::Reverse(word.len()));
+    }
+    let escaped: Vec<String> = unique.iter().map(|word| regex::escape(word)).collect();
+    let mut pattern = format!("(?:{})", escaped.join("|"));
+    if options.whole_word {
+        pattern = format!(r"\b{}\b", pattern);
+    }
+    if options.case_insensitive {
+        pattern = format!("(?i:{})", pattern);
+    }
+    HumanRegex(pattern, pd::<SymbolChain>)
+}
+
+impl<T: State<Chain = SymbolChain>> HumanRegex<T> {
+    /// Compile into a [Matcher], which searches with an Aho-Corasick automaton if this is only an
+    /// alternation of literal words, like those built by [one_of_words], and with a regex otherwise
+    ///
+    /// Words that match regardless of case need to be ASCII to use the automaton.
+    /// ```
+    /// use human_regex::{digit, escape_all, one_or_more, or};
+    /// let stop_words = or(&escape_all(&["a", "an", "the"])).to_matcher();
+    /// assert!(stop_words.uses_automaton());
+    /// assert_eq!(stop_words.find("then").unwrap().range(), 0..3);
+    /// let number = one_or_more(digit()).to_matcher();
+    /// assert!(!number.uses_automaton());
+    /// assert_eq!(number.replace("route 66", "N"), "route N");
+    /// ```
+    pub fn to_matcher(&self) -> Matcher {
+        let engine = match literal_alternation(&self.0) {
+            Some(alternation)
+                if !alternation.case_insensitive
+                    || alternation.words.iter().all(|word| word.is_ascii()) =>
+            {
+                let kind = match alternation.whole_word {
+                    true => MatchKind::Standard,
+                    false => MatchKind::LeftmostFirst,
+                };
+                let automaton = AhoCorasick::builder()
+                    .match_kind(kind)
+                    .ascii_case_insensitive(alternation.case_insensitive)
+                    .build(&alternation.words)
+                    .unwrap();
+                Engine::Words(Words {
+                    automaton,
+                    whole_word: alternation.whole_word,
+                    case_insensitive: alternation.case_insensitive,
+                    pattern: self.0.clone(),
+                    fallback: OnceLock::new(),
+                })
+            }
+            _ => Engine::Regex(self.to_regex()),
+        };
+        Matcher { engine }
+    }
+}
+
+impl Matcher {
+    /// Return whether the pattern matches anywhere in the haystack
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let matcher = one_of_words(&["cat", "dog"], &WordOptions::default()).to_matcher();
+    /// assert!(matcher.is_match("hotdog"));
+    /// assert!(!matcher.is_match("bird"));
+    /// ```
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Return the first match in the haystack
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let options = WordOptions { whole_word: true, ..Default::default() };
+    /// let matcher = one_of_words(&["foo", "foobar"], &options).to_matcher();
+    /// assert_eq!(matcher.find("a foobar").unwrap().as_str(), "foobar");
+    /// ```
+    pub fn find<'h>(&self, haystack: &'h str) -> Option<WordMatch<'h>> {
+        self.find_at(haystack, 0)
+    }
+
+    /// Return the first match in the haystack that starts at or after `start`, looking before it
+    /// for word boundaries
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let options = WordOptions { whole_word: true, ..Default::default() };
+    /// let matcher = one_of_words(&["ant"], &options).to_matcher();
+    /// assert_eq!(matcher.find_at("ant pant", 1), None);
+    /// ```
+    pub fn find_at<'h>(&self, haystack: &'h str, start: usize) -> Option<WordMatch<'h>> {
+        let found = match &self.engine {
+            Engine::Regex(regex) => regex.find_at(haystack, start)?.range(),
+            Engine::Words(words) => words.find_at(haystack, start)?.range(),
+        };
+        Some(WordMatch {
+            haystack,
+            start: found.start,
+            end: found.end,
+        })
+    }
+
+    /// Return every successive non-overlapping match in the haystack
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let matcher = one_of_words(&["he", "she", "his", "hers"], &WordOptions::default()).to_matcher();
+    /// let found: Vec<_> = matcher.find_iter("ushers").map(|m| m.as_str()).collect();
+    /// assert_eq!(found, vec!["she"]);
+    /// ```
+    pub fn find_iter<'r, 'h>(
+        &'r self,
+        haystack: &'h str,
+    ) -> impl Iterator<Item = WordMatch<'h>> + 'r
+    where
+        'h: 'r,
+    {
+        successive_matches(haystack, move |start| {
+            Some(self.find_at(haystack, start)?.range())
+        })
+        .map(move |range| WordMatch {
+            haystack,
+            start: range.start,
+            end: range.end,
+        })
+    }
+
+    /// Replace the first match in the haystack, expanding `$` references in the replacement like
+    /// [regex::Regex::replace] does: `$0` is the match, `$name` or `${name}` a capture group, and `$$`
+    /// a literal `$`. An alternation of words has no capture group but the match.
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let matcher = one_of_words(&["cat", "dog"], &WordOptions::default()).to_matcher();
+    /// assert_eq!(matcher.replace("cat and dog", "[$0]"), "[cat] and dog");
+    /// assert_eq!(matcher.replace("cat and dog", "$$pet"), "$pet and dog");
+    /// ```
+    pub fn replace<'h>(&self, haystack: &'h str, replacement: &str) -> Cow<'h, str> {
+        self.replace_n(haystack, replacement, 1)
+    }
+
+    /// Replace every non-overlapping match in the haystack, expanding `$` references in the
+    /// replacement like [Matcher::replace]
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let options = WordOptions { whole_word: true, case_insensitive: true, ..Default::default() };
+    /// let matcher = one_of_words(&["a", "the"], &options).to_matcher();
+    /// assert_eq!(matcher.replace_all("The cat sat on a mat", "-"), "- cat sat on - mat");
+    /// ```
+    pub fn replace_all<'h>(&self, haystack: &'h str, replacement: &str) -> Cow<'h, str> {
+        self.replace_n(haystack, replacement, 0)
+    }
+
+    /// Return whether this searches with an Aho-Corasick automaton rather than a regex
+    /// ```
+    /// use human_regex::{one_of_words, WordOptions};
+    /// let options = WordOptions { case_insensitive: true, ..Default::default() };
+    /// assert!(one_of_words(&["straße"], &WordOptions::default()).to_matcher().uses_automaton());
+    /// assert!(!one_of_words(&["straße"], &options).to_matcher().uses_automaton());
+    /// ```
+    pub fn uses_automaton(&self) -> bool {
+        matches!(self.engine, Engine::Words(_))
+    }
+
+    /// Replace up to `limit` matches, or all of them if `limit` is zero
+    fn replace_n<'h>(&self, haystack: &'h str, replacement: &str, limit: usize) -> Cow<'h, str> {
+        if let Engine::Regex(regex) = &self.engine {
+            return regex.replacen(haystack, limit, replacement);
+        }
+        let mut matches = self.find_iter(haystack).peekable();
+        if matches.peek().is_none() {
+            return Cow::Borrowed(haystack);
+        }
+        let mut replaced = String::with_capacity(haystack.len());
+        let mut last = 0;
+        for (count, found) in matches.enumerate() {
+            if limit > 0 && count == limit {
+                break;
+            }
+            replaced.push_str(&haystack[last..found.start()]);
+            expand(replacement, found.as_str(), &mut replaced);
+            last = found.end();
+        }
+        replaced.push_str(&haystack[last..]);
+        Cow::Owned(replaced)
+    }
+}
+
+impl Words {
+    /// Return the first match starting at or after `start`, as the regex would find it
+    fn find_at(&self, haystack: &str, start: usize) -> Option<Match> {
+        let found = self.search(haystack, start);
+        // Unicode folds the Kelvin sign onto `k` and the long s onto `s`, which the automaton cannot,
+        // so let the regex search wherever one could be part of a match starting before this one
+        if self.case_insensitive {
+            let longest = 3 * self.automaton.max_pattern_len();
+            let limit = found.map_or(haystack.len(), |found| {
+                haystack.len().min(found.start() + longest)
+            });
+            let window = &haystack.as_bytes()[start..limit];
+            if window.windows(3).any(|bytes| bytes == KELVIN_SIGN)
+                || window.windows(2).any(|bytes| bytes == LONG_S)
+            {
+                let regex = self
+                    .fallback
+                    .get_or_init(|| Regex::new(&self.pattern).unwrap());
+                return regex
+                    .find_at(haystack, start)
+                    .map(|found| Match::must(0, found.range()));
+            }
+        }
+        found
+    }
+
+    /// Search with the automaton alone
+    fn search(&self, haystack: &str, start: usize) -> Option<Match> {
+        let input = Input::new(haystack).range(start..);
+        if !self.whole_word {
+            let found = self.automaton.find(input)?;
+            return Some(Match::must(0, found.range()));
+        }
+        // Overlapping matches come in order of where they end, so once they end further than the
+        // longest word past the best start, none can start earlier
+        let longest = self.automaton.max_pattern_len();
+        let mut best: Option<aho_corasick::Match> = None;
+        for found in self.automaton.find_overlapping_iter(input) {
+            if let Some(best) = best {
+                if found.end() > best.start() + longest {
+                    break;
+                }
+            }
+            if !is_boundary(haystack, found.start()) || !is_boundary(haystack, found.end()) {
+                continue;
+            }
+            let better = best.is_none_or(|best| {
+                (found.start(), found.pattern()) < (best.start(), best.pattern())
+            });
+            if better {
+                best = Some(found);
+            }
+        }
+        best.map(|found| Match::must(0, found.range()))
+    }
+}
+
+/// Add a replacement to the output, expanding its `$` references the way the regex crate does for
+/// a pattern whose only group is the whole match
+fn expand(replacement: &str, found: &str, replaced: &mut String) {
+    let mut rest = replacement;
+    while let Some(dollar) = rest.find('$') {
+        replaced.push_str(&rest[..dollar]);
+        rest = &rest[dollar + 1..];
+        if let Some(after) = rest.strip_prefix('$') {
+            replaced.push('$');
+            rest = after;
+            continue;
+        }
+        let reference = match rest.strip_prefix('{') {
+            Some(braced) => braced
+                .find('}')
+                .map(|close| (&braced[..close], &braced[close + 1..])),
+            None => {
+                let length = rest
+                    .find(|chr: char| !(chr.is_ascii_alphanumeric() || chr == '_'))
+                    .unwrap_or(rest.len());
+                (length > 0).then(|| (&rest[..length], &rest[length..]))
+            }
+        };
+        let Some((name, after)) = reference else {
+            // Not a reference, so the `$` is kept as it is
+            replaced.push('$');
+            continue;
+        };
+        if name.parse() == Ok(0usize) {
+            replaced.push_str(found);
+        }
+        rest = after;
+    }
+    replaced.push_str(rest);
+}
+
+/// Return whether a Unicode word boundary, `\b`, is at a position in the haystack
+fn is_boundary(haystack: &str, at: usize) -> bool {
+    let is_word = |chr: Option<char>| chr.is_some_and(regex_syntax::is_word_character);
+    is_word(haystack[..at].chars().next_back()) != is_word(haystack[at..].chars().next())
+}
+
+/// Find the words of a regex string that is only an alternation of literals, optionally matching
+/// regardless of case and with a word boundary on each side
+fn literal_alternation(pattern: &str) -> Option<Alternation> {
+    let ast = syntax::parse(pattern).ok()?;
+    let (ast, outer) = ungroup(&ast, FlagState::default())?;
+    let (ast, whole_word) = match ast {
+        Ast::Concat(concat)
+            if concat.asts.len() == 3
+                && is_word_boundary(&concat.asts[0])
+                && is_word_boundary(&concat.asts[2]) =>
+        {
+            (&concat.asts[1], true)
+        }
+        ast => (ast, false),
+    };
+    let (ast, flags) = ungroup(ast, outer)?;
+    if !outer.unicode || !flags.unicode {
+        return None;
+    }
+    let branches = match ast {
+        Ast::Alternation(alternation) => alternation.asts.iter().collect(),
+        ast => vec![ast],
+    };
+    let words = branches
+        .into_iter()
+        .map(|branch| match ungroup(branch, flags)? {
+            (ast, state) if state == flags => literal(ast),
+            _ => None,
+        })
+        .collect::<Option<Vec<String>>>()?;
+    Some(Alternation {
+        words,
+        case_insensitive: flags.case_insensitive,
+        whole_word,
+    })
+}
+
+/// Step inside non-capturing groups, returning what they hold and the flags in effect there, or
+/// `None` if a group captures
+fn ungroup(mut ast: &Ast, mut flags: FlagState) -> Option<(&Ast, FlagState)> {
+    while let Ast::Group(group) = ast {
+        match &group.kind {
+            ast::GroupKind::NonCapturing(group_flags) => flags = flags.apply(group_flags),
+            _ => return None,
+        }
+        ast = &group.ast;
+    }
+    Some((ast, flags))
+}
+
+/// Return whether the syntax tree is a Unicode word boundary, `\b`
+fn is_word_boundary(ast: &Ast) -> bool {
+    matches!(ast, Ast::Assertion(assertion) if assertion.kind == ast::AssertionKind::WordBoundary)
+}
+
+/// Return the text of a non-empty literal, or `None` if the syntax tree is anything else
+fn literal(ast: &Ast) -> Option<String> {
+    match ast {
+        Ast::Literal(literal) => Some(literal.c.to_string()),
+        Ast::Concat(concat) => concat
+            .asts
+            .iter()
+            .map(|ast| match ast {
+                Ast::Literal(literal) => Some(literal.c),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
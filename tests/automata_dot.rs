@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use hr::DotAutomaton;
+    use human_regex as hr;
+
+    /// Return the states drawn as match states
+    fn match_states(dot: &str) -> Vec<String> {
+        dot.lines()
+            .filter(|line| line.ends_with("[shape=doublecircle];"))
+            .map(|line| line.split_whitespace().next().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn greedy_tags_keep_going_after_a_match() {
+        let greedy = hr::text("<") + hr::one_or_more(hr::any()) + hr::text(">");
+        let lazy = hr::text("<") + hr::one_or_more(hr::any()).lazy() + hr::text(">");
+        for (regex_string, continues) in [(greedy, true), (lazy, false)] {
+            let dot = regex_string.to_dot(DotAutomaton::MinimalDfa).unwrap();
+            let leaves_a_match = match_states(&dot)
+                .iter()
+                .any(|state| dot.contains(&format!("  {} -> ", state)));
+            assert_eq!(leaves_a_match, continues);
+        }
+    }
+
+    #[test]
+    fn nfa_shows_preferences_and_capture_slots() {
+        let dot = hr::capture(hr::zero_or_more(hr::text("a")).lazy())
+            .to_dot(DotAutomaton::Nfa)
+            .unwrap();
+        assert!(dot.contains(r#"label="open group 1 (slot 2)", style=dashed"#));
+        assert!(dot.contains(r#"label="close group 1 (slot 3)", style=dashed"#));
+        assert!(dot.contains(r#"label="ε1""#));
+        assert!(dot.contains(r#"label="ε2""#));
+        assert!(dot.contains(r#"label="a""#));
+    }
+
+    #[test]
+    fn minimization_merges_the_shared_suffix() {
+        let regex_string = hr::or(&[hr::text("abc"), hr::text("xbc")]);
+        let count = |dot: String| dot.matches(" -> ").count();
+        assert!(
+            count(regex_string.to_dot(DotAutomaton::MinimalDfa).unwrap())
+                < count(regex_string.to_dot(DotAutomaton::Dfa).unwrap())
+        );
+    }
+
+    #[test]
+    fn raw_bytes_draw_invalid_utf8_edges() {
+        let regex_string = hr::text("a") + hr::byte_range(0x80..=0xFF);
+        for automaton in [
+            DotAutomaton::Nfa,
+            DotAutomaton::Dfa,
+            DotAutomaton::MinimalDfa,
+        ] {
+            let dot = regex_string.to_dot(automaton).unwrap();
+            assert!(dot.contains(r#"label="\\x80-\\xFF""#), "{}", dot);
+        }
+    }
+}
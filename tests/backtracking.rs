@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn nested_repetitions_are_exponential_with_rewrites() {
+        let words = hr::beginning()
+            + hr::one_or_more(hr::one_or_more(hr::word()) + hr::zero_or_one(hr::whitespace()))
+            + hr::end();
+        let risks = words.backtracking_risks();
+        assert_eq!(risks.len(), 1);
+        assert_eq!(risks[0].complexity, hr::Complexity::Exponential);
+        assert_eq!(risks[0].expression, r"(?:(?:\w)+(?:\s)?)+");
+        assert_eq!(risks[0].attack.build(3), "aaa!");
+        let rewrite = risks[0].rewrite.as_deref().unwrap();
+        assert_eq!(rewrite, r"(?:\w)+(?:(?:\s)(?:\w)+)*(?:\s)?");
+        let rewritten = hr::nonescaped_text(&format!("^{}$", rewrite));
+        assert!(rewritten.backtracking_risks().is_empty());
+        for sample in [
+            "one two three",
+            "one",
+            "one  two",
+            "one!",
+            "one two ",
+            "one ",
+            " one",
+        ] {
+            assert_eq!(
+                rewritten.to_regex().is_match(sample),
+                words.to_regex().is_match(sample),
+                "{}",
+                sample
+            );
+        }
+        // A bounded outer repetition is polynomial, with the bound as the degree
+        let bounded = hr::beginning()
+            + hr::exactly(12, hr::zero_or_more(hr::any()) + hr::text("a"))
+            + hr::end();
+        let risks = bounded.backtracking_risks();
+        assert_eq!(risks.len(), 1, "{:?}", risks);
+        assert_eq!(risks[0].complexity, hr::Complexity::Polynomial(12));
+        assert_eq!(risks[0].attack.pump, "a");
+        assert!(!bounded.to_regex().is_match(&risks[0].attack.build(20)));
+        // Nothing after the repetition can fail, so a backtracking engine succeeds at once
+        assert!(hr::one_or_more(hr::one_or_more(hr::digit()))
+            .backtracking_risks()
+            .is_empty());
+    }
+
+    #[test]
+    fn overlapping_branches_are_exponential() {
+        let risks = (hr::text("id:")
+            + hr::one_or_more(hr::or(&[hr::text("ab"), hr::text("a"), hr::text("b")]))
+            + hr::text(";"))
+        .backtracking_risks();
+        assert_eq!(risks.len(), 1);
+        assert_eq!(risks[0].attack.prefix, "id:");
+        assert_eq!(risks[0].attack.pump, "ab");
+        assert_eq!(
+            risks[0].rewrite.as_deref(),
+            Some("(?:(?:a)|(?:b))+"),
+            "{}",
+            risks[0]
+        );
+        let rewritten = hr::nonescaped_text(&format!("id:{};", risks[0].rewrite.as_ref().unwrap()));
+        assert!(rewritten.backtracking_risks().is_empty());
+        // Branches that cannot match the same text are safe
+        let disjoint =
+            hr::beginning() + hr::one_or_more(hr::or(&[hr::text("a"), hr::text("ab")])) + hr::end();
+        assert!(disjoint.backtracking_risks().is_empty());
+    }
+
+    #[test]
+    fn adjacent_repetitions_are_polynomial() {
+        let regex_string = hr::text("id=")
+            + hr::zero_or_more(hr::word())
+            + hr::zero_or_more(hr::digit())
+            + hr::zero_or_more(hr::any())
+            + hr::text("!");
+        let risks = regex_string.backtracking_risks();
+        assert_eq!(risks.len(), 1);
+        assert_eq!(risks[0].complexity, hr::Complexity::Polynomial(3));
+        assert_eq!(risks[0].attack.pump, "0");
+        assert!(!regex_string.to_regex().is_match(&risks[0].attack.build(50)));
+        assert_eq!(
+            risks[0].to_string(),
+            format!("cubic backtracking: {} (at 7..27)", risks[0].message)
+        );
+        let separated = hr::beginning()
+            + hr::one_or_more(hr::digit())
+            + hr::text(".")
+            + hr::one_or_more(hr::digit())
+            + hr::end();
+        assert!(separated.backtracking_risks().is_empty());
+    }
+}
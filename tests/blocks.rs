@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+    use human_regex::{CharSet, UnicodeBlock, UnicodeCategory, UnicodeScript};
+
+    #[test]
+    fn blocks_cover_every_assigned_character() {
+        let mut blocks = CharSet::new();
+        let mut previous: Option<char> = None;
+        for block in UnicodeBlock::ALL {
+            let range = block.range();
+            assert!(
+                previous.is_none_or(|end| end < *range.start()),
+                "{:?}",
+                block
+            );
+            assert_eq!(*range.start() as u32 % 16, 0, "{:?}", block);
+            assert_eq!(*range.end() as u32 % 16, 15, "{:?}", block);
+            previous = Some(*range.end());
+            blocks = blocks | CharSet::from(range);
+        }
+        let unassigned = hr::unicode_category(UnicodeCategory::Unassigned);
+        let assigned = !CharSet::from_class(&unassigned).unwrap();
+        assert!((assigned - &blocks).is_empty());
+    }
+
+    #[test]
+    fn blocks_compose_with_other_classes() {
+        let latin_letters = hr::and(
+            hr::unicode_block(UnicodeBlock::Latin1Supplement),
+            hr::unicode_category(UnicodeCategory::Letter),
+        );
+        assert!(latin_letters.to_regex().is_match("é"));
+        assert!(!latin_letters.to_regex().is_match("×"));
+
+        let unassigned_cjk = hr::subtract(
+            hr::unicode_block(UnicodeBlock::CjkUnifiedIdeographs),
+            hr::unicode_script(UnicodeScript::Han),
+        );
+        assert!(CharSet::from_class(&unassigned_cjk).unwrap().is_empty());
+
+        let either = hr::xor(
+            hr::unicode_block(UnicodeBlock::BasicLatin),
+            hr::within_range('a'..='\u{FF}'),
+        );
+        assert!(either.to_regex().is_match("A"));
+        assert!(either.to_regex().is_match("ÿ"));
+        assert!(!either.to_regex().is_match("z"));
+
+        let outside = hr::non_unicode_block(UnicodeBlock::MathematicalOperators);
+        assert!(outside.to_regex().is_match("+"));
+        assert!(!outside.to_regex().is_match("∑"));
+    }
+
+    #[test]
+    fn blocks_round_trip_through_the_dsl() {
+        for (name, block) in [
+            ("BasicLatin", UnicodeBlock::BasicLatin),
+            ("CjkUnifiedIdeographs", UnicodeBlock::CjkUnifiedIdeographs),
+            ("LatinExtendedA", UnicodeBlock::LatinExtendedA),
+            (
+                "SymbolsForLegacyComputingSupplement",
+                UnicodeBlock::SymbolsForLegacyComputingSupplement,
+            ),
+        ] {
+            let dsl = format!("unicode_block {}", name);
+            assert_eq!(
+                hr::parse_dsl(&dsl).unwrap().to_string(),
+                hr::unicode_block(block).to_string()
+            );
+            assert_eq!(hr::unicode_block(block).to_dsl(), dsl);
+            assert_eq!(
+                hr::non_unicode_block(block).to_dsl(),
+                format!("non_{}", dsl)
+            );
+        }
+        assert_eq!(
+            UnicodeBlock::CjkUnifiedIdeographs.name(),
+            "CJK Unified Ideographs"
+        );
+        assert!(hr::parse_dsl("unicode_block Klingon").is_err());
+    }
+}
@@ -0,0 +1,134 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+    use human_regex::CharSet;
+
+    /// A set operation paired with the membership rule it should follow
+    type Operation = (CharSet, fn(bool, bool) -> bool);
+
+    /// Characters around the edges of the interesting ranges, to check sets against a brute force model
+    fn probes() -> Vec<char> {
+        let mut probes: Vec<char> = ('\0'..='\u{17F}').collect();
+        probes.extend(['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}', '\u{FFFF}']);
+        probes.extend(['\u{10000}', '\u{10FFFE}', char::MAX]);
+        probes
+    }
+
+    #[test]
+    fn set_operations_agree_with_membership() {
+        let sets = [
+            CharSet::new(),
+            CharSet::all(),
+            CharSet::from('a'..='z'),
+            "aeiou-^]".chars().collect(),
+            CharSet::from('\u{D000}'..='\u{E100}') | CharSet::from('\0'..='\u{20}'),
+            CharSet::from('\u{100}'..=char::MAX) ^ CharSet::from('\u{120}'..='\u{10FFFE}'),
+        ];
+        for a in &sets {
+            assert_eq!(a.iter().count(), a.len());
+            assert_eq!(a.iter().collect::<CharSet>(), *a);
+            for b in &sets {
+                let operations: [Operation; 5] = [
+                    (a | b, |x, y| x || y),
+                    (a & b, |x, y| x && y),
+                    (a - b, |x, y| x && !y),
+                    (a ^ b, |x, y| x != y),
+                    (!a, |x, _| !x),
+                ];
+                for (result, expected) in &operations {
+                    let regex = result.to_class().to_regex();
+                    for chr in probes() {
+                        let member = expected(a.contains(chr), b.contains(chr));
+                        assert_eq!(result.contains(chr), member, "{} {:?}", result, chr);
+                        assert_eq!(
+                            regex.is_match(&chr.to_string()),
+                            member,
+                            "{} {:?}",
+                            result,
+                            chr
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sets_render_minimal_escaped_classes() {
+        assert_eq!(
+            CharSet::from_iter(['c', 'a', 'b', 'e']).to_string(),
+            "[a-ce]"
+        );
+        assert_eq!(CharSet::from_iter(['a', 'b']).to_string(), "[ab]");
+        assert_eq!(
+            CharSet::from_iter([' ', '\t', '#']).to_string(),
+            r"[\x{9}\x{20}\#]"
+        );
+        assert_eq!((!CharSet::from('0'..='9')).to_string(), "[^0-9]");
+        assert_eq!(CharSet::all().to_string(), r"[\x{0}-\x{10FFFF}]");
+
+        // Escaped classes keep their meaning under the verbose flag
+        let verbose = hr::ignore_whitespace(CharSet::from_iter([' ', '#', 'x']).to_class());
+        assert!(verbose.to_regex().is_match(" "));
+        assert!(verbose.to_regex().is_match("#"));
+
+        let class = hr::within_range('a'..='f') & !hr::within_set(&['c']);
+        let set = CharSet::from_class(&class).unwrap();
+        assert_eq!(set.ranges(), &[('a', 'b'), ('d', 'f')]);
+        assert_eq!(hr::HumanRegex::from(set).to_string(), "[abd-f]");
+        assert!(CharSet::from_class(&hr::digit())
+            .unwrap()
+            .contains('\u{663}'));
+    }
+
+    #[test]
+    fn within_set_treats_every_character_literally() {
+        let special = hr::within_set(&[']', '-', '^']);
+        assert_eq!(special.to_string(), r"[\]\-\^]");
+        for (haystack, matches) in [
+            ("]", true),
+            ("-", true),
+            ("^", true),
+            ("a", false),
+            ("\\", false),
+        ] {
+            assert_eq!(
+                special.to_regex().is_match(haystack),
+                matches,
+                "{:?}",
+                haystack
+            );
+            assert_eq!(
+                (!hr::within_set(&[']', '-', '^']))
+                    .to_regex()
+                    .is_match(haystack),
+                !matches
+            );
+        }
+        let strings = hr::within_set(&["a-c", r"\d"]);
+        assert!(strings.to_regex().is_match("-"));
+        assert!(!strings.to_regex().is_match("b"));
+        assert!(!strings.to_regex().is_match("5"));
+        assert!(hr::within_range('['..=']').to_regex().is_match("\\"));
+        assert!(!hr::without_range('-'..='/').to_regex().is_match("."));
+
+        // Negating a composite class negates the whole class
+        let composite = !hr::subtract(hr::within_range('a'..='z'), hr::within_set(&['q']));
+        assert!(composite.to_regex().is_match("q"));
+        assert!(composite.to_regex().is_match("A"));
+        assert!(!composite.to_regex().is_match("b"));
+    }
+
+    #[test]
+    fn escaped_items_are_not_escaped_twice() {
+        let brackets = hr::within_set(&hr::escape_all(&["[", "]"]));
+        assert_eq!(brackets.to_string(), r"[\[\]]");
+        assert!(brackets.to_regex().is_match("["));
+        assert!(brackets.to_regex().is_match("]"));
+        assert!(!brackets.to_regex().is_match("\\"));
+        let spaced = hr::without_set(&hr::escape_all(&["a b", "\t"]));
+        assert!(!spaced.to_regex().is_match(" "));
+        assert!(!spaced.to_regex().is_match("\t"));
+        assert!(spaced.to_regex().is_match("c"));
+    }
+}
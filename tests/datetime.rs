@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    /// Compile a pattern that must match the whole haystack
+    fn whole(pattern: hr::HumanRegex) -> regex::Regex {
+        (hr::beginning() + pattern + hr::end()).to_regex()
+    }
+
+    /// Return the number of days in a month of the Gregorian calendar
+    fn days_in(year: u32, month: u32) -> u32 {
+        let leap =
+            year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+        match month {
+            2 if leap => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    #[test]
+    fn strict_formats_check_days_against_months_and_leap_years() {
+        let loose = whole(hr::datetime_format("%Y-%m-%d").unwrap());
+        let strict = whole(hr::datetime_format_strict("%Y-%m-%d").unwrap());
+        let short = whole(hr::datetime_format_strict("%-m/%-d/%y").unwrap());
+        for year in [1900, 1996, 2000, 2023, 2024, 2100, 2400] {
+            for month in 0..=13 {
+                for day in 0..=32 {
+                    let date = format!("{:04}-{:02}-{:02}", year, month, day);
+                    let in_month = (1..=12).contains(&month) && day >= 1;
+                    assert_eq!(loose.is_match(&date), in_month && day <= 31, "{}", date);
+                    let exists = in_month && day <= days_in(year, month);
+                    assert_eq!(strict.is_match(&date), exists, "{}", date);
+                    if (2000..2100).contains(&year) {
+                        let date = format!("{}/{}/{:02}", month, day, year % 100);
+                        assert_eq!(short.is_match(&date), exists, "{}", date);
+                    }
+                }
+            }
+        }
+        let caps = loose.captures("2024-02-29").unwrap();
+        assert_eq!(
+            (&caps["year"], &caps["month"], &caps["day"]),
+            ("2024", "02", "29")
+        );
+        let error = hr::datetime_format_strict("%m %H %d").err().unwrap();
+        assert_eq!(
+            error.message,
+            "checking days needs the month and day next to each other"
+        );
+    }
+
+    #[test]
+    fn specifiers_match_their_fields() {
+        let cases = [
+            ("%e.%m.", " 7.03.", "day", " 7"),
+            ("%_H:%M", " 9:05", "hour", " 9"),
+            ("%I:%M %p", "12:30 PM", "am_pm", "PM"),
+            ("%A, %B %-d", "Friday, March 7", "month", "March"),
+            ("%Y/%j", "2024/366", "day_of_year", "366"),
+            ("%T%.3f", "23:59:60.125", "fraction", "125"),
+            ("%F %:z", "2024-01-31 -05:30", "offset", "-05:30"),
+            ("%H%#z", "12Z", "offset", "Z"),
+            ("%s%%", "1700000000%", "timestamp", "1700000000"),
+            ("%D %Z", "12/31/99 CET", "zone", "CET"),
+        ];
+        for (format, haystack, name, value) in cases {
+            let regex = whole(hr::datetime_format(format).unwrap());
+            let caps = regex
+                .captures(haystack)
+                .unwrap_or_else(|| panic!("{}", format));
+            assert_eq!(&caps[name], value, "{}", format);
+        }
+        let rejected = [
+            ("%e.%m.", "07x03."),
+            ("%I:%M %p", "13:30 PM"),
+            ("%Y/%j", "2024/367"),
+            ("%T%.3f", "23:59:61.125"),
+            ("%T%.3f", "23:59:59.12"),
+            ("%T%.f", "23:59:59."),
+            ("%F %:z", "2024-01-31 -0530"),
+            ("%d%m", "3112x"),
+        ];
+        for (format, haystack) in rejected {
+            let regex = whole(hr::datetime_format(format).unwrap());
+            assert!(!regex.is_match(haystack), "{} {}", format, haystack);
+        }
+        // Only the first field with a name captures it
+        let regex = hr::datetime_format("%H:%M-%H:%M").unwrap().to_regex();
+        assert_eq!(&regex.captures("09:00-17:30").unwrap()["hour"], "09");
+        for (format, message, offset) in [
+            ("%Y-%Q", "unknown specifier `%Q`", 3),
+            ("%H:%-p", "unknown specifier `%-p`", 3),
+            ("%H%", "`%` at the end of the format", 2),
+        ] {
+            let error = hr::datetime_format(format).err().unwrap();
+            assert_eq!((error.message.as_str(), error.offset), (message, offset));
+        }
+    }
+
+    #[test]
+    fn presets_match_the_standard_formats() {
+        let iso = whole(hr::iso8601());
+        let rfc3339 = whole(hr::rfc3339());
+        let rfc2822 = whole(hr::rfc2822());
+        for (regex, accepted, rejected) in [
+            (
+                &iso,
+                vec![
+                    "2024-02-29T13:05:59",
+                    "2024-02-29T13:05:59.123456789Z",
+                    "2024-02-29T13:05:59+01",
+                    "2024-02-29T13:05:59-0830",
+                ],
+                vec!["2024-02-29", "2024-2-29T13:05:59", "2024-02-29T13:05"],
+            ),
+            (
+                &rfc3339,
+                vec!["1985-04-12T23:20:50.52Z", "1996-12-19T16:39:57-08:00"],
+                vec!["1996-12-19 16:39:57-08:00", "1996-12-19T16:39:57-8:00"],
+            ),
+            (
+                &rfc2822,
+                vec![
+                    "Fri, 21 Nov 1997 09:55:06 -0600",
+                    "21 Nov 1997 09:55 GMT",
+                    "Tue, 1 Jul 2003 10:52:37 +0200",
+                ],
+                vec![
+                    "Fri 21 Nov 1997 09:55:06 -0600",
+                    "21 November 1997 09:55:06 -0600",
+                    "21 Nov 97 09:55:06 -0600",
+                ],
+            ),
+        ] {
+            for haystack in accepted {
+                assert!(regex.is_match(haystack), "{}", haystack);
+            }
+            for haystack in rejected {
+                assert!(!regex.is_match(haystack), "{}", haystack);
+            }
+        }
+        let caps = rfc3339.captures("1985-04-12T23:20:50.52Z").unwrap();
+        assert_eq!((&caps["second"], &caps["offset"]), ("50", "Z"));
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+    use human_regex::dfa::{Automaton, DfaKind, Input};
+
+    /// The pattern serialized in `tests/data`
+    fn date() -> hr::HumanRegex {
+        hr::beginning()
+            + hr::exactly(4, hr::within_range('0'..='9'))
+            + hr::text("-")
+            + hr::exactly(2, hr::within_range('0'..='9'))
+            + hr::end()
+    }
+
+    /// Check that a DFA matches the same samples as the regex of the pattern
+    fn assert_same_matches<A: Automaton>(dfa: &A) {
+        let regex = date().to_regex();
+        for sample in ["2023-01", "2023-1", "12023-01", "", "abcd-ef"] {
+            assert_eq!(
+                dfa.try_search_fwd(&Input::new(sample)).unwrap().is_some(),
+                regex.is_match(sample),
+                "{:?}",
+                sample
+            );
+        }
+    }
+
+    #[test]
+    fn written_dfas_load_back() {
+        let out_dir = std::env::temp_dir().join(format!("human_regex_dfa_{}", std::process::id()));
+        std::fs::create_dir_all(&out_dir).unwrap();
+        std::env::set_var("OUT_DIR", &out_dir);
+        let dense = hr::dfa::write_dfa("date_dense", &date(), DfaKind::Dense).unwrap();
+        let sparse = hr::dfa::write_dfa("date_sparse", &date(), DfaKind::Sparse).unwrap();
+        assert_eq!(dense, out_dir.join("date_dense.dfa"));
+
+        // Dense DFAs need their bytes aligned to a `u32`, like the static `include_dense_dfa!` embeds
+        let bytes = std::fs::read(&dense).unwrap();
+        let mut buffer = vec![0u8; bytes.len() + 3];
+        let start = buffer.as_ptr().align_offset(4);
+        buffer[start..start + bytes.len()].copy_from_slice(&bytes);
+        let (dfa, _) =
+            hr::dfa::dense::DFA::from_bytes(&buffer[start..start + bytes.len()]).unwrap();
+        assert_same_matches(&dfa);
+
+        let bytes = std::fs::read(&sparse).unwrap();
+        let (dfa, _) = hr::dfa::sparse::DFA::from_bytes(&bytes).unwrap();
+        assert_same_matches(&dfa);
+        std::fs::remove_dir_all(&out_dir).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_endian = "little")]
+    fn included_dfas_match_like_the_pattern() {
+        // Written by `to_dfa_bytes(DfaKind::Dense, Endian::Little)` and `DfaKind::Sparse` from `date()`
+        let dense = hr::include_dense_dfa!("data/date.dense.dfa");
+        assert_same_matches(&dense);
+        let sparse = hr::include_sparse_dfa!("data/date.sparse.dfa");
+        assert_same_matches(&sparse);
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use hr::Dialect;
+    use human_regex as hr;
+
+    #[test]
+    fn case_insensitivity_is_scoped_or_expanded() {
+        let regex_string = hr::text("id-") + hr::case_insensitive(hr::text("ab"));
+        assert_eq!(
+            regex_string.to_dialect(Dialect::Python).unwrap(),
+            "id-(?i:ab)"
+        );
+        assert_eq!(
+            regex_string.to_dialect(Dialect::JavaScript).unwrap(),
+            "id-[Aa][Bb]"
+        );
+    }
+
+    #[test]
+    fn escapes_follow_the_dialect() {
+        let regex_string = hr::text("a/b\u{1F600}") + hr::within_set(&['\u{7}', '/']);
+        assert_eq!(
+            regex_string.to_dialect(Dialect::JavaScript).unwrap(),
+            r"a\/b\u{1F600}[\u{7}\/]"
+        );
+        assert_eq!(
+            regex_string.to_dialect(Dialect::Python).unwrap(),
+            r"a/b\U0001F600[\x07/]"
+        );
+        assert_eq!(
+            regex_string.to_dialect(Dialect::DotNet).unwrap(),
+            r"a/b\uD83D\uDE00[\u0007/]"
+        );
+    }
+
+    #[test]
+    fn classes_that_never_match_are_exported() {
+        // An empty intersection used to be reported as an unsupported class of raw bytes
+        let regex_string = hr::text("x") + hr::and(hr::within_range('a'..='c'), hr::digit());
+        assert_eq!(
+            regex_string.to_dialect(Dialect::Python).unwrap(),
+            r"x[^\x00-\U0010FFFF]"
+        );
+        let never = hr::CharSet::from_class(&hr::and(hr::within_range('a'..='c'), hr::digit()));
+        assert_eq!(never, Some(hr::CharSet::new()));
+    }
+
+    #[test]
+    fn alternations_keep_their_grouping() {
+        let regex_string =
+            hr::one_or_more(hr::or(&[hr::text("ab"), hr::text("cd")])) + hr::text("e");
+        for dialect in [Dialect::JavaScript, Dialect::Python, Dialect::Go] {
+            assert_eq!(regex_string.to_dialect(dialect).unwrap(), "(?:ab|cd)+e");
+        }
+        assert_eq!(
+            regex_string.to_dialect(Dialect::Posix).unwrap(),
+            "(ab|cd)+e"
+        );
+        let captured = hr::capture(hr::text("x")) + regex_string;
+        assert!(captured.to_dialect(Dialect::Posix).is_err());
+    }
+
+    #[test]
+    fn unsupported_constructs_are_named() {
+        let regex_string = hr::word_boundary() + hr::text("cat");
+        assert_eq!(
+            regex_string
+                .to_dialect(Dialect::Go)
+                .unwrap_err()
+                .to_string(),
+            "Go cannot express Unicode word boundaries"
+        );
+        assert_eq!(
+            hr::one_or_more(hr::any())
+                .lazy()
+                .to_dialect(Dialect::Posix)
+                .unwrap_err()
+                .to_string(),
+            "POSIX cannot express lazy repetitions"
+        );
+    }
+
+    #[test]
+    fn posix_never_writes_nul_or_a_bracketed_backslash() {
+        for (regex_string, construct) in [
+            (hr::beginning() + hr::text("a\0"), "a NUL character"),
+            (
+                hr::text("a") + hr::within_range('\0'..='\u{1F}'),
+                "a NUL character",
+            ),
+            (
+                hr::text("a") + hr::within_set(&['\\', 'b']),
+                "a backslash in a bracket expression",
+            ),
+            (
+                hr::text("a") + hr::within_range('['..=']'),
+                "a backslash in a bracket expression",
+            ),
+        ] {
+            assert_eq!(
+                regex_string
+                    .to_dialect(Dialect::Posix)
+                    .unwrap_err()
+                    .to_string(),
+                format!("POSIX cannot express {}", construct)
+            );
+        }
+        // Outside brackets, and inside a range that does not end on it, `\` is fine
+        assert_eq!(
+            (hr::text("a\\") + hr::within_range('!'..='~'))
+                .to_dialect(Dialect::Posix)
+                .unwrap(),
+            r"a\\[!-~]"
+        );
+    }
+
+    #[test]
+    fn raw_bytes_are_unsupported() {
+        for regex_string in [
+            hr::text("a") + hr::byte(0xFF),
+            hr::text("a") + hr::byte_range(0x80..=0xFF),
+            hr::text("a") + hr::any_byte(),
+        ] {
+            for dialect in [Dialect::JavaScript, Dialect::Python, Dialect::Posix] {
+                assert!(
+                    matches!(
+                        regex_string.to_dialect(dialect),
+                        Err(hr::DialectError::Unsupported { .. })
+                    ),
+                    "{}",
+                    regex_string
+                );
+            }
+        }
+        // Without Unicode, ASCII classes still match the same characters
+        let ascii = hr::disable_unicode(hr::one_or_more(hr::word()));
+        assert_eq!(ascii.to_dialect(Dialect::Go).unwrap(), "[0-9A-Za-z_]+");
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use hr::Dialect;
+    use human_regex as hr;
+
+    #[test]
+    fn javascript_classes_stay_ascii() {
+        let regex_string = hr::from_javascript(r"/^\w+\b/").unwrap();
+        assert!(regex_string.to_regex().is_match("word"));
+        assert!(!regex_string.to_regex().is_match("éte"));
+    }
+
+    #[test]
+    fn flags_become_wrappers() {
+        assert_eq!(
+            hr::from_javascript("/a.c/is").unwrap().to_string(),
+            "(?i:(?s:a.c))"
+        );
+        assert_eq!(hr::from_python("(?m)^x$").unwrap().to_string(), "(?m:^x$)");
+    }
+
+    #[test]
+    fn python_verbose_patterns_lose_their_comments() {
+        let regex_string = hr::from_python(
+            r"(?x)
+            (?P<area>\d{3})  # area code
+            [-\ ]?
+            (?P<number>\d{4})",
+        )
+        .unwrap();
+        let caps = regex_string.to_regex().captures("555 1234").unwrap();
+        assert_eq!(&caps["area"], "555");
+        assert_eq!(&caps["number"], "1234");
+    }
+
+    #[test]
+    fn posix_brackets_have_no_escapes() {
+        let regex_string = hr::from_posix_extended(r"[\]]").unwrap();
+        assert!(regex_string.to_regex().is_match(r"\]"));
+        assert!(!regex_string.to_regex().is_match("]"));
+    }
+
+    #[test]
+    fn unsupported_features_are_located() {
+        for (result, message) in [
+            (
+                hr::from_javascript(r"/(?<=\$)\d+/"),
+                r"this crate cannot express the JavaScript lookbehind `(?<=` at byte 1",
+            ),
+            (
+                hr::from_python(r"(\w)\1"),
+                r"this crate cannot express the Python backreference `\1` at byte 4",
+            ),
+            (
+                hr::from_posix_basic(r"\(a\)\1"),
+                r"this crate cannot express the POSIX backreference `\1` at byte 5",
+            ),
+            (
+                hr::from_posix_extended("[[=e=]]"),
+                "this crate cannot express the POSIX equivalence class `[=` at byte 1",
+            ),
+            (
+                hr::from_python(r"^\d+$"),
+                "this crate cannot express the Python end of text `$`, which also matches before a final newline at byte 4",
+            ),
+            (
+                hr::from_javascript("/a/gy"),
+                "this crate cannot express the JavaScript sticky flag `y` at byte 4",
+            ),
+        ] {
+            assert_eq!(result.err().unwrap().to_string(), message);
+        }
+        // In multi-line mode, `$` means the same in Python and here
+        for pattern in [r"(?m)^\d+$", r"(?m:\d+$)", r"\d+\Z"] {
+            let regex_string = hr::from_python(pattern).unwrap();
+            assert!(regex_string.to_regex().is_match("42"), "{}", pattern);
+        }
+        assert!(hr::from_python(r"(?m)(?-m:\d+$)").is_err());
+    }
+
+    #[test]
+    fn imports_round_trip_through_exports() {
+        let pattern = r"(?P<user>[\w.+-]+)@(?P<host>[\w-]+(?:\.[\w-]+)+)";
+        let regex_string = hr::from_python(pattern).unwrap();
+        assert_eq!(
+            regex_string.to_dialect(Dialect::Python).unwrap(),
+            r"(?P<user>[\w.+\-]+)@(?P<host>[\w\-]+(?:\.[\w\-]+)+)"
+        );
+    }
+}
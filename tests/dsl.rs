@@ -0,0 +1,179 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn functions_build_the_same_regex_as_the_builder() {
+        let cases = [
+            (
+                r#"lazy between 2 5 within_set ['a', 'e'], or ["x", "yz"]"#,
+                (hr::between(2, 5, hr::within_set(&['a', 'e'])).lazy()
+                    + hr::or(&[hr::text("x"), hr::text("yz")]))
+                .to_string(),
+            ),
+            (
+                "subtract within_range 'a'..='z' within_set ['q'], non_word_boundary",
+                (hr::subtract(hr::within_range('a'..='z'), hr::within_set(&['q']))
+                    + hr::non_word_boundary())
+                .to_string(),
+            ),
+            (
+                "case_insensitive (capture word, one_or_more unicode_script Greek)",
+                hr::case_insensitive(
+                    hr::capture(hr::word())
+                        + hr::one_or_more(hr::unicode_script(hr::UnicodeScript::Greek)),
+                )
+                .to_string(),
+            ),
+            (
+                r#"comment "the year" exactly 4 digit  # four digits"#,
+                hr::exactly(4, hr::digit()).comment("the year").to_string(),
+            ),
+        ];
+        for (source, expected) in cases {
+            assert_eq!(hr::parse_dsl(source).unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let errors = [
+            ("exactly 1001 digit", "expected a number from 0 to 1000", 8),
+            ("lazy digit", "`lazy` needs a repetition", 5),
+            ("and digit \"x\"", "`and` needs a character class", 10),
+            ("digit digit", "expected `,` or the end of the pattern", 6),
+            (
+                "unicode_script Klingon",
+                "unknown Unicode script `Klingon`",
+                15,
+            ),
+            ("\"open", "unterminated quote", 0),
+            ("between 5 2 digit", "the range of repetitions is empty", 10),
+        ];
+        for (source, message, offset) in errors {
+            let error = hr::parse_dsl(source).err().unwrap();
+            assert_eq!((error.message.as_str(), error.offset), (message, offset));
+        }
+    }
+
+    #[test]
+    fn libraries_resolve_earlier_names_only() {
+        let library = hr::PatternLibrary::parse(
+            "octet = between 1 3 digit\nip = octet, exactly 3 (\".\", octet)\n",
+        )
+        .unwrap();
+        assert!(library.get("ip").unwrap().to_regex().is_match("10.0.0.255"));
+        let error = hr::PatternLibrary::parse("a = b\nb = digit\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "unknown function or pattern `b` at byte 4"
+        );
+        let error = hr::PatternLibrary::parse("a = digit\na = word\n").unwrap_err();
+        assert_eq!(error.to_string(), "`a` is already defined at byte 10");
+    }
+
+    #[test]
+    fn printing_and_parsing_give_back_the_same_pattern() {
+        let patterns = [
+            (hr::between(2, 5, hr::within_set(&['a', 'e'])).lazy()
+                + hr::or(&[hr::text("x"), hr::text("y z")]))
+            .to_string(),
+            hr::case_insensitive(
+                hr::capture(hr::word())
+                    + hr::one_or_more(hr::non_unicode_script(hr::UnicodeScript::Greek)),
+            )
+            .to_string(),
+            hr::xor(
+                hr::alphabetic(),
+                hr::and(hr::hexdigit(), hr::without_range('0'..='9')),
+            )
+            .to_string(),
+            (hr::zero_or_more(hr::text("ab") + hr::any())
+                + hr::exactly(4, hr::digit()).comment("the year")
+                + hr::text("\"quote\"\n\\"))
+            .to_string(),
+            r"(?im:a+)(?-u:\w)[^\d][[:^alpha:]a]x{1001}\pL".to_string(),
+        ];
+        for pattern in patterns {
+            let dsl = hr::nonescaped_text(&pattern).to_dsl();
+            let parsed = hr::parse_dsl(&dsl).unwrap().to_string();
+            assert_eq!(
+                regex_syntax::parse(&parsed).unwrap(),
+                regex_syntax::parse(&pattern).unwrap(),
+                "{} was printed as {}",
+                pattern,
+                dsl
+            );
+        }
+    }
+
+    #[test]
+    fn raw_bytes_round_trip() {
+        let regex_string = hr::text("caf")
+            + hr::one_or_more(hr::byte_range(0x80..=0xFF))
+            + hr::byte(0x00)
+            + hr::zero_or_more(hr::any_byte())
+            + hr::disable_unicode(hr::without_set(&['A']));
+        let dsl = regex_string.to_dsl();
+        assert_eq!(
+            dsl,
+            r#""caf", one_or_more byte_range 0x80..=0xFF, byte 0x00, zero_or_more any_byte, nonescaped_text "(?-u:[^A])""#
+        );
+        let parsed = hr::parse_bytes_dsl(&dsl).unwrap();
+        assert_eq!(parsed.to_dsl(), dsl);
+        for sample in [
+            &b"caf\xE9\x00"[..],
+            b"caf\x00",
+            b"caf\xFF\x00\xFFB",
+            b"caf\xFF\x00A",
+        ] {
+            assert_eq!(
+                parsed.to_bytes_regex().is_match(sample),
+                regex_string.to_bytes_regex().is_match(sample),
+                "{:?}",
+                sample
+            );
+        }
+        let error = hr::parse_dsl(&dsl).err().unwrap();
+        assert!(error.message.contains("parse_bytes_dsl"), "{}", error);
+    }
+
+    #[test]
+    fn printing_uses_builder_functions_where_they_exist() {
+        assert_eq!(
+            hr::nonescaped_text(r"(?im:a+)(?-u:\w)[^\d]x{300}x{1001}").to_dsl(),
+            r#"case_insensitive multi_line_mode one_or_more "a", disable_unicode word, nonescaped_text "[^\\d]", exactly 300 "x", nonescaped_text "x{1001}""#
+        );
+        assert_eq!(
+            hr::xor(
+                hr::alphabetic(),
+                hr::and(hr::hexdigit(), hr::without_range('0'..='9'))
+            )
+            .to_dsl(),
+            "xor alphabetic and hexdigit without_range '0'..='9'"
+        );
+    }
+
+    #[test]
+    fn builder_expressions_parse_like_code() {
+        let expression = r#"hr::named_capture(hr::one_or_more(hr::word()), "name") + or(&[text("a"), text("b")])
+            + hr::between(1, 3, within_set(&['x', 'y'])).lazy()
+            + and(digit(), within_range('0'..='5')).comment("low digit")
+            + non_unicode_category(UnicodeCategory::CurrencySymbol)"#;
+        let expected = hr::named_capture(hr::one_or_more(hr::word()), "name")
+            + hr::or(&[hr::text("a"), hr::text("b")])
+            + hr::between(1, 3, hr::within_set(&['x', 'y'])).lazy()
+            + hr::and(hr::digit(), hr::within_range('0'..='5')).comment("low digit")
+            + hr::non_unicode_category(hr::UnicodeCategory::CurrencySymbol);
+        assert_eq!(
+            hr::parse_builder_expression(expression)
+                .unwrap()
+                .to_string(),
+            expected.to_string()
+        );
+        let error = hr::parse_builder_expression("exactly 4 digit")
+            .err()
+            .unwrap();
+        assert_eq!(error.to_string(), "expected `(` at byte 8");
+    }
+}
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn nested_groups_take_the_innermost_color() {
+        let regex_string = hr::named_capture(
+            hr::capture(hr::one_or_more(hr::digit())) + hr::text(":") + hr::exactly(2, hr::digit()),
+            "time",
+        );
+        assert_eq!(
+            regex_string.highlight_ansi("at 9:30 or 10:15"),
+            concat!(
+                "at \x1b[1;4;33m9\x1b[0m\x1b[1;4;32m:30\x1b[0m",
+                " or \x1b[1;4;33m10\x1b[0m\x1b[1;4;32m:15\x1b[0m"
+            )
+        );
+    }
+
+    #[test]
+    fn html_is_nested_and_escaped() {
+        let regex_string = hr::text("<")
+            + hr::capture(hr::one_or_more(hr::word()))
+            + hr::zero_or_one(hr::named_capture(hr::text("/"), "closing"))
+            + hr::text(">");
+        let html = regex_string.highlight_html("a<b>&");
+        assert!(html.contains(concat!(
+            r#"<pre>a<mark class="match">&lt;"#,
+            r#"<mark class="group group-1" style="background-color: #b8e6b8" title="group 1">b</mark>"#,
+            r#"&gt;</mark>&amp;</pre>"#
+        )));
+        assert!(html.contains(r#"background-color: #b8e6b8">group 1</mark></li>"#));
+        assert!(html.contains(r#"background-color: #ffe08a">closing</mark></li>"#));
+    }
+
+    #[test]
+    fn text_without_matches_is_unchanged() {
+        let regex_string = hr::one_or_more(hr::digit());
+        assert_eq!(regex_string.highlight_ansi("no digits"), "no digits");
+        assert_eq!(
+            regex_string.highlight_html("a < b"),
+            r#"<div class="human-regex-highlight"><pre>a &lt; b</pre></div>"#
+        );
+    }
+    #[test]
+    fn compiled_regexes_highlight_like_the_pattern() {
+        let regex_string = hr::capture(hr::one_or_more(hr::digit())) + hr::text("h");
+        let regex = regex_string.to_regex();
+        for haystack in ["at 9h", "10h or 11h", "none"] {
+            assert_eq!(
+                hr::highlight_ansi(&regex, haystack),
+                regex_string.highlight_ansi(haystack)
+            );
+            assert_eq!(
+                hr::highlight_html(&regex, haystack),
+                regex_string.highlight_html(haystack)
+            );
+        }
+    }
+}
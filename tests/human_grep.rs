@@ -0,0 +1,107 @@
+#![cfg(feature = "grep")]
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    /// Create a fresh directory of files to search
+    fn files(name: &str, contents: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("human-grep-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        for (path, text) in contents {
+            let path = dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, text).unwrap();
+        }
+        dir
+    }
+
+    /// Run human-grep in a directory, returning its exit code and output
+    fn grep(dir: &PathBuf, args: &[&str]) -> (i32, String) {
+        let output = Command::new(env!("CARGO_BIN_EXE_human-grep"))
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        (
+            output.status.code().unwrap(),
+            String::from_utf8(output.stdout).unwrap(),
+        )
+    }
+
+    #[test]
+    fn context_count_and_only_matching() {
+        let dir = files("context", &[("log.txt", "a\nb 2024\nc\nd\ne\nf 1999\n")]);
+        let pattern = "exactly 4 digit";
+        assert_eq!(
+            grep(&dir, &["-n", "-C1", pattern, "log.txt"]),
+            (0, "1-a\n2:b 2024\n3-c\n--\n5-e\n6:f 1999\n".to_string())
+        );
+        assert_eq!(
+            grep(&dir, &["-c", pattern, "log.txt"]),
+            (0, "2\n".to_string())
+        );
+        assert_eq!(
+            grep(&dir, &["-o", pattern, "log.txt"]),
+            (0, "2024\n1999\n".to_string())
+        );
+        assert_eq!(grep(&dir, &["\"zzz\"", "log.txt"]), (1, String::new()));
+        assert_eq!(grep(&dir, &["exactly 4 digits", "log.txt"]).0, 2);
+    }
+
+    #[test]
+    fn libraries_and_extracted_columns() {
+        let dir = files(
+            "extract",
+            &[
+                ("dates.hr", "year = exactly 4 digit\ndate = capture year: year, \"-\", capture month: exactly 2 digit\n"),
+                ("notes.txt", "due 2024-02\nnothing\nfrom 1999-12 to 2000-01\n"),
+            ],
+        );
+        assert_eq!(
+            grep(&dir, &["-l", "dates.hr", "-x", "-n", "date", "notes.txt"]),
+            (
+                0,
+                "line\tyear\tmonth\n1\t2024\t02\n3\t1999\t12\n3\t2000\t01\n".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn recursive_search_respects_ignore_files() {
+        let dir = files(
+            "recursive",
+            &[
+                (".ignore", "skipped/\n"),
+                ("kept/a.txt", "x1\n"),
+                ("skipped/b.txt", "x2\n"),
+            ],
+        );
+        assert_eq!(
+            grep(&dir, &["-r", "\"x\", digit", "."]),
+            (0, "./kept/a.txt:x1\n".to_string())
+        );
+        assert_eq!(
+            grep(&dir, &["-r", "--no-ignore", "-c", "\"x\", digit", "."]),
+            (0, "./kept/a.txt:1\n./skipped/b.txt:1\n".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_utf8_lines_and_binary_files() {
+        let dir = files("binary", &[]);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("latin1.txt"), b"caf\xE9 1\nnext 2\r\n").unwrap();
+        std::fs::write(dir.join("data.bin"), b"\x00\x01 42\n").unwrap();
+        assert_eq!(
+            grep(&dir, &["-n", "digit", "latin1.txt"]),
+            (0, "1:caf\u{FFFD} 1\n2:next 2\n".to_string())
+        );
+        assert_eq!(
+            grep(&dir, &["digit", "data.bin"]),
+            (0, "Binary file data.bin matches\n".to_string())
+        );
+        assert_eq!(grep(&dir, &["\"x\"", "data.bin"]), (1, String::new()));
+    }
+}
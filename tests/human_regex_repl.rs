@@ -0,0 +1,87 @@
+#![cfg(feature = "repl")]
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+
+    /// Run the shell on some lines of input and return its output
+    fn repl(name: &str, input: &str) -> String {
+        let home =
+            std::env::temp_dir().join(format!("human-regex-repl-{}-{}", name, std::process::id()));
+        std::fs::create_dir_all(&home).unwrap();
+        let mut child = Command::new(env!("CARGO_BIN_EXE_human-regex-repl"))
+            .env("HOME", &home)
+            .current_dir(&home)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(input.as_bytes())
+            .unwrap();
+        String::from_utf8(child.wait_with_output().unwrap().stdout).unwrap()
+    }
+
+    #[test]
+    fn patterns_are_explained_and_tried_on_samples() {
+        let output = repl(
+            "samples",
+            concat!(
+                ":test hello\n",
+                "exactly(4, digit()) + text(\"-\") + named_capture(exactly(2, digit()), \"month\")\n",
+                ":test on 2024-02\n",
+                ":dsl\n",
+            ),
+        );
+        assert_eq!(
+            output,
+            concat!(
+                "no pattern yet, type one first\n",
+                "regex: (?:\\d){4}(?:\\-)(?P<month>(?:\\d){2})\n",
+                "  \\d{4}       # exactly 4 digits\n",
+                "  -           # \"-\"\n",
+                "  (?P<month>  # named group \"month\"\n",
+                "    \\d{2}     # exactly 2 digits\n",
+                "  )\n",
+                "no match  \"hello\"\n",
+                "match     \"on 2024-02\"  match = \"2024-02\", month = \"02\"\n",
+                "exactly 4 digit, \"-\", capture month: exactly 2 digit\n",
+            )
+        );
+    }
+
+    #[test]
+    fn corpora_are_saved_and_loaded() {
+        let output = repl(
+            "corpora",
+            ":test a1\n:test b\n:save corpus.txt\n:clear\n:load corpus.txt\none_or_more digit\n:quit\n:test never\n",
+        );
+        assert_eq!(
+            output,
+            concat!(
+                "no pattern yet, type one first\n",
+                "no pattern yet, type one first\n",
+                "saved 2 samples to corpus.txt\n",
+                "removed every sample\n",
+                "loaded 2 samples from corpus.txt\n",
+                "no pattern yet, type one first\n",
+                "regex: (?:\\d)+\n",
+                "  \\d+  # one or more digits\n",
+                "match     \"a1\"  match = \"1\"\n",
+                "no match  \"b\"\n",
+            )
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        assert_eq!(
+            repl("errors", "exactly 4 digitz\n"),
+            "  exactly 4 digitz\n            ^ unknown function or pattern `digitz`\n"
+        );
+    }
+}
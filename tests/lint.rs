@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn lazy_is_only_flagged_outside_of_repetitions() {
+        let kinds = |regex: String| -> Vec<hr::LintKind> {
+            hr::nonescaped_text(&regex)
+                .lint()
+                .iter()
+                .map(|lint| lint.kind)
+                .collect()
+        };
+        let lazy = vec![hr::LintKind::LazyWithoutRepetition];
+        assert_eq!(kinds(hr::digit().lazy().to_string()), lazy);
+        assert_eq!(kinds(hr::capture(hr::digit()).lazy().to_string()), lazy);
+        assert_eq!(
+            kinds(hr::one_or_more(hr::digit()).lazy().lazy().to_string()),
+            lazy
+        );
+        assert!(kinds(hr::one_or_more(hr::digit()).lazy().to_string()).is_empty());
+        assert!(kinds(hr::zero_or_one(hr::text("ab")).to_string()).is_empty());
+        assert!(kinds(hr::zero_or_one(hr::digit()).to_string()).is_empty());
+    }
+
+    #[test]
+    fn shadowed_branches_and_nested_repetitions() {
+        let lints = hr::or(&[hr::word(), hr::digit()]).lint();
+        assert_eq!(lints.len(), 1);
+        assert_eq!(
+            lints[0].to_string(),
+            "the branch `\\d` can never be chosen, because the earlier branch `\\w` already matches everything it does (at 6..8)"
+        );
+        // A longer branch after a shorter one can still be chosen when what follows needs it
+        assert!(hr::or(&["a", "ab"]).lint().is_empty());
+        let lints = hr::exactly(3, hr::exactly(2, hr::digit())).lint();
+        assert_eq!(lints[0].kind, hr::LintKind::NestedRepetition);
+        assert!(lints[0].message.contains("exactly(6)"));
+    }
+
+    #[test]
+    fn patterns_that_do_not_compile_are_explained() {
+        let lints = (hr::text("x") + hr::within_set::<&str>(&[])).lint();
+        assert_eq!(
+            (lints[0].kind, lints[0].span.clone()),
+            (hr::LintKind::EmptySet, 5..7)
+        );
+        let lints = hr::nonescaped_text(r"\p{Klingon}").lint();
+        assert_eq!(lints[0].kind, hr::LintKind::InvalidPattern);
+        let anchored_one_branch = hr::or(&[
+            hr::beginning() + hr::text("a") + hr::end(),
+            hr::beginning() + hr::text("b"),
+        ]);
+        let lints = anchored_one_branch.lint_for_validation();
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].kind, hr::LintKind::Unanchored);
+    }
+}
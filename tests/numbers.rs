@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    /// Compile a pattern that must match the whole haystack
+    fn whole(pattern: hr::HumanRegex) -> regex::Regex {
+        (hr::beginning() + pattern + hr::end()).to_regex()
+    }
+
+    /// Write a number with a separator between groups of three digits
+    fn grouped(number: i64, separator: char) -> String {
+        let digits = number.unsigned_abs().to_string();
+        let mut written = String::new();
+        for (idx, digit) in digits.chars().enumerate() {
+            if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+                written.push(separator);
+            }
+            written.push(digit);
+        }
+        match number < 0 {
+            true => format!("-{}", written),
+            false => written,
+        }
+    }
+
+    #[test]
+    fn integers_match_exactly_their_range() {
+        for (lo, hi) in [
+            (0, 255),
+            (-128, 127),
+            (17, 4321),
+            (-1000, -37),
+            (0, 0),
+            (1, 65535),
+            (99, 101),
+        ] {
+            let regex = whole(hr::number_in_range(lo..=hi));
+            for number in (lo - 1500)..=(hi + 1500) {
+                assert_eq!(
+                    regex.is_match(&number.to_string()),
+                    (lo..=hi).contains(&number),
+                    "{} in {}..={}",
+                    number,
+                    lo,
+                    hi
+                );
+            }
+            assert!(!regex.is_match(&format!("0{}", hi.abs())));
+        }
+        let port = hr::number_in_range(0..=65535).to_regex();
+        assert_eq!(port.find("port 8080/tcp").unwrap().as_str(), "8080");
+        assert_eq!(port.find("x65535").unwrap().as_str(), "65535");
+    }
+
+    #[test]
+    fn options_change_how_integers_are_written() {
+        let options = hr::NumberOptions {
+            zero_pad: 4,
+            plus_sign: true,
+            ..Default::default()
+        };
+        let regex = whole(hr::number_in_range_with(-50..=12000, &options));
+        for number in -60i64..=12010 {
+            let padded = format!("{:04}", number.abs());
+            let signed = match number < 0 {
+                true => format!("-{}", padded),
+                false => format!("+{}", padded),
+            };
+            let expected = (-50..=12000).contains(&number);
+            assert_eq!(regex.is_match(&signed), expected, "{}", signed);
+            assert_eq!(regex.is_match(&signed.replace('+', "")), expected);
+            if number.abs() < 1000 && number != 0 {
+                assert!(!regex.is_match(&number.to_string()), "{}", number);
+            }
+        }
+
+        let options = hr::NumberOptions {
+            leading_zeros: true,
+            ..Default::default()
+        };
+        let regex = whole(hr::number_in_range_with(1..=31, &options));
+        assert!(regex.is_match("01") && regex.is_match("0009") && regex.is_match("31"));
+        assert!(!regex.is_match("00") && !regex.is_match("032"));
+
+        let options = hr::NumberOptions {
+            thousands_separator: Some('.'),
+            ..Default::default()
+        };
+        let regex = whole(hr::number_in_range_with(-2_500_000..=1_234_567, &options));
+        for number in [
+            -2_500_001, -2_500_000, -1000, -999, 0, 999, 1000, 1_234_567, 1_234_568,
+        ] {
+            let expected = (-2_500_000..=1_234_567).contains(&number);
+            assert_eq!(
+                regex.is_match(&grouped(number, '.')),
+                expected,
+                "{}",
+                number
+            );
+        }
+        assert!(!regex.is_match("1234"));
+        assert!(!regex.is_match("1x234"));
+    }
+
+    #[test]
+    fn decimals_match_exactly_their_range() {
+        let regex = whole(hr::decimal_in_range(-12.5..=3.75, 2));
+        for hundredths in -1400..=500 {
+            let value = hundredths as f64 / 100.0;
+            let expected = (-12.5..=3.75).contains(&value);
+            let full = format!("{:.2}", value);
+            let trimmed = full.trim_end_matches('0').trim_end_matches('.');
+            for written in [full.as_str(), trimmed] {
+                let written = match written {
+                    "-0" | "-0.00" => continue,
+                    written => written,
+                };
+                assert_eq!(regex.is_match(written), expected, "{}", written);
+            }
+        }
+        for rejected in ["1.234", "1.", ".5", "-0", "-0.00", "03.5", "1,5"] {
+            assert!(!regex.is_match(rejected), "{}", rejected);
+        }
+
+        let options = hr::NumberOptions {
+            thousands_separator: Some(','),
+            ..Default::default()
+        };
+        let regex = whole(hr::decimal_in_range_with(999.5..=1000.25, 1, &options));
+        assert!(regex.is_match("999.5") && regex.is_match("1,000") && regex.is_match("1,000.2"));
+        assert!(!regex.is_match("999.4") && !regex.is_match("1,000.3") && !regex.is_match("1000"));
+        assert!(whole(hr::decimal_in_range(0.0..=1.0, 0)).is_match("1"));
+        assert!(!whole(hr::decimal_in_range(0.0..=1.0, 0)).is_match("0.5"));
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    /// Compile a pattern with the given match semantics and default options
+    fn compile(pattern: &hr::HumanRegex, match_kind: hr::MatchKind) -> hr::ConfiguredRegex {
+        pattern
+            .to_regex_with(&hr::Options {
+                match_kind,
+                ..Default::default()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn every_match_kind_finds_every_match() {
+        let cases = [
+            (hr::text("a").to_string(), "aaa", vec![0..1, 1..2, 2..3]),
+            (
+                hr::or(&[hr::text("ab"), hr::text("a")]).to_string(),
+                "xabya",
+                vec![1..3, 4..5],
+            ),
+            (
+                hr::one_or_more(hr::digit()).to_string(),
+                "1 22 333",
+                vec![0..1, 2..4, 5..8],
+            ),
+        ];
+        for (pattern, haystack, expected) in cases {
+            for match_kind in [
+                hr::MatchKind::LeftmostFirst,
+                hr::MatchKind::LeftmostLongest,
+                hr::MatchKind::All,
+            ] {
+                let found: Vec<_> = compile(&hr::nonescaped_text(&pattern), match_kind)
+                    .find_iter(haystack)
+                    .map(|m| m.range())
+                    .collect();
+                assert_eq!(found, expected, "{} with {:?}", pattern, match_kind);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_matches_follow_the_regex_crate() {
+        let pattern = hr::zero_or_more(hr::text("a"));
+        for haystack in ["baab", "", "aaa", "bb", "éa"] {
+            let expected: Vec<_> = pattern
+                .to_regex()
+                .find_iter(haystack)
+                .map(|m| m.range())
+                .collect();
+            for match_kind in [hr::MatchKind::LeftmostFirst, hr::MatchKind::LeftmostLongest] {
+                let found: Vec<_> = compile(&pattern, match_kind)
+                    .find_iter(haystack)
+                    .map(|m| m.range())
+                    .collect();
+                assert_eq!(found, expected, "{:?} with {:?}", haystack, match_kind);
+            }
+        }
+    }
+
+    #[test]
+    fn exceeding_a_limit_is_an_error() {
+        let pattern = hr::exactly(100, hr::word());
+        let small = hr::Options {
+            size_limit: 1000,
+            ..Default::default()
+        };
+        let error = pattern.to_regex_with(&small).unwrap_err();
+        assert!(error.size_limit().is_some(), "{}", error);
+        let shallow = hr::Options {
+            nest_limit: 2,
+            ..Default::default()
+        };
+        assert!(hr::capture(hr::capture(hr::capture(hr::text("a"))))
+            .to_regex_with(&shallow)
+            .is_err());
+        assert!(pattern.to_regex_with(&hr::Options::default()).is_ok());
+    }
+}
@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn captures_and_counts_are_labeled() {
+        let regex_string = hr::named_capture(hr::between(2, 4, hr::digit()), "year")
+            + hr::capture(hr::one_or_more(hr::word()).lazy());
+        let svg = regex_string.to_railroad_svg();
+        assert!(svg.contains(">year</text>"));
+        assert!(svg.contains(">group 2</text>"));
+        assert!(svg.contains(">2 to 4 times</text>"));
+        assert!(svg.contains(">lazy</text>"));
+        assert_eq!(svg.matches("class=\"loop\"").count(), 2);
+    }
+
+    #[test]
+    fn branches_and_assertions_are_drawn() {
+        let regex_string = hr::beginning()
+            + hr::or(&[hr::text("cat"), hr::text("dog")])
+            + hr::zero_or_one(hr::text("s"))
+            + hr::end();
+        let svg = regex_string.to_railroad_svg();
+        assert!(svg.contains(">start of the text</text>"));
+        assert!(svg.contains(">end of the text</text>"));
+        assert_eq!(svg.matches("class=\"assertion\"").count(), 2);
+        assert_eq!(svg.matches("class=\"literal\"").count(), 3);
+        assert_eq!(svg.matches("<svg").count(), svg.matches("</svg>").count());
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        let svg = hr::text("<a&b>").to_railroad_svg();
+        assert!(svg.contains(">\"&lt;a&amp;b&gt;\"</text>"));
+    }
+}
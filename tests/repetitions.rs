@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    #[test]
+    fn repeat_builds_the_same_regex_as_the_named_functions() {
+        let digit = hr::digit;
+        let cases = [
+            (hr::repeat(4, digit()), hr::exactly(4, digit())),
+            (hr::repeat(3.., digit()), hr::at_least(3, digit())),
+            (hr::repeat(2..=5, digit()), hr::between(2, 5, digit())),
+            (hr::repeat(2..6, digit()), hr::between(2, 5, digit())),
+            (hr::repeat(..=4, digit()), hr::between(0, 4, digit())),
+            (hr::repeat(0.., digit()), hr::zero_or_more(digit())),
+            (hr::repeat(.., digit()), hr::zero_or_more(digit())),
+            (hr::repeat(0..=1, digit()), hr::zero_or_one(digit())),
+            (
+                hr::repeat_lazy(1.., digit()),
+                hr::one_or_more(digit()).lazy(),
+            ),
+        ];
+        for (repeated, expected) in cases {
+            assert_eq!(repeated.to_string(), expected.to_string());
+        }
+    }
+
+    #[test]
+    fn counts_go_up_to_the_engine_limit() {
+        let regex = hr::repeat(hr::MAX_REPETITIONS, hr::text("a")).to_regex();
+        assert!(regex.is_match(&"a".repeat(1000)));
+        assert!(!regex.is_match(&"a".repeat(999)));
+        assert!(hr::between(250, 300, hr::digit())
+            .to_regex()
+            .is_match(&"7".repeat(300)));
+    }
+
+    #[test]
+    #[should_panic(expected = "a repetition count of 1001 is larger than the limit of 1000")]
+    fn counts_past_the_limit_panic() {
+        hr::at_least(1001, hr::digit());
+    }
+
+    #[test]
+    #[should_panic(expected = "the range of repetitions 5..=2 is empty")]
+    fn empty_ranges_panic() {
+        #[allow(clippy::reversed_empty_ranges)]
+        hr::repeat(5..=2, hr::digit());
+    }
+    #[test]
+    #[should_panic(expected = "the range of repetitions 5..=2 is empty")]
+    fn reversed_between_bounds_panic() {
+        hr::between(5, 2, hr::digit());
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use hr::TraceAction;
+    use human_regex as hr;
+
+    #[test]
+    fn trace_finds_the_same_match_as_regex() {
+        let patterns = [
+            r"<.+>",
+            r"<.+?>",
+            r"(a|ab)(c|bcd)(d*)",
+            r"(?i)hello\s+world",
+            r"(?m)^b$",
+            r"x{2,3}?y",
+            r"(a*)*b",
+            r"(?P<y>\d+)(?P<z>\D)?",
+            r"\B\w",
+        ];
+        let haystacks = [
+            "<div><h1>Wow</h1></div>",
+            "abcd",
+            "xx HELLO   World",
+            "a\nb\nc",
+            "xxxy",
+            "aab",
+            "123x",
+        ];
+        for pattern in patterns {
+            let regex = regex::Regex::new(pattern).unwrap();
+            for haystack in haystacks {
+                let trace = hr::nonescaped_text(pattern).trace(haystack);
+                let captures: Vec<_> = match regex.captures(haystack) {
+                    Some(captures) => captures
+                        .iter()
+                        .map(|group| group.map(|m| m.range()))
+                        .collect(),
+                    None => vec![None; regex.captures_len()],
+                };
+                assert_eq!(trace.captures, captures, "{} on {:?}", pattern, haystack);
+            }
+        }
+    }
+
+    #[test]
+    fn greedy_repetitions_run_ahead_and_lazy_ones_do_not() {
+        let haystack = "<div><h1>Wow, so cool!</h1></div>";
+        let iterations = |regex_string: hr::HumanRegex| {
+            let trace = regex_string.trace(haystack);
+            let most = trace
+                .events
+                .iter()
+                .filter_map(|event| match event.action {
+                    TraceAction::Iteration(count) => Some(count),
+                    _ => None,
+                })
+                .max();
+            (trace.found, most)
+        };
+        let greedy = hr::text("<") + hr::one_or_more(hr::any()) + hr::text(">");
+        let lazy = hr::text("<") + hr::one_or_more(hr::any()).lazy() + hr::text(">");
+        assert_eq!(iterations(greedy), (Some(0..33), Some(33)));
+        assert_eq!(iterations(lazy), (Some(0..5), Some(3)));
+    }
+
+    #[test]
+    fn runaway_traces_are_truncated() {
+        let regex_string =
+            hr::zero_or_more(hr::or(&[hr::text("a"), hr::text("a")])) + hr::text("b");
+        let trace = regex_string.trace(&"a".repeat(40));
+        assert!(trace.truncated);
+        assert_eq!(trace.found, None);
+        assert!(trace.events.len() >= hr::MAX_TRACE_EVENTS);
+        assert!(trace
+            .log()
+            .ends_with(&format!("gave up after {} events\n", trace.events.len())));
+    }
+}
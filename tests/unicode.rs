@@ -0,0 +1,749 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+    use human_regex::{CharSet, UnicodeCategory, UnicodeProperty, UnicodeScript};
+
+    // The position of every variant in its declaration. A new variant fails to compile here until
+    // it gets the next index, which `all_arrays_list_every_variant` then expects in `ALL`.
+    fn unicode_category_index(category: UnicodeCategory) -> usize {
+        match category {
+            UnicodeCategory::Letter => 0,
+            UnicodeCategory::LowercaseLetter => 1,
+            UnicodeCategory::UppercaseLetter => 2,
+            UnicodeCategory::TitlecaseLetter => 3,
+            UnicodeCategory::CasedLetter => 4,
+            UnicodeCategory::ModifierLetter => 5,
+            UnicodeCategory::OtherLetter => 6,
+            UnicodeCategory::Mark => 7,
+            UnicodeCategory::NonSpacingMark => 8,
+            UnicodeCategory::SpaceCombiningMark => 9,
+            UnicodeCategory::EnclosingMark => 10,
+            UnicodeCategory::Separator => 11,
+            UnicodeCategory::SpaceSeparator => 12,
+            UnicodeCategory::LineSeparator => 13,
+            UnicodeCategory::ParagraphSeparator => 14,
+            UnicodeCategory::Symbol => 15,
+            UnicodeCategory::MathSymbol => 16,
+            UnicodeCategory::CurrencySymbol => 17,
+            UnicodeCategory::ModifierSymbol => 18,
+            UnicodeCategory::OtherSymbol => 19,
+            UnicodeCategory::Number => 20,
+            UnicodeCategory::DecimalDigitNumber => 21,
+            UnicodeCategory::LetterNumber => 22,
+            UnicodeCategory::OtherNumber => 23,
+            UnicodeCategory::Punctuation => 24,
+            UnicodeCategory::DashPunctuation => 25,
+            UnicodeCategory::OpenPunctuation => 26,
+            UnicodeCategory::ClosePunctuation => 27,
+            UnicodeCategory::InitialPunctuation => 28,
+            UnicodeCategory::FinalPunctuation => 29,
+            UnicodeCategory::ConnectorPunctuation => 30,
+            UnicodeCategory::OtherPunctuation => 31,
+            UnicodeCategory::Other => 32,
+            UnicodeCategory::Control => 33,
+            UnicodeCategory::Format => 34,
+            UnicodeCategory::PrivateUse => 35,
+            UnicodeCategory::Unassigned => 36,
+        }
+    }
+
+    fn unicode_script_index(script: UnicodeScript) -> usize {
+        match script {
+            UnicodeScript::Adlam => 0,
+            UnicodeScript::Ahom => 1,
+            UnicodeScript::AnatolianHieroglyphs => 2,
+            UnicodeScript::Arabic => 3,
+            UnicodeScript::Armenian => 4,
+            UnicodeScript::Avestan => 5,
+            UnicodeScript::Balinese => 6,
+            UnicodeScript::Bamum => 7,
+            UnicodeScript::BassaVah => 8,
+            UnicodeScript::Batak => 9,
+            UnicodeScript::Bengali => 10,
+            UnicodeScript::Bhaiksuki => 11,
+            UnicodeScript::Bopomofo => 12,
+            UnicodeScript::Brahmi => 13,
+            UnicodeScript::Braille => 14,
+            UnicodeScript::Buginese => 15,
+            UnicodeScript::Buhid => 16,
+            UnicodeScript::CanadianAboriginal => 17,
+            UnicodeScript::Carian => 18,
+            UnicodeScript::CaucasianAlbanian => 19,
+            UnicodeScript::Chakma => 20,
+            UnicodeScript::Cham => 21,
+            UnicodeScript::Cherokee => 22,
+            UnicodeScript::Chorasmian => 23,
+            UnicodeScript::Common => 24,
+            UnicodeScript::Coptic => 25,
+            UnicodeScript::Cuneiform => 26,
+            UnicodeScript::Cypriot => 27,
+            UnicodeScript::CyproMinoan => 28,
+            UnicodeScript::Cyrillic => 29,
+            UnicodeScript::Deseret => 30,
+            UnicodeScript::Devanagari => 31,
+            UnicodeScript::DivesAkuru => 32,
+            UnicodeScript::Dogra => 33,
+            UnicodeScript::Duployan => 34,
+            UnicodeScript::EgyptianHieroglyphs => 35,
+            UnicodeScript::Elbasan => 36,
+            UnicodeScript::Elymaic => 37,
+            UnicodeScript::Ethiopic => 38,
+            UnicodeScript::Garay => 39,
+            UnicodeScript::Georgian => 40,
+            UnicodeScript::Glagolitic => 41,
+            UnicodeScript::Gothic => 42,
+            UnicodeScript::Grantha => 43,
+            UnicodeScript::Greek => 44,
+            UnicodeScript::Gujarati => 45,
+            UnicodeScript::GunjalaGondi => 46,
+            UnicodeScript::Gurmukhi => 47,
+            UnicodeScript::GurungKhema => 48,
+            UnicodeScript::Han => 49,
+            UnicodeScript::Hangul => 50,
+            UnicodeScript::HanifiRohingya => 51,
+            UnicodeScript::Hanunoo => 52,
+            UnicodeScript::Hatran => 53,
+            UnicodeScript::Hebrew => 54,
+            UnicodeScript::Hiragana => 55,
+            UnicodeScript::ImperialAramaic => 56,
+            UnicodeScript::Inherited => 57,
+            UnicodeScript::InscriptionalPahlavi => 58,
+            UnicodeScript::InscriptionalParthian => 59,
+            UnicodeScript::Javanese => 60,
+            UnicodeScript::Kaithi => 61,
+            UnicodeScript::Kannada => 62,
+            UnicodeScript::Katakana => 63,
+            UnicodeScript::Kawi => 64,
+            UnicodeScript::KayahLi => 65,
+            UnicodeScript::Kharoshthi => 66,
+            UnicodeScript::KhitanSmallScript => 67,
+            UnicodeScript::Khmer => 68,
+            UnicodeScript::Khojki => 69,
+            UnicodeScript::Khudawadi => 70,
+            UnicodeScript::KiratRai => 71,
+            UnicodeScript::Lao => 72,
+            UnicodeScript::Latin => 73,
+            UnicodeScript::Lepcha => 74,
+            UnicodeScript::Limbu => 75,
+            UnicodeScript::LinearA => 76,
+            UnicodeScript::LinearB => 77,
+            UnicodeScript::Lisu => 78,
+            UnicodeScript::Lycian => 79,
+            UnicodeScript::Lydian => 80,
+            UnicodeScript::Mahajani => 81,
+            UnicodeScript::Makasar => 82,
+            UnicodeScript::Malayalam => 83,
+            UnicodeScript::Mandaic => 84,
+            UnicodeScript::Manichaean => 85,
+            UnicodeScript::Marchen => 86,
+            UnicodeScript::MasaramGondi => 87,
+            UnicodeScript::Medefaidrin => 88,
+            UnicodeScript::MeeteiMayek => 89,
+            UnicodeScript::MendeKikakui => 90,
+            UnicodeScript::MeroiticCursive => 91,
+            UnicodeScript::MeroiticHieroglyphs => 92,
+            UnicodeScript::Miao => 93,
+            UnicodeScript::Modi => 94,
+            UnicodeScript::Mongolian => 95,
+            UnicodeScript::Mro => 96,
+            UnicodeScript::Multani => 97,
+            UnicodeScript::Myanmar => 98,
+            UnicodeScript::Nabataean => 99,
+            UnicodeScript::NagMundari => 100,
+            UnicodeScript::Nandinagari => 101,
+            UnicodeScript::NewTaiLue => 102,
+            UnicodeScript::Newa => 103,
+            UnicodeScript::Nko => 104,
+            UnicodeScript::Nushu => 105,
+            UnicodeScript::NyiakengPuachueHmong => 106,
+            UnicodeScript::Ogham => 107,
+            UnicodeScript::OlChiki => 108,
+            UnicodeScript::OlOnal => 109,
+            UnicodeScript::OldHungarian => 110,
+            UnicodeScript::OldItalic => 111,
+            UnicodeScript::OldNorthArabian => 112,
+            UnicodeScript::OldPermic => 113,
+            UnicodeScript::OldPersian => 114,
+            UnicodeScript::OldSogdian => 115,
+            UnicodeScript::OldSouthArabian => 116,
+            UnicodeScript::OldTurkic => 117,
+            UnicodeScript::OldUyghur => 118,
+            UnicodeScript::Oriya => 119,
+            UnicodeScript::Osage => 120,
+            UnicodeScript::Osmanya => 121,
+            UnicodeScript::PahawhHmong => 122,
+            UnicodeScript::Palmyrene => 123,
+            UnicodeScript::PauCinHau => 124,
+            UnicodeScript::PhagsPa => 125,
+            UnicodeScript::Phoenician => 126,
+            UnicodeScript::PsalterPahlavi => 127,
+            UnicodeScript::Rejang => 128,
+            UnicodeScript::Runic => 129,
+            UnicodeScript::Samaritan => 130,
+            UnicodeScript::Saurashtra => 131,
+            UnicodeScript::Sharada => 132,
+            UnicodeScript::Shavian => 133,
+            UnicodeScript::Siddham => 134,
+            UnicodeScript::SignWriting => 135,
+            UnicodeScript::Sinhala => 136,
+            UnicodeScript::Sogdian => 137,
+            UnicodeScript::SoraSompeng => 138,
+            UnicodeScript::Soyombo => 139,
+            UnicodeScript::Sundanese => 140,
+            UnicodeScript::Sunuwar => 141,
+            UnicodeScript::SylotiNagri => 142,
+            UnicodeScript::Syriac => 143,
+            UnicodeScript::Tagalog => 144,
+            UnicodeScript::Tagbanwa => 145,
+            UnicodeScript::TaiLe => 146,
+            UnicodeScript::TaiTham => 147,
+            UnicodeScript::TaiViet => 148,
+            UnicodeScript::Takri => 149,
+            UnicodeScript::Tamil => 150,
+            UnicodeScript::Tangsa => 151,
+            UnicodeScript::Tangut => 152,
+            UnicodeScript::Telugu => 153,
+            UnicodeScript::Thaana => 154,
+            UnicodeScript::Thai => 155,
+            UnicodeScript::Tibetan => 156,
+            UnicodeScript::Tifinagh => 157,
+            UnicodeScript::Tirhuta => 158,
+            UnicodeScript::Todhri => 159,
+            UnicodeScript::Toto => 160,
+            UnicodeScript::TuluTigalari => 161,
+            UnicodeScript::Ugaritic => 162,
+            UnicodeScript::Vai => 163,
+            UnicodeScript::Vithkuqi => 164,
+            UnicodeScript::Wancho => 165,
+            UnicodeScript::WarangCiti => 166,
+            UnicodeScript::Yezidi => 167,
+            UnicodeScript::Yi => 168,
+            UnicodeScript::ZanabazarSquare => 169,
+        }
+    }
+
+    fn unicode_property_index(property: UnicodeProperty) -> usize {
+        match property {
+            UnicodeProperty::AsciiHexDigit => 0,
+            UnicodeProperty::Alphabetic => 1,
+            UnicodeProperty::BidiControl => 2,
+            UnicodeProperty::BidiMirrored => 3,
+            UnicodeProperty::CaseIgnorable => 4,
+            UnicodeProperty::Cased => 5,
+            UnicodeProperty::ChangesWhenCasefolded => 6,
+            UnicodeProperty::ChangesWhenCasemapped => 7,
+            UnicodeProperty::ChangesWhenLowercased => 8,
+            UnicodeProperty::ChangesWhenTitlecased => 9,
+            UnicodeProperty::ChangesWhenUppercased => 10,
+            UnicodeProperty::Dash => 11,
+            UnicodeProperty::DefaultIgnorableCodePoint => 12,
+            UnicodeProperty::Deprecated => 13,
+            UnicodeProperty::Diacritic => 14,
+            UnicodeProperty::Emoji => 15,
+            UnicodeProperty::EmojiComponent => 16,
+            UnicodeProperty::EmojiModifier => 17,
+            UnicodeProperty::EmojiModifierBase => 18,
+            UnicodeProperty::EmojiPresentation => 19,
+            UnicodeProperty::ExtendedPictographic => 20,
+            UnicodeProperty::Extender => 21,
+            UnicodeProperty::GraphemeBase => 22,
+            UnicodeProperty::GraphemeExtend => 23,
+            UnicodeProperty::GraphemeLink => 24,
+            UnicodeProperty::HexDigit => 25,
+            UnicodeProperty::Hyphen => 26,
+            UnicodeProperty::IdsBinaryOperator => 27,
+            UnicodeProperty::IdsTrinaryOperator => 28,
+            UnicodeProperty::IdsUnaryOperator => 29,
+            UnicodeProperty::IdCompatMathContinue => 30,
+            UnicodeProperty::IdCompatMathStart => 31,
+            UnicodeProperty::IdContinue => 32,
+            UnicodeProperty::IdStart => 33,
+            UnicodeProperty::Ideographic => 34,
+            UnicodeProperty::JoinControl => 35,
+            UnicodeProperty::LogicalOrderException => 36,
+            UnicodeProperty::Lowercase => 37,
+            UnicodeProperty::Math => 38,
+            UnicodeProperty::ModifierCombiningMark => 39,
+            UnicodeProperty::NoncharacterCodePoint => 40,
+            UnicodeProperty::OtherAlphabetic => 41,
+            UnicodeProperty::OtherDefaultIgnorableCodePoint => 42,
+            UnicodeProperty::OtherGraphemeExtend => 43,
+            UnicodeProperty::OtherIdContinue => 44,
+            UnicodeProperty::OtherIdStart => 45,
+            UnicodeProperty::OtherLowercase => 46,
+            UnicodeProperty::OtherMath => 47,
+            UnicodeProperty::OtherUppercase => 48,
+            UnicodeProperty::PatternSyntax => 49,
+            UnicodeProperty::PatternWhiteSpace => 50,
+            UnicodeProperty::PrependedConcatenationMark => 51,
+            UnicodeProperty::QuotationMark => 52,
+            UnicodeProperty::Radical => 53,
+            UnicodeProperty::RegionalIndicator => 54,
+            UnicodeProperty::SentenceTerminal => 55,
+            UnicodeProperty::SoftDotted => 56,
+            UnicodeProperty::TerminalPunctuation => 57,
+            UnicodeProperty::UnifiedIdeograph => 58,
+            UnicodeProperty::Uppercase => 59,
+            UnicodeProperty::VariationSelector => 60,
+            UnicodeProperty::WhiteSpace => 61,
+            UnicodeProperty::XidContinue => 62,
+            UnicodeProperty::XidStart => 63,
+        }
+    }
+
+    // The names regex-syntax resolves in `\p{..}`, copied from its `general_category`, `script` and
+    // `property_bool` tables.
+    const CATEGORIES: [&str; 37] = [
+        "Cased_Letter",
+        "Close_Punctuation",
+        "Connector_Punctuation",
+        "Control",
+        "Currency_Symbol",
+        "Dash_Punctuation",
+        "Decimal_Number",
+        "Enclosing_Mark",
+        "Final_Punctuation",
+        "Format",
+        "Initial_Punctuation",
+        "Letter",
+        "Letter_Number",
+        "Line_Separator",
+        "Lowercase_Letter",
+        "Mark",
+        "Math_Symbol",
+        "Modifier_Letter",
+        "Modifier_Symbol",
+        "Nonspacing_Mark",
+        "Number",
+        "Open_Punctuation",
+        "Other",
+        "Other_Letter",
+        "Other_Number",
+        "Other_Punctuation",
+        "Other_Symbol",
+        "Paragraph_Separator",
+        "Private_Use",
+        "Punctuation",
+        "Separator",
+        "Space_Separator",
+        "Spacing_Mark",
+        "Symbol",
+        "Titlecase_Letter",
+        "Unassigned",
+        "Uppercase_Letter",
+    ];
+
+    const SCRIPTS: [&str; 170] = [
+        "Adlam",
+        "Ahom",
+        "Anatolian_Hieroglyphs",
+        "Arabic",
+        "Armenian",
+        "Avestan",
+        "Balinese",
+        "Bamum",
+        "Bassa_Vah",
+        "Batak",
+        "Bengali",
+        "Bhaiksuki",
+        "Bopomofo",
+        "Brahmi",
+        "Braille",
+        "Buginese",
+        "Buhid",
+        "Canadian_Aboriginal",
+        "Carian",
+        "Caucasian_Albanian",
+        "Chakma",
+        "Cham",
+        "Cherokee",
+        "Chorasmian",
+        "Common",
+        "Coptic",
+        "Cuneiform",
+        "Cypriot",
+        "Cypro_Minoan",
+        "Cyrillic",
+        "Deseret",
+        "Devanagari",
+        "Dives_Akuru",
+        "Dogra",
+        "Duployan",
+        "Egyptian_Hieroglyphs",
+        "Elbasan",
+        "Elymaic",
+        "Ethiopic",
+        "Garay",
+        "Georgian",
+        "Glagolitic",
+        "Gothic",
+        "Grantha",
+        "Greek",
+        "Gujarati",
+        "Gunjala_Gondi",
+        "Gurmukhi",
+        "Gurung_Khema",
+        "Han",
+        "Hangul",
+        "Hanifi_Rohingya",
+        "Hanunoo",
+        "Hatran",
+        "Hebrew",
+        "Hiragana",
+        "Imperial_Aramaic",
+        "Inherited",
+        "Inscriptional_Pahlavi",
+        "Inscriptional_Parthian",
+        "Javanese",
+        "Kaithi",
+        "Kannada",
+        "Katakana",
+        "Kawi",
+        "Kayah_Li",
+        "Kharoshthi",
+        "Khitan_Small_Script",
+        "Khmer",
+        "Khojki",
+        "Khudawadi",
+        "Kirat_Rai",
+        "Lao",
+        "Latin",
+        "Lepcha",
+        "Limbu",
+        "Linear_A",
+        "Linear_B",
+        "Lisu",
+        "Lycian",
+        "Lydian",
+        "Mahajani",
+        "Makasar",
+        "Malayalam",
+        "Mandaic",
+        "Manichaean",
+        "Marchen",
+        "Masaram_Gondi",
+        "Medefaidrin",
+        "Meetei_Mayek",
+        "Mende_Kikakui",
+        "Meroitic_Cursive",
+        "Meroitic_Hieroglyphs",
+        "Miao",
+        "Modi",
+        "Mongolian",
+        "Mro",
+        "Multani",
+        "Myanmar",
+        "Nabataean",
+        "Nag_Mundari",
+        "Nandinagari",
+        "New_Tai_Lue",
+        "Newa",
+        "Nko",
+        "Nushu",
+        "Nyiakeng_Puachue_Hmong",
+        "Ogham",
+        "Ol_Chiki",
+        "Ol_Onal",
+        "Old_Hungarian",
+        "Old_Italic",
+        "Old_North_Arabian",
+        "Old_Permic",
+        "Old_Persian",
+        "Old_Sogdian",
+        "Old_South_Arabian",
+        "Old_Turkic",
+        "Old_Uyghur",
+        "Oriya",
+        "Osage",
+        "Osmanya",
+        "Pahawh_Hmong",
+        "Palmyrene",
+        "Pau_Cin_Hau",
+        "Phags_Pa",
+        "Phoenician",
+        "Psalter_Pahlavi",
+        "Rejang",
+        "Runic",
+        "Samaritan",
+        "Saurashtra",
+        "Sharada",
+        "Shavian",
+        "Siddham",
+        "SignWriting",
+        "Sinhala",
+        "Sogdian",
+        "Sora_Sompeng",
+        "Soyombo",
+        "Sundanese",
+        "Sunuwar",
+        "Syloti_Nagri",
+        "Syriac",
+        "Tagalog",
+        "Tagbanwa",
+        "Tai_Le",
+        "Tai_Tham",
+        "Tai_Viet",
+        "Takri",
+        "Tamil",
+        "Tangsa",
+        "Tangut",
+        "Telugu",
+        "Thaana",
+        "Thai",
+        "Tibetan",
+        "Tifinagh",
+        "Tirhuta",
+        "Todhri",
+        "Toto",
+        "Tulu_Tigalari",
+        "Ugaritic",
+        "Vai",
+        "Vithkuqi",
+        "Wancho",
+        "Warang_Citi",
+        "Yezidi",
+        "Yi",
+        "Zanabazar_Square",
+    ];
+
+    const PROPERTIES: [&str; 65] = [
+        "ASCII_Hex_Digit",
+        "Alphabetic",
+        "Bidi_Control",
+        "Bidi_Mirrored",
+        "Case_Ignorable",
+        "Cased",
+        "Changes_When_Casefolded",
+        "Changes_When_Casemapped",
+        "Changes_When_Lowercased",
+        "Changes_When_Titlecased",
+        "Changes_When_Uppercased",
+        "Dash",
+        "Default_Ignorable_Code_Point",
+        "Deprecated",
+        "Diacritic",
+        "Emoji",
+        "Emoji_Component",
+        "Emoji_Modifier",
+        "Emoji_Modifier_Base",
+        "Emoji_Presentation",
+        "Extended_Pictographic",
+        "Extender",
+        "Grapheme_Base",
+        "Grapheme_Extend",
+        "Grapheme_Link",
+        "Hex_Digit",
+        "Hyphen",
+        "IDS_Binary_Operator",
+        "IDS_Trinary_Operator",
+        "IDS_Unary_Operator",
+        "ID_Compat_Math_Continue",
+        "ID_Compat_Math_Start",
+        "ID_Continue",
+        "ID_Start",
+        "Ideographic",
+        "InCB",
+        "Join_Control",
+        "Logical_Order_Exception",
+        "Lowercase",
+        "Math",
+        "Modifier_Combining_Mark",
+        "Noncharacter_Code_Point",
+        "Other_Alphabetic",
+        "Other_Default_Ignorable_Code_Point",
+        "Other_Grapheme_Extend",
+        "Other_ID_Continue",
+        "Other_ID_Start",
+        "Other_Lowercase",
+        "Other_Math",
+        "Other_Uppercase",
+        "Pattern_Syntax",
+        "Pattern_White_Space",
+        "Prepended_Concatenation_Mark",
+        "Quotation_Mark",
+        "Radical",
+        "Regional_Indicator",
+        "Sentence_Terminal",
+        "Soft_Dotted",
+        "Terminal_Punctuation",
+        "Unified_Ideograph",
+        "Uppercase",
+        "Variation_Selector",
+        "White_Space",
+        "XID_Continue",
+        "XID_Start",
+    ];
+
+    fn assert_same_names(table: &[&str], classes: impl IntoIterator<Item = String>) {
+        let ours: Vec<String> = classes
+            .into_iter()
+            .map(|class| class[3..class.len() - 1].to_string())
+            .collect();
+        for name in &ours {
+            assert!(table.contains(&name.as_str()), "{}", name);
+        }
+        // Listed names that do not resolve yet, such as InCB, have no variant until they do
+        for name in table {
+            assert_eq!(
+                regex::Regex::new(&format!(r"\p{{{}}}", name)).is_ok(),
+                ours.contains(&name.to_string()),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn every_variant_compiles() {
+        for category in UnicodeCategory::ALL {
+            for regex_string in [
+                hr::unicode_category(category),
+                hr::non_unicode_category(category),
+            ] {
+                assert!(
+                    regex::Regex::new(&regex_string.to_string()).is_ok(),
+                    "{:?}",
+                    category
+                );
+            }
+        }
+        for script in UnicodeScript::ALL {
+            for regex_string in [
+                hr::unicode_script(script),
+                hr::non_unicode_script(script),
+                hr::unicode_script_extensions(script),
+                hr::non_unicode_script_extensions(script),
+            ] {
+                assert!(
+                    regex::Regex::new(&regex_string.to_string()).is_ok(),
+                    "{:?}",
+                    script
+                );
+            }
+        }
+        for property in UnicodeProperty::ALL {
+            for regex_string in [
+                hr::unicode_property(property),
+                hr::non_unicode_property(property),
+            ] {
+                assert!(
+                    regex::Regex::new(&regex_string.to_string()).is_ok(),
+                    "{:?}",
+                    property
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn all_arrays_list_every_variant() {
+        for (index, category) in UnicodeCategory::ALL.into_iter().enumerate() {
+            assert_eq!(unicode_category_index(category), index);
+        }
+        for (index, script) in UnicodeScript::ALL.into_iter().enumerate() {
+            assert_eq!(unicode_script_index(script), index);
+        }
+        for (index, property) in UnicodeProperty::ALL.into_iter().enumerate() {
+            assert_eq!(unicode_property_index(property), index);
+        }
+
+        assert_same_names(
+            &CATEGORIES,
+            UnicodeCategory::ALL.map(|category| hr::unicode_category(category).to_string()),
+        );
+        assert_same_names(
+            &SCRIPTS,
+            UnicodeScript::ALL.map(|script| hr::unicode_script(script).to_string()),
+        );
+        assert_same_names(
+            &PROPERTIES,
+            UnicodeProperty::ALL.map(|property| hr::unicode_property(property).to_string()),
+        );
+    }
+
+    #[test]
+    fn scripts_cover_every_letter() {
+        let mut scripts = CharSet::new();
+        for script in UnicodeScript::ALL {
+            let own = CharSet::from_class(&hr::unicode_script(script)).unwrap();
+            let extended = CharSet::from_class(&hr::unicode_script_extensions(script)).unwrap();
+            // Characters shared between scripts move from Common and Inherited to the scripts using them
+            if ![UnicodeScript::Common, UnicodeScript::Inherited].contains(&script) {
+                assert!((&own - &extended).is_empty(), "{:?}", script);
+            }
+            scripts = scripts | own;
+        }
+        let letters = CharSet::from_class(&hr::unicode_category(UnicodeCategory::Letter)).unwrap();
+        assert!((letters - &scripts).is_empty());
+
+        let mut categories = CharSet::new();
+        for category in UnicodeCategory::ALL {
+            categories = categories | CharSet::from_class(&hr::unicode_category(category)).unwrap();
+        }
+        assert_eq!(categories, CharSet::all());
+    }
+
+    #[test]
+    fn variants_match_their_characters() {
+        for (script, chr) in [
+            (UnicodeScript::CanadianAboriginal, '\u{1401}'),
+            (UnicodeScript::Gurmukhi, '\u{0A15}'),
+            (UnicodeScript::Hiragana, '\u{3042}'),
+            (UnicodeScript::TaiLe, '\u{1950}'),
+            (UnicodeScript::SignWriting, '\u{1D800}'),
+            (UnicodeScript::OlChiki, '\u{1C5A}'),
+        ] {
+            assert!(
+                hr::unicode_script(script)
+                    .to_regex()
+                    .is_match(&chr.to_string()),
+                "{:?}",
+                script
+            );
+        }
+        for (category, chr) in [
+            (UnicodeCategory::NonSpacingMark, '\u{0301}'),
+            (UnicodeCategory::SpaceCombiningMark, '\u{0903}'),
+            (UnicodeCategory::DecimalDigitNumber, '\u{0663}'),
+        ] {
+            assert!(
+                hr::unicode_category(category)
+                    .to_regex()
+                    .is_match(&chr.to_string()),
+                "{:?}",
+                category
+            );
+        }
+        for (property, chr) in [
+            (UnicodeProperty::Alphabetic, 'x'),
+            (UnicodeProperty::WhiteSpace, '\u{2028}'),
+            (UnicodeProperty::Emoji, '\u{1F980}'),
+            (UnicodeProperty::Ideographic, '\u{8C4A}'),
+            (UnicodeProperty::AsciiHexDigit, 'F'),
+            (UnicodeProperty::XidStart, '\u{00E9}'),
+        ] {
+            assert!(
+                hr::unicode_property(property)
+                    .to_regex()
+                    .is_match(&chr.to_string()),
+                "{:?}",
+                property
+            );
+            assert!(!hr::non_unicode_property(property)
+                .to_regex()
+                .is_match(&chr.to_string()));
+        }
+        let dsl = hr::parse_dsl("unicode_property WhiteSpace, non_unicode_script_extensions Greek")
+            .unwrap();
+        assert_eq!(
+            dsl.to_string(),
+            (hr::unicode_property(UnicodeProperty::WhiteSpace)
+                + hr::non_unicode_script_extensions(UnicodeScript::Greek))
+            .to_string()
+        );
+        assert_eq!(
+            dsl.to_dsl(),
+            "unicode_property WhiteSpace, non_unicode_script_extensions Greek"
+        );
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    /// Check that the verbose form of a pattern matches the same samples as the original
+    fn assert_same_matches<T>(regex_string: &hr::HumanRegex<T>, samples: &[&str]) {
+        let original = regex::Regex::new(&regex_string.to_string()).unwrap();
+        let verbose = regex_string.to_verbose_string();
+        let rendered = regex::Regex::new(&verbose).unwrap();
+        for sample in samples {
+            assert_eq!(
+                rendered.is_match(sample),
+                original.is_match(sample),
+                "{:?} in\n{}",
+                sample,
+                verbose
+            );
+        }
+    }
+
+    #[test]
+    fn alternations_keep_their_group() {
+        let regex_string = hr::beginning()
+            + hr::text("a")
+            + hr::or(&[hr::text("b"), hr::text("c")])
+            + hr::text("d")
+            + hr::end();
+        assert_same_matches(&regex_string, &["abd", "acd", "ab", "cd", "a", "d"]);
+        assert!(regex_string.to_verbose_string().contains("(?:"));
+    }
+
+    #[test]
+    fn standalone_flags_stay_in_their_group() {
+        let regex_string = hr::beginning() + hr::or(&["(?i)a"]) + hr::text("b") + hr::end();
+        assert_same_matches(&regex_string, &["ab", "Ab", "aB", "AB"]);
+    }
+
+    #[test]
+    fn plain_groups_are_flattened() {
+        let regex_string = hr::or(&[hr::text("ab")]) + hr::text("c");
+        assert_same_matches(&regex_string, &["abc", "ab", "c"]);
+        assert!(!regex_string.to_verbose_string().contains("(?:"));
+    }
+}
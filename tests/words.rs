@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod tests {
+    use human_regex as hr;
+
+    /// Check that the matcher finds the same matches as the regex in every haystack
+    fn agree(pattern: &hr::HumanRegex, haystacks: &[&str]) {
+        let (regex, matcher) = (pattern.to_regex(), pattern.to_matcher());
+        for haystack in haystacks {
+            let expected: Vec<_> = regex.find_iter(haystack).map(|m| m.range()).collect();
+            let found: Vec<_> = matcher.find_iter(haystack).map(|m| m.range()).collect();
+            assert_eq!(found, expected, "{} in {:?}", pattern, haystack);
+        }
+    }
+
+    #[test]
+    fn automaton_finds_what_the_regex_finds() {
+        let words = [
+            "foo", "foobar", "bar", "o", "ob", "Straße", "x-y", "café", "k", "is",
+        ];
+        let haystacks = [
+            "foobar foo bar",
+            "xfoobar foox foo_bar, foo-bar",
+            "FOOBAR Foo bAr",
+            "x-y x-yz -x-y- (x-y)",
+            "STRASSE Straße straße café cafés",
+            "k K \u{212A} \u{212A}\u{212A} this \u{17F} I\u{17F}",
+            "",
+            "oboe",
+        ];
+        for whole_word in [false, true] {
+            for case_insensitive in [false, true] {
+                for longest_first in [false, true] {
+                    let options = hr::WordOptions {
+                        whole_word,
+                        case_insensitive,
+                        longest_first,
+                    };
+                    let ascii: Vec<&str> = words.iter().copied().filter(|w| w.is_ascii()).collect();
+                    for list in [&words[..], &ascii[..]] {
+                        let pattern = hr::one_of_words(list, &options);
+                        let expected = !case_insensitive || list.len() == ascii.len();
+                        assert_eq!(pattern.to_matcher().uses_automaton(), expected);
+                        agree(&pattern, &haystacks);
+                    }
+                }
+            }
+        }
+
+        // Pure literal alternations built any other way use the automaton too
+        let stop_words = ["a", "an", "the", "of"];
+        let built = [
+            hr::or(&hr::escape_all(&stop_words)),
+            hr::word_boundary() + hr::or(&hr::escape_all(&stop_words)) + hr::word_boundary(),
+            hr::case_insensitive(hr::or(&[hr::text("an"), hr::text("the")])),
+            hr::nonescaped_text(&hr::text("the end").to_string()),
+        ];
+        for pattern in &built {
+            assert!(pattern.to_matcher().uses_automaton(), "{}", pattern);
+            agree(pattern, &["The end of an era, then a theory of the end"]);
+        }
+    }
+
+    #[test]
+    fn replacements_expand_like_the_regex() {
+        let pattern = hr::one_of_words(&["cat", "dog"], &hr::WordOptions::default());
+        let (regex, matcher) = (pattern.to_regex(), pattern.to_matcher());
+        assert!(matcher.uses_automaton());
+        let haystack = "a cat, a dog";
+        for replacement in [
+            "$0", "${0}s", "$$0", "[$1]", "$pet", "${pet}s", "$", "${", "${}", "a$", "$00", "$0a",
+            "$ $",
+        ] {
+            assert_eq!(
+                matcher.replace_all(haystack, replacement),
+                regex.replace_all(haystack, replacement),
+                "{:?}",
+                replacement
+            );
+            assert_eq!(
+                matcher.replace(haystack, replacement),
+                regex.replace(haystack, replacement),
+                "{:?}",
+                replacement
+            );
+        }
+        let found: Vec<&str> = matcher.find_iter(haystack).map(|m| m.as_str()).collect();
+        assert_eq!(found, ["cat", "dog"]);
+    }
+
+    #[test]
+    fn large_dictionaries_compile_into_an_automaton() {
+        let words: Vec<String> = (0..20_000)
+            .map(|idx| format!("{:x}term{}", idx * 7919, idx))
+            .collect();
+        let options = hr::WordOptions {
+            whole_word: true,
+            case_insensitive: true,
+            ..Default::default()
+        };
+        let matcher = hr::one_of_words(&words, &options).to_matcher();
+        assert!(matcher.uses_automaton());
+        let haystack = format!(
+            "{} and {}x {}",
+            words[42],
+            words[19_999],
+            words[5].to_uppercase()
+        );
+        let found: Vec<&str> = matcher
+            .find_iter(&haystack)
+            .map(|m| &haystack[m.range()])
+            .collect();
+        assert_eq!(found, vec![words[42].as_str(), &words[5].to_uppercase()]);
+        assert_eq!(
+            matcher.replace_all(&haystack, "*"),
+            format!("* and {}x *", words[19_999])
+        );
+        assert_eq!(
+            matcher.replace(&haystack, "*"),
+            haystack.replacen(&words[42], "*", 1)
+        );
+        assert!(matches!(
+            matcher.replace_all("nothing here", "*"),
+            std::borrow::Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn other_patterns_fall_back_to_a_regex() {
+        for pattern in [
+            hr::one_or_more(hr::digit()),
+            hr::named_capture(hr::text("cat"), "pet"),
+            hr::or(&[hr::text("cat"), hr::text("ca")]) + hr::zero_or_one(hr::text("t")),
+            hr::disable_unicode(hr::word_boundary() + hr::text("cat") + hr::word_boundary()),
+            hr::beginning() + hr::text("cat"),
+            hr::zero_or_more(hr::text("t")),
+        ] {
+            assert!(!pattern.to_matcher().uses_automaton(), "{}", pattern);
+            agree(&pattern, &["cat 42 catt"]);
+        }
+        let number = hr::one_or_more(hr::digit()).to_matcher();
+        assert_eq!(number.replace_all("1 and 22", "<$0>"), "<1> and <22>");
+        assert_eq!(number.find_at("1 and 22", 1).unwrap().range(), 6..8);
+    }
+}